@@ -0,0 +1,380 @@
+//! Optional on-disk cache of per-file results, enabled with `RAE_CACHE_DIR`,
+//! so frequently restarted CI runners can skip files they have seen before.
+//!
+//! Each entry is a JSON file named by a `ContentHash` over everything a
+//! result depends on: content, file name, language, line range, rule config
+//! and the rule set. Results are stored before cross-file coupling is
+//! applied, since that depends on the rest of the batch. The directory is
+//! kept under `RAE_CACHE_MAX_BYTES` by evicting least recently used entries.
+//! The directory is only scanned when a running total of its size goes over
+//! the limit; writes by other processes sharing it are picked up by that
+//! scan. Cache I/O failures are logged and otherwise ignored.
+
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::SystemTime;
+use tracing::{debug, warn};
+
+use crate::{
+    analysis::coupling::FileImports,
+    rules::RuleSet,
+    types::{ContentHash, FileAnalysisResult, Language, RuleConfig, SourceFile},
+};
+
+const DEFAULT_MAX_BYTES: u64 = 256 * 1024 * 1024;
+
+const ENTRY_EXTENSION: &str = "json";
+
+/// Distinguishes temp files written concurrently by this process.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+pub struct DiskCache {
+    dir: PathBuf,
+    max_bytes: u64,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    /// Bytes of entries in `dir` as of the last scan, plus this cache's
+    /// writes since. `None` until the first write scans the directory.
+    size: Mutex<Option<u64>>,
+}
+
+/// A cached file result, with the imports coupling needs for the batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedFile {
+    pub result: FileAnalysisResult,
+    pub imports: FileImports,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>, max_bytes: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            size: Mutex::new(None),
+        }
+    }
+
+    /// A cache in `RAE_CACHE_DIR`, bounded by `RAE_CACHE_MAX_BYTES` (256 MiB
+    /// by default). `None` when no directory is configured.
+    pub fn from_env() -> Option<Self> {
+        let dir = env::var("RAE_CACHE_DIR").ok().filter(|dir| !dir.trim().is_empty())?;
+        let max_bytes = match env::var("RAE_CACHE_MAX_BYTES") {
+            Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+                warn!("Ignoring invalid RAE_CACHE_MAX_BYTES: {}", value);
+                DEFAULT_MAX_BYTES
+            }),
+            Err(_) => DEFAULT_MAX_BYTES,
+        };
+
+        Some(Self::new(dir, max_bytes))
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Lookups answered from the cache since it was opened.
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Lookups that found no usable entry since the cache was opened.
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn key(&self, file: &SourceFile, language: &Language, config: &RuleConfig, rule_set: &RuleSet) -> ContentHash {
+        // Going through `Value` sorts map keys, so equal configs serialize equally
+        let config = serde_json::to_value(config).map(|value| value.to_string()).unwrap_or_default();
+
+        ContentHash::from_content(&format!(
+            "{}\0{}\0{:?}\0{:?}\0{:?}\0{}\0{}",
+            ContentHash::from_content(&file.content).0,
+            file.name,
+            language,
+            file.analyze_range,
+            file.parser_override,
+            config,
+            rule_set.fingerprint(),
+        ))
+    }
+
+    pub(crate) fn get(&self, key: &ContentHash) -> Option<CachedFile> {
+        let entry = self.read_entry(key);
+        let counter = if entry.is_some() { &self.hits } else { &self.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        entry
+    }
+
+    fn read_entry(&self, key: &ContentHash) -> Option<CachedFile> {
+        let path = self.entry_path(key);
+        let bytes = fs::read(&path).ok()?;
+
+        match serde_json::from_slice(&bytes) {
+            Ok(entry) => {
+                // Reads count as use for eviction
+                if let Err(e) = File::options().write(true).open(&path).and_then(|f| f.set_modified(SystemTime::now())) {
+                    debug!("Failed to touch cache entry {}: {}", path.display(), e);
+                }
+                Some(entry)
+            }
+            Err(e) => {
+                warn!("Ignoring unreadable cache entry {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn put(&self, key: &ContentHash, entry: &CachedFile) {
+        let (written, replaced) = match self.write_entry(key, entry) {
+            Ok(sizes) => sizes,
+            Err(e) => {
+                warn!("Failed to write cache entry for {}: {}", entry.result.file_name, e);
+                return;
+            }
+        };
+
+        let mut size = self.size.lock().unwrap_or_else(PoisonError::into_inner);
+        let needs_scan = match size.as_mut() {
+            Some(total) => {
+                *total = (*total + written).saturating_sub(replaced);
+                *total > self.max_bytes
+            }
+            None => true,
+        };
+        if needs_scan {
+            *size = match self.evict() {
+                Ok(total) => Some(total),
+                Err(e) => {
+                    warn!("Failed to evict cache entries in {}: {}", self.dir.display(), e);
+                    None
+                }
+            };
+        }
+    }
+
+    /// Returns the size of the new entry and of the one it replaced, if any.
+    fn write_entry(&self, key: &ContentHash, entry: &CachedFile) -> std::io::Result<(u64, u64)> {
+        fs::create_dir_all(&self.dir)?;
+
+        // Write then rename, so concurrent readers never see a partial entry.
+        // The counter keeps concurrent writers of one key off each other's
+        // temp file.
+        let path = self.entry_path(key);
+        let temp = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let bytes = serde_json::to_vec(entry)?;
+        fs::write(&temp, &bytes)?;
+        let replaced = fs::metadata(&path).map_or(0, |metadata| metadata.len());
+        fs::rename(&temp, &path)?;
+
+        Ok((bytes.len() as u64, replaced))
+    }
+
+    /// Scans the directory and, if it is over `max_bytes`, removes the least
+    /// recently used entries until it is back under 90% of it, so the next
+    /// few writes don't each trigger a scan. Returns the resulting size.
+    fn evict(&self) -> std::io::Result<u64> {
+        let mut entries = Vec::new();
+        for dir_entry in fs::read_dir(&self.dir)? {
+            let path = dir_entry?.path();
+            if path.extension().is_some_and(|ext| ext == ENTRY_EXTENSION) {
+                let metadata = fs::metadata(&path)?;
+                entries.push((metadata.modified()?, metadata.len(), path));
+            }
+        }
+
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        if total <= self.max_bytes {
+            return Ok(total);
+        }
+
+        let target = self.max_bytes - self.max_bytes / 10;
+        entries.sort();
+        for (_, len, path) in entries {
+            if total <= target {
+                break;
+            }
+            fs::remove_file(&path)?;
+            total -= len;
+        }
+
+        Ok(total)
+    }
+
+    fn entry_path(&self, key: &ContentHash) -> PathBuf {
+        self.dir.join(format!("{}.{}", key.0, ENTRY_EXTENSION))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::FileMetrics;
+    use std::time::Duration;
+
+    fn entry(name: &str) -> CachedFile {
+        CachedFile {
+            result: FileAnalysisResult {
+                file_name: name.to_string(),
+                language: Language::JavaScript,
+                findings: Vec::new(),
+                metrics: FileMetrics {
+                    lines_of_code: 1,
+                    functions_count: 0,
+                    classes_count: 0,
+                    complexity_score: 1.0,
+                    todo_density: 0.0,
+                    iife_count: 0,
+                    longest_regex: 0,
+                    class_cohesion: Vec::new(),
+                    halstead: None,
+                },
+                rules_executed: Vec::new(),
+                rules_skipped: Vec::new(),
+                coupling: Default::default(),
+                timing_breakdown: None,
+            },
+            imports: FileImports {
+                specifiers: vec!["./dep".to_string()],
+                anchor: None,
+                snippet: None,
+            },
+        }
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rae-cache-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = temp_dir("round-trip");
+        let cache = DiskCache::new(&dir, DEFAULT_MAX_BYTES);
+        let key = ContentHash::from_content("a");
+
+        assert!(cache.get(&key).is_none());
+        cache.put(&key, &entry("a.js"));
+        let cached = cache.get(&key).unwrap();
+
+        assert_eq!(cached.result.file_name, "a.js");
+        assert_eq!(cached.imports.specifiers, vec!["./dep"]);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let dir = temp_dir("evict");
+        let first = ContentHash::from_content("first");
+        let second = ContentHash::from_content("second");
+
+        let unbounded = DiskCache::new(&dir, DEFAULT_MAX_BYTES);
+        unbounded.put(&first, &entry("first.js"));
+        let entry_size = fs::metadata(unbounded.entry_path(&first)).unwrap().len();
+        File::options()
+            .write(true)
+            .open(unbounded.entry_path(&first))
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+
+        // Room for one entry only
+        let cache = DiskCache::new(&dir, entry_size + entry_size / 2);
+        cache.put(&second, &entry("second.js"));
+
+        assert!(cache.get(&first).is_none());
+        assert!(cache.get(&second).is_some());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_running_total_triggers_eviction() {
+        let dir = temp_dir("running-total");
+        let keys: Vec<_> = ["a", "b", "c"].iter().map(|name| ContentHash::from_content(name)).collect();
+
+        let probe = DiskCache::new(temp_dir("running-total-probe"), DEFAULT_MAX_BYTES);
+        probe.put(&keys[0], &entry("a.js"));
+        let entry_size = fs::metadata(probe.entry_path(&keys[0])).unwrap().len();
+        fs::remove_dir_all(probe.dir()).unwrap();
+
+        // Room for two and a half entries
+        let cache = DiskCache::new(&dir, entry_size * 5 / 2);
+        cache.put(&keys[0], &entry("a.js"));
+        File::options()
+            .write(true)
+            .open(cache.entry_path(&keys[0]))
+            .unwrap()
+            .set_modified(SystemTime::now() - Duration::from_secs(3600))
+            .unwrap();
+        cache.put(&keys[1], &entry("b.js"));
+        // Rewriting an entry doesn't grow the total
+        cache.put(&keys[1], &entry("b.js"));
+        assert!(cache.entry_path(&keys[0]).exists());
+
+        cache.put(&keys[2], &entry("c.js"));
+
+        assert!(!cache.entry_path(&keys[0]).exists());
+        assert!(cache.entry_path(&keys[1]).exists());
+        assert!(cache.entry_path(&keys[2]).exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_concurrent_writes_of_one_key_do_not_collide() {
+        let dir = temp_dir("concurrent");
+        let cache = DiskCache::new(&dir, DEFAULT_MAX_BYTES);
+        let key = ContentHash::from_content("shared");
+
+        std::thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    for _ in 0..20 {
+                        cache.write_entry(&key, &entry("shared.js")).unwrap();
+                    }
+                });
+            }
+        });
+
+        assert_eq!(cache.get(&key).unwrap().result.file_name, "shared.js");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_key_depends_on_config_and_content() {
+        let cache = DiskCache::new(temp_dir("key"), DEFAULT_MAX_BYTES);
+        let rule_set = RuleSet::new();
+        let file = SourceFile {
+            name: "a.js".to_string(),
+            content: "const a = 1;".to_string(),
+            language: None,
+            analyze_range: None,
+            parser_override: None,
+        };
+        let changed = SourceFile {
+            content: "const a = 2;".to_string(),
+            ..file.clone()
+        };
+        let strict = RuleConfig {
+            complexity_threshold: Some(2),
+            ..Default::default()
+        };
+        let default = RuleConfig::default();
+
+        let key = cache.key(&file, &Language::JavaScript, &default, &rule_set);
+        assert_eq!(key, cache.key(&file, &Language::JavaScript, &default, &rule_set));
+        assert_ne!(key, cache.key(&changed, &Language::JavaScript, &default, &rule_set));
+        assert_ne!(key, cache.key(&file, &Language::JavaScript, &strict, &rule_set));
+    }
+}
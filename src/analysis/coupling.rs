@@ -0,0 +1,189 @@
+//! Cross-file coupling metrics computed once every file in a batch has been
+//! analyzed: how many modules each file imports and how many other files in
+//! the batch import it.
+
+use std::collections::{HashMap, HashSet};
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::parser::{ImportInfo, MODULE_EXTENSIONS};
+use crate::types::{
+    CouplingMetrics, FileAnalysisResult, Finding, Location, RuleConfig, Severity, SkippedRule, SnippetLine,
+};
+
+pub const RULE_ID: &str = "high-coupling";
+
+pub const RATIONALE: &str =
+    "Modules imported by many files, or importing many, turn every change into a ripple across the codebase.";
+
+const DEFAULT_MAX_FAN_IN: u32 = 15;
+const DEFAULT_MAX_IMPORTS: u32 = 25;
+
+/// What coupling needs from one file, gathered when the file is evaluated
+/// so cached results and duplicates carry it too.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileImports {
+    /// Import specifiers exactly as written, in source order.
+    pub specifiers: Vec<String>,
+    /// Where the file's `high-coupling` findings are reported, see
+    /// [`anchor`]. `None` when the file's `analyze_range` excludes it.
+    pub anchor: Option<Location>,
+    /// Snippet of `anchor`, when `RuleConfig.snippet_context_lines` is set.
+    pub snippet: Option<Vec<SnippetLine>>,
+}
+
+/// The first import statement, or the top of a file that imports nothing.
+pub fn anchor(imports: &[ImportInfo], source: &str) -> Location {
+    let line = imports.iter().map(|import| import.line).min().unwrap_or(1);
+    let indent = source
+        .lines()
+        .nth(line.saturating_sub(1) as usize)
+        .map_or(0, |text| text.len() - text.trim_start().len());
+
+    Location {
+        line,
+        column: indent as u32 + 1,
+        end_line: None,
+        end_column: None,
+    }
+}
+
+/// Fills in `coupling` for every result and emits `high-coupling` findings.
+/// `imports[i]` describes the imports of `results[i]`.
+///
+/// Path canonicalization, the per-file part, runs on the rayon pool. The
+/// import graph is then built sequentially in file order, so the outcome
+/// doesn't depend on thread scheduling.
+pub fn apply(results: &mut [FileAnalysisResult], imports: &[FileImports], config: &RuleConfig) {
+    let module_keys: Vec<String> = results.par_iter().map(|result| module_key(&result.file_name)).collect();
+    let targets: Vec<Vec<String>> = imports
+        .par_iter()
+        .zip(results.par_iter())
+        .map(|(file_imports, result)| {
+            file_imports
+                .specifiers
+                .iter()
+                .filter_map(|specifier| resolve(&result.file_name, specifier))
+                .collect()
+        })
+        .collect();
+
+    let keys: HashMap<String, usize> = module_keys
+        .into_iter()
+        .enumerate()
+        .map(|(index, key)| (key, index))
+        .collect();
+
+    let mut importers: Vec<HashSet<usize>> = vec![HashSet::new(); results.len()];
+    for (importer, targets) in targets.iter().enumerate() {
+        for target in targets {
+            if let Some(&imported) = keys.get(target) {
+                if imported != importer {
+                    importers[imported].insert(importer);
+                }
+            }
+        }
+    }
+
+    let metrics_only = config.metrics_only.unwrap_or(false);
+    let disabled = metrics_only
+        || config
+            .disabled_rules
+            .as_ref()
+            .is_some_and(|ids| ids.iter().any(|id| id == RULE_ID));
+    let max_fan_in = config.max_fan_in.unwrap_or(DEFAULT_MAX_FAN_IN);
+    let max_imports = config.max_imports.unwrap_or(DEFAULT_MAX_IMPORTS);
+
+    for (index, result) in results.iter_mut().enumerate() {
+        let coupling = CouplingMetrics {
+            import_count: imports.get(index).map_or(0, |file_imports| file_imports.specifiers.len() as u32),
+            fan_in: importers[index].len() as u32,
+        };
+
+        if disabled {
+            result.rules_skipped.push(SkippedRule {
+                rule_id: RULE_ID.to_string(),
+                reason: if metrics_only { super::METRICS_ONLY_REASON } else { "disabled by config" }.to_string(),
+            });
+        } else {
+            result.rules_executed.push(RULE_ID.to_string());
+            let mut messages = Vec::new();
+            if coupling.fan_in > max_fan_in {
+                messages.push(format!(
+                    "File is imported by {} other files (max: {})",
+                    coupling.fan_in, max_fan_in
+                ));
+            }
+            if coupling.import_count > max_imports {
+                messages.push(format!("File imports {} modules (max: {})", coupling.import_count, max_imports));
+            }
+            if let Some(file_imports) = imports.get(index) {
+                for message in messages {
+                    result.findings.extend(coupling_finding(message, file_imports));
+                }
+            }
+        }
+
+        result.coupling = coupling;
+    }
+}
+
+/// A finding at the file's anchor, or none when that lies outside its
+/// `analyze_range`.
+fn coupling_finding(message: String, file_imports: &FileImports) -> Option<Finding> {
+    let mut finding = Finding::new(RULE_ID, Severity::Medium, message, file_imports.anchor.clone()?)
+        .with_suggestion("Reduce coupling by splitting the module or introducing a narrower interface");
+    finding.snippet = file_imports.snippet.clone();
+    Some(finding)
+}
+
+/// Resolves a relative `specifier` against the importing file's directory.
+/// Package imports never point at files in the batch.
+fn resolve(importer: &str, specifier: &str) -> Option<String> {
+    if !(specifier.starts_with("./") || specifier.starts_with("../")) {
+        return None;
+    }
+
+    let dir = importer.rsplit_once('/').map_or("", |(dir, _)| dir);
+    Some(module_key(&format!("{}/{}", dir, specifier)))
+}
+
+/// Canonical form of a module path: `.`/`..` segments resolved, extension
+/// and a trailing `/index` dropped.
+fn module_key(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    for segment in path.split(['/', '\\']) {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    if let Some(last) = segments.last_mut() {
+        if let Some(ext) = MODULE_EXTENSIONS.iter().find(|ext| last.ends_with(*ext)) {
+            *last = &last[..last.len() - ext.len()];
+        }
+    }
+    if segments.len() > 1 && segments.last() == Some(&"index") {
+        segments.pop();
+    }
+
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_key_resolves_relative_paths() {
+        assert_eq!(resolve("src/app.js", "./utils").unwrap(), module_key("src/utils.ts"));
+        assert_eq!(resolve("src/a/b.js", "../utils/index.js").unwrap(), module_key("src/utils.js"));
+        assert_eq!(resolve("app.js", "./lib").unwrap(), module_key("lib/index.ts"));
+        assert!(resolve("app.js", "react").is_none());
+    }
+}
@@ -0,0 +1,103 @@
+//! Halstead software-science metrics, computed from a file's token stream.
+//!
+//! Operands are identifiers and literals; operators are every other token
+//! (keywords, punctuation and operator symbols). Comments are ignored.
+
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use crate::types::HalsteadMetrics;
+
+/// Literals counted as one operand rather than by their inner tokens.
+const LITERAL_KINDS: &[&str] = &["string", "template_string", "regex"];
+
+pub fn compute(root: Node, source: &str) -> HalsteadMetrics {
+    let mut counts = TokenCounts::default();
+    collect(root, source, &mut counts);
+
+    let distinct_operators = counts.operators.len() as f64;
+    let distinct_operands = counts.operands.len() as f64;
+    let vocabulary = distinct_operators + distinct_operands;
+    let length = (counts.total_operators + counts.total_operands) as f64;
+
+    let volume = if vocabulary > 0.0 { length * vocabulary.log2() } else { 0.0 };
+    let difficulty = if distinct_operands > 0.0 {
+        distinct_operators / 2.0 * counts.total_operands as f64 / distinct_operands
+    } else {
+        0.0
+    };
+
+    HalsteadMetrics {
+        vocabulary: vocabulary as u32,
+        length: length as u32,
+        volume,
+        difficulty,
+        effort: difficulty * volume,
+    }
+}
+
+#[derive(Default)]
+struct TokenCounts<'a> {
+    operators: HashSet<&'a str>,
+    operands: HashSet<&'a str>,
+    total_operators: u32,
+    total_operands: u32,
+}
+
+fn collect<'a>(node: Node, source: &'a str, counts: &mut TokenCounts<'a>) {
+    if node.kind() == "comment" {
+        return;
+    }
+
+    let is_literal = LITERAL_KINDS.contains(&node.kind());
+    if is_literal || node.child_count() == 0 {
+        let text = source.get(node.start_byte()..node.end_byte()).unwrap_or("");
+        if text.is_empty() {
+            return;
+        }
+        if is_literal || node.is_named() {
+            counts.operands.insert(text);
+            counts.total_operands += 1;
+        } else {
+            counts.operators.insert(text);
+            counts.total_operators += 1;
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(child, source, counts);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metrics(source: &str) -> HalsteadMetrics {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_javascript::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        compute(tree.root_node(), source)
+    }
+
+    #[test]
+    fn test_counts_operators_and_operands() {
+        // operators: let, =, +, ;, ;  -> 4 distinct, 5 total
+        // operands:  x, a, 'b', x     -> 3 distinct, 4 total
+        let result = metrics("let x = a + 'b'; x;");
+
+        assert_eq!(result.vocabulary, 7);
+        assert_eq!(result.length, 9);
+        assert!((result.volume - 9.0 * 7f64.log2()).abs() < 1e-9);
+        assert!((result.difficulty - 4.0 / 2.0 * 4.0 / 3.0).abs() < 1e-9);
+        assert!((result.effort - result.difficulty * result.volume).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_comments_are_ignored_and_empty_source_is_zero() {
+        assert_eq!(metrics("// only a comment\n").length, 0);
+        assert_eq!(metrics("").volume, 0.0);
+    }
+}
@@ -1,26 +1,221 @@
-use std::collections::HashMap;
-use std::time::Instant;
-use tracing::{info, instrument};
+use dashmap::DashMap;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use tracing::{info, instrument, warn};
+use tree_sitter::Tree;
 
 use crate::{
     error::{AnalysisError, AnalysisResult},
-    parser::ParserRegistry,
+    parser::{syntax_errors, FunctionInfo, ParseResult, Parser, ParserRegistry},
+    rules::{complex_regex::longest_regex, is_test_file, legacy_iife::iife_count, todo_density::todo_density, RuleContext, RuleSet},
     types::{
-        AnalysisRequest, AnalysisResponse, FileAnalysisResult, AnalysisSummary,
-        Finding, FileMetrics, Language, Severity, SourceFile,
+        AnalysisRequest, ApiSurfaceRequest, ApiSurfaceResponse, ExportedClass, ExportedFunction, FileApiSurface, AnalysisResponse, ConfigValidation, ContentHash, CouplingMetrics, DedupedFinding, FileAnalysisResult, AnalysisSummary, ClassCohesion,
+        Finding, FindingWithFile, FileMetrics, Language, MultiConfigRequest, MultiConfigResponse, ParseCheckRequest,
+        ParseCheckResponse, ParseCheckResult, RuleConfig, RuleDescriptor, SourceFile,
     },
 };
 
+pub mod cache;
+pub mod coupling;
+pub mod halstead;
+pub mod snippet;
+pub mod validation;
+
+use cache::{CachedFile, DiskCache};
+use coupling::FileImports;
+
+/// Line count at or under which optional metrics are skipped.
+const DEFAULT_SMALL_FILE_MAX_LINES: u32 = 3;
+
+/// `rules_skipped` reason for every rule under `RuleConfig.metrics_only`.
+pub(crate) const METRICS_ONLY_REASON: &str = "metrics-only mode";
+
 pub struct AnalysisEngine {
     parser_registry: ParserRegistry,
+    rule_set: RuleSet,
+    /// Files parsed since startup, per language.
+    parse_counts: DashMap<Language, u64>,
+    cache: Option<DiskCache>,
+    /// Files retained by each open session, by token.
+    sessions: DashMap<String, Session>,
+    session_limits: SessionLimits,
+}
+
+/// Bounds on the memory sessions hold, since each keeps its files' content
+/// and syntax trees.
+#[derive(Debug, Clone, Copy)]
+pub struct SessionLimits {
+    /// Idle time after which a session is dropped.
+    pub ttl: Duration,
+    /// Open sessions at most; opening another drops the least recently used.
+    pub max_sessions: usize,
+    /// Files one session may retain; requests that would take it past this
+    /// are rejected.
+    pub max_files: usize,
+}
+
+impl Default for SessionLimits {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(30 * 60),
+            max_sessions: 100,
+            max_files: 100,
+        }
+    }
+}
+
+struct Session {
+    /// Locked for the length of an analysis, so requests to one session
+    /// run one at a time without holding up the session map.
+    batch: Arc<Mutex<ParsedBatch>>,
+    last_used: Instant,
 }
 
 impl AnalysisEngine {
+    /// An engine using the disk cache configured by `RAE_CACHE_DIR`, if any.
     pub async fn new() -> AnalysisResult<Self> {
+        Self::with_cache(DiskCache::from_env()).await
+    }
+
+    pub async fn with_cache(cache: Option<DiskCache>) -> AnalysisResult<Self> {
         let parser_registry = ParserRegistry::new().await?;
-        
+        if let Some(cache) = &cache {
+            info!("Caching results in {}", cache.dir().display());
+        }
+
         Ok(Self {
             parser_registry,
+            rule_set: RuleSet::new(),
+            parse_counts: DashMap::new(),
+            cache,
+            sessions: DashMap::new(),
+            session_limits: SessionLimits::default(),
+        })
+    }
+
+    pub fn set_session_limits(&mut self, limits: SessionLimits) {
+        self.session_limits = limits;
+    }
+
+    pub fn cache(&self) -> Option<&DiskCache> {
+        self.cache.as_ref()
+    }
+
+    /// Number of files parsed per language since the engine was created,
+    /// sorted by language name.
+    pub fn parse_counts(&self) -> Vec<(Language, u64)> {
+        let mut counts: Vec<(Language, u64)> = self
+            .parse_counts
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        counts.sort_by_key(|(language, _)| format!("{:?}", language));
+        counts
+    }
+
+    /// The rule catalog: every rule, including the cross-file
+    /// `high-coupling` pass, with its rationale.
+    pub fn rules(&self) -> Vec<RuleDescriptor> {
+        let mut rules = self.rule_set.descriptors();
+        rules.push(RuleDescriptor {
+            id: coupling::RULE_ID.to_string(),
+            rationale: coupling::RATIONALE.to_string(),
+            enabled_by_default: true,
+            confidence: 1.0,
+        });
+        rules
+    }
+
+    /// Checks `config` for out-of-range thresholds, unknown rule ids and
+    /// empty patterns.
+    pub fn validate_config(&self, config: &RuleConfig) -> ConfigValidation {
+        let mut known_rules = self.rule_set.ids();
+        known_rules.push(coupling::RULE_ID);
+        let errors = validation::config_errors(config, &known_rules);
+
+        ConfigValidation {
+            valid: errors.is_empty(),
+            errors,
+        }
+    }
+
+    /// Parses each file and reports only whether it is syntactically valid.
+    /// No metrics are computed and no rules run.
+    #[instrument(skip(self, request))]
+    pub async fn check_parse(&self, request: ParseCheckRequest) -> AnalysisResult<ParseCheckResponse> {
+        let start_time = Instant::now();
+
+        let results = request
+            .files
+            .iter()
+            .map(|file| {
+                let language = resolve_language(file)?;
+                let tree = self
+                    .parser_for(file.parser_override.as_ref().unwrap_or(&language))?
+                    .parse_tree(&file.name, &file.content)?;
+                let errors = syntax_errors(&tree);
+                *self.parse_counts.entry(language).or_insert(0) += 1;
+
+                Ok(ParseCheckResult {
+                    file_name: file.name.clone(),
+                    parses: errors.count == 0,
+                    error_count: errors.count,
+                    first_error_line: errors.first_line,
+                })
+            })
+            .collect::<AnalysisResult<Vec<_>>>()?;
+
+        Ok(ParseCheckResponse {
+            results,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Lists the exported functions and classes of each file, with function
+    /// signatures. No metrics are computed and no rules run.
+    #[instrument(skip(self, request))]
+    pub async fn api_surface(&self, request: ApiSurfaceRequest) -> AnalysisResult<ApiSurfaceResponse> {
+        let start_time = Instant::now();
+
+        let results = request
+            .files
+            .iter()
+            .map(|file| {
+                let language = resolve_language(file)?;
+                let parse_result = self.parse(file, &language)?;
+                *self.parse_counts.entry(language.clone()).or_insert(0) += 1;
+
+                Ok(FileApiSurface {
+                    file_name: file.name.clone(),
+                    language,
+                    functions: parse_result
+                        .functions
+                        .into_iter()
+                        .filter(|function| function.is_exported)
+                        .map(|function| ExportedFunction {
+                            name: function.name,
+                            line: function.line,
+                            signature: function.signature,
+                        })
+                        .collect(),
+                    classes: parse_result
+                        .classes
+                        .into_iter()
+                        .filter(|class| class.is_exported)
+                        .map(|class| ExportedClass {
+                            name: class.name,
+                            line: class.line,
+                        })
+                        .collect(),
+                })
+            })
+            .collect::<AnalysisResult<Vec<_>>>()?;
+
+        Ok(ApiSurfaceResponse {
+            results,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
         })
     }
 
@@ -29,106 +224,783 @@ impl AnalysisEngine {
         let start_time = Instant::now();
         
         info!("Starting analysis of {} files", request.files.len());
-        
-        let mut results = Vec::new();
+
+        let config = request.rules.unwrap_or_default();
+        let files = sample(request.files, &config);
+
+        let fail_on_unsupported = config.fail_on_unsupported.unwrap_or(false);
+        let batch = self.parse_batch(files, config.node_budget, fail_on_unsupported, Some(&config))?;
+        let response = self.respond(&batch, &config, start_time);
+
+        info!(
+            "Analysis completed in {}ms, {} findings across {} lines",
+            response.execution_time_ms,
+            response.summary.total_findings,
+            response.summary.total_lines_analyzed
+        );
+
+        Ok(response)
+    }
+
+    /// Starts an empty session under `token` for `analyze_session`. Expired
+    /// sessions are dropped first, then the least recently used one if the
+    /// limit is reached.
+    pub fn open_session(&self, token: &str) {
+        let now = Instant::now();
+        let ttl = self.session_limits.ttl;
+        self.sessions.retain(|_, session| now.duration_since(session.last_used) < ttl);
+
+        while self.sessions.len() >= self.session_limits.max_sessions.max(1) {
+            let oldest = self
+                .sessions
+                .iter()
+                .min_by_key(|entry| entry.value().last_used)
+                .map(|entry| entry.key().clone());
+            match oldest {
+                Some(oldest) => {
+                    info!("Session limit reached, closing session {}", oldest);
+                    self.sessions.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+
+        self.sessions.insert(
+            token.to_string(),
+            Session {
+                batch: Arc::default(),
+                last_used: now,
+            },
+        );
+    }
+
+    /// Drops the session and the files it retains. False if it wasn't open.
+    pub fn close_session(&self, token: &str) -> bool {
+        self.sessions.remove(token).is_some()
+    }
+
+    /// Adds the request's files to the session, replacing retained files of
+    /// the same name, and analyzes everything the session holds. Only the
+    /// files in the request are parsed, but rules and cross-file passes run
+    /// over the full retained set. Files are sampled as they arrive, like
+    /// `analyze` does.
+    #[instrument(skip(self, request))]
+    pub async fn analyze_session(&self, token: &str, request: AnalysisRequest) -> AnalysisResult<AnalysisResponse> {
+        let start_time = Instant::now();
+
+        let unknown = || AnalysisError::ValidationError {
+            message: format!("Unknown session: {}", token),
+        };
+        let ttl = self.session_limits.ttl;
+        if self.sessions.remove_if(token, |_, session| session.last_used.elapsed() >= ttl).is_some() {
+            info!("Session {} expired", token);
+            return Err(unknown());
+        }
+        let batch = {
+            let mut session = self.sessions.get_mut(token).ok_or_else(unknown)?;
+            session.last_used = Instant::now();
+            Arc::clone(&session.batch)
+        };
+        let mut batch = batch.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let config = request.rules.unwrap_or_default();
+        let files = sample(request.files, &config);
+        let retained = batch.file_names();
+        let added: HashSet<&str> = files
+            .iter()
+            .map(|file| file.name.as_str())
+            .filter(|name| !retained.contains(*name))
+            .collect();
+        let total_files = retained.len() + added.len();
+        let max_files = self.session_limits.max_files;
+        if total_files > max_files {
+            return Err(AnalysisError::ValidationError {
+                message: format!("Session would retain {} files (max: {})", total_files, max_files),
+            }
+            .at_field("files"));
+        }
+
+        let fail_on_unsupported = config.fail_on_unsupported.unwrap_or(false);
+        batch.merge(self.parse_batch(files, config.node_budget, fail_on_unsupported, None)?);
+
+        info!("Analyzing {} files retained by the session", batch.entries.len());
+        Ok(self.respond(&batch, &config, start_time))
+    }
+
+    fn respond(&self, batch: &ParsedBatch, config: &RuleConfig, start_time: Instant) -> AnalysisResponse {
+        let (mut results, mut summary) = self.evaluate_batch(batch, config);
+        if config.sample_rate.is_some() {
+            // Every file in the batch came through `sample`
+            summary.sampled = true;
+            summary.sample_size =
+                Some((batch.entries.len() + batch.unsupported_files.len()) as u32 + batch.files_skipped);
+        }
+        if config.minimal_response.unwrap_or(false) {
+            return AnalysisResponse {
+                results: Vec::new(),
+                summary,
+                execution_time_ms: start_time.elapsed().as_millis() as u64,
+                all_findings: None,
+                deduped_findings: None,
+            };
+        }
+        let all_findings = config
+            .flatten_findings
+            .unwrap_or(false)
+            .then(|| FindingWithFile::collect(&results));
+        let deduped_findings = config
+            .dedupe_across_files
+            .unwrap_or(false)
+            .then(|| DedupedFinding::collect(&mut results));
+
+        AnalysisResponse {
+            results,
+            summary,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+            all_findings,
+            deduped_findings,
+        }
+    }
+
+    /// Analyzes the same files under several rule configs, parsing each file
+    /// once and re-running only the rules. Returns one summary per config, in
+    /// request order.
+    #[instrument(skip(self, request))]
+    pub async fn analyze_multi_config(&self, request: MultiConfigRequest) -> AnalysisResult<MultiConfigResponse> {
+        let start_time = Instant::now();
+
+        info!(
+            "Starting analysis of {} files under {} configs",
+            request.files.len(),
+            request.configs.len()
+        );
+
+        // The strictest budget applies, since the parse is shared
+        let node_budget = request.configs.iter().filter_map(|config| config.node_budget).min();
+        let fail_on_unsupported = request
+            .configs
+            .iter()
+            .any(|config| config.fail_on_unsupported.unwrap_or(false));
+        let batch = self.parse_batch(request.files, node_budget, fail_on_unsupported, None)?;
+        let summaries = request
+            .configs
+            .iter()
+            .map(|config| self.evaluate_batch(&batch, config).1)
+            .collect();
+
+        Ok(MultiConfigResponse {
+            summaries,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Runs only the parser over `file` and returns the raw structural data
+    /// (functions, classes, imports and the syntax tree), without evaluating
+    /// rules or computing metrics.
+    ///
+    /// ```
+    /// use rust_analysis_engine::{analysis::AnalysisEngine, types::SourceFile};
+    ///
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let engine = AnalysisEngine::new().await.unwrap();
+    /// let file = SourceFile {
+    ///     name: "math.js".to_string(),
+    ///     content: "import { sum } from './sum';\nfunction double(x) { return sum(x, x); }".to_string(),
+    ///     language: None,
+    ///     analyze_range: None,
+    ///     parser_override: None,
+    /// };
+    ///
+    /// let parsed = engine.extract(&file).unwrap();
+    /// assert_eq!(parsed.functions[0].name, "double");
+    /// assert_eq!(parsed.imports[0].module, "./sum");
+    /// # });
+    /// ```
+    pub fn extract(&self, file: &SourceFile) -> AnalysisResult<ParseResult> {
+        let language = resolve_language(file)?;
+        self.parse(file, &language)
+    }
+
+    fn parser_for(&self, language: &Language) -> AnalysisResult<&dyn Parser> {
+        self.parser_registry.get_parser(language).ok_or_else(|| {
+            AnalysisError::UnsupportedLanguage {
+                language: format!("{:?}", language),
+            }
+        })
+    }
+
+    /// Whether a parser is registered for `language`.
+    pub fn has_parser(&self, language: &Language) -> bool {
+        self.parser_registry.get_parser(language).is_some()
+    }
+
+    fn parse(&self, file: &SourceFile, language: &Language) -> AnalysisResult<ParseResult> {
+        let parser_language = file.parser_override.as_ref().unwrap_or(language);
+        self.parser_for(parser_language)?.parse_file(&file.name, &file.content)
+    }
+
+    /// Parses every file, stopping early once the node budget is spent.
+    /// Files whose content (and name-dependent traits) repeat an earlier file
+    /// are recorded as duplicates instead of being parsed again. With a
+    /// `cache_config`, files found in the disk cache aren't parsed either.
+    /// Files in a language without a parser are left out, or fail the whole
+    /// batch with `fail_on_unsupported`.
+    fn parse_batch(
+        &self,
+        files: Vec<SourceFile>,
+        node_budget: Option<u64>,
+        fail_on_unsupported: bool,
+        cache_config: Option<&RuleConfig>,
+    ) -> AnalysisResult<ParsedBatch> {
+        let total_requested = files.len();
+        let mut entries = Vec::new();
+        let mut unsupported_files = Vec::new();
+        let mut parsed: HashMap<DuplicateKey, usize> = HashMap::new();
+        let mut budget = NodeBudget::new(node_budget);
+
+        // Cached timings would describe an earlier run, so timing bypasses the cache
+        let cache = self
+            .cache
+            .as_ref()
+            .zip(cache_config)
+            .filter(|(_, config)| !config.include_timing.unwrap_or(false));
+
+        for file in files {
+            if budget.is_exhausted() {
+                warn!(
+                    "Node budget exhausted after {} of {} files, returning partial results",
+                    entries.len(),
+                    total_requested
+                );
+                break;
+            }
+
+            let key = duplicate_key(&file)?;
+            if let Some(&original) = parsed.get(&key) {
+                entries.push(BatchEntry::Duplicate {
+                    file_name: file.name,
+                    original,
+                });
+                continue;
+            }
+
+            let language = resolve_language(&file)?;
+            if file.parser_override.is_none() && !self.has_parser(&language) {
+                if fail_on_unsupported {
+                    return Err(AnalysisError::UnsupportedLanguage {
+                        language: format!("{:?}", language),
+                    });
+                }
+                warn!("No parser for {:?}, skipping {}", language, file.name);
+                unsupported_files.push(file.name);
+                continue;
+            }
+
+            let cache_key = cache.map(|(cache, config)| cache.key(&file, &language, config, &self.rule_set));
+            if let Some(cached) = cache.zip(cache_key.as_ref()).and_then(|((cache, _), key)| cache.get(key)) {
+                parsed.insert(key, entries.len());
+                entries.push(BatchEntry::Cached(cached));
+                continue;
+            }
+
+            let parse_start = Instant::now();
+            let parse_result = self.parse(&file, &language)?;
+            let parse_micros = parse_start.elapsed().as_micros() as u64;
+            budget.charge(&parse_result.tree);
+            *self.parse_counts.entry(language.clone()).or_insert(0) += 1;
+
+            parsed.insert(key, entries.len());
+            entries.push(BatchEntry::Parsed(ParsedFile {
+                file,
+                language,
+                parse_result,
+                parse_micros,
+                cache_key,
+            }));
+        }
+
+        Ok(ParsedBatch {
+            files_skipped: (total_requested - entries.len() - unsupported_files.len()) as u32,
+            budget_exceeded: budget.is_exhausted(),
+            entries,
+            unsupported_files,
+        })
+    }
+
+    /// Runs the rules over an already parsed batch under `config`.
+    fn evaluate_batch(&self, batch: &ParsedBatch, config: &RuleConfig) -> (Vec<FileAnalysisResult>, AnalysisSummary) {
+        let mut results: Vec<FileAnalysisResult> = Vec::new();
+        let mut imports: Vec<FileImports> = Vec::new();
+
+        for entry in &batch.entries {
+            match entry {
+                BatchEntry::Parsed(parsed) => {
+                    let (file_result, file_imports) = self.evaluate_file(parsed, config);
+                    if let Some((cache, key)) = self.cache.as_ref().zip(parsed.cache_key.as_ref()) {
+                        cache.put(
+                            key,
+                            &CachedFile {
+                                result: file_result.clone(),
+                                imports: file_imports.clone(),
+                            },
+                        );
+                    }
+                    results.push(file_result);
+                    imports.push(file_imports);
+                }
+                BatchEntry::Cached(cached) => {
+                    results.push(cached.result.clone());
+                    imports.push(cached.imports.clone());
+                }
+                // Identical files are analyzed once; copies reuse the result
+                BatchEntry::Duplicate { file_name, original } => {
+                    let mut copy = results[*original].clone();
+                    copy.file_name = file_name.clone();
+                    results.push(copy);
+                    imports.push(imports[*original].clone());
+                }
+            }
+        }
+
+        coupling::apply(&mut results, &imports, config);
+        if let Some(overrides) = &config.suggestion_overrides {
+            for file_result in &mut results {
+                apply_suggestion_overrides(&mut file_result.findings, overrides);
+            }
+        }
+        if let Some(base_url) = &config.rule_doc_base_url {
+            for file_result in &mut results {
+                apply_doc_urls(&mut file_result.findings, base_url);
+            }
+        }
+        if config.explain.unwrap_or(false) {
+            let rationales: HashMap<String, String> =
+                self.rules().into_iter().map(|rule| (rule.id, rule.rationale)).collect();
+            for finding in results.iter_mut().flat_map(|result| &mut result.findings) {
+                finding.rationale = rationales.get(&finding.rule_id).cloned();
+            }
+        }
+
+        if let Some(min_severity) = &config.min_severity {
+            for file_result in &mut results {
+                file_result.findings.retain(|finding| finding.severity >= *min_severity);
+            }
+        }
+        if let Some(min_confidence) = config.min_confidence {
+            for file_result in &mut results {
+                file_result.findings.retain(|finding| finding.confidence >= min_confidence);
+            }
+        }
+        if config.sort_findings.unwrap_or(false) {
+            for file_result in &mut results {
+                sort_by_severity(&mut file_result.findings);
+            }
+        }
+
         let mut total_lines = 0u32;
         let mut total_findings = 0u32;
         let mut findings_by_severity: HashMap<String, u32> = HashMap::new();
-
-        for file in request.files {
-            let file_result = self.analyze_file(file, &request.rules).await?;
-            
+        let mut findings_by_directory: Option<HashMap<String, u32>> =
+            config.group_by_directory.unwrap_or(false).then(HashMap::new);
+        let mut languages_analyzed: HashMap<Language, u32> = HashMap::new();
+        let mut lines_by_language: HashMap<Language, u32> = HashMap::new();
+        for file_result in &results {
             total_lines += file_result.metrics.lines_of_code;
             total_findings += file_result.findings.len() as u32;
+            *languages_analyzed.entry(file_result.language.clone()).or_insert(0) += 1;
+            *lines_by_language.entry(file_result.language.clone()).or_insert(0) += file_result.metrics.lines_of_code;
             
             // Count findings by severity
             for finding in &file_result.findings {
                 let severity_str = format!("{:?}", finding.severity);
                 *findings_by_severity.entry(severity_str).or_insert(0) += 1;
             }
-            
-            results.push(file_result);
+            if let Some(by_directory) = &mut findings_by_directory {
+                *by_directory.entry(top_level_directory(&file_result.file_name)).or_insert(0) +=
+                    file_result.findings.len() as u32;
+            }
         }
 
-        let execution_time = start_time.elapsed();
-        
-        info!(
-            "Analysis completed in {}ms, {} findings across {} lines",
-            execution_time.as_millis(),
+        let summary = AnalysisSummary {
+            total_files: results.len() as u32,
             total_findings,
-            total_lines
-        );
+            findings_by_severity,
+            total_lines_analyzed: total_lines,
+            budget_exceeded: batch.budget_exceeded,
+            files_skipped: batch.files_skipped,
+            unsupported_files: batch.unsupported_files.clone(),
+            truncated: false,
+            truncation_reason: None,
+            findings_by_directory,
+            sampled: false,
+            sample_size: None,
+            languages_analyzed,
+            lines_by_language,
+        };
 
-        Ok(AnalysisResponse {
-            results,
-            summary: AnalysisSummary {
-                total_files: results.len() as u32,
-                total_findings,
-                findings_by_severity,
-                total_lines_analyzed: total_lines,
-            },
-            execution_time_ms: execution_time.as_millis() as u64,
-        })
+        (results, summary)
     }
 
-    async fn analyze_file(
-        &self,
-        mut file: SourceFile,
-        _rule_config: &Option<crate::types::RuleConfig>,
-    ) -> AnalysisResult<FileAnalysisResult> {
-        // Detect language if not provided
-        let language = match file.language {
-            Some(lang) => lang,
-            None => Language::from_filename(&file.name)
-                .ok_or_else(|| AnalysisError::UnsupportedLanguage {
-                    language: file.name.split('.').last().unwrap_or("unknown").to_string(),
-                })?,
+    fn evaluate_file(&self, parsed: &ParsedFile, config: &RuleConfig) -> (FileAnalysisResult, FileImports) {
+        let ParsedFile { file, language, .. } = parsed;
+
+        // Options that rewrite parser output work on a copy so the shared
+        // parse stays untouched for other configs
+        let normalize_imports = config.normalize_import_paths.unwrap_or(false);
+        let discount_guards = config.discount_guard_clauses.unwrap_or(false);
+        let adjusted;
+        let parse_result = if normalize_imports || discount_guards {
+            let mut copy = parsed.parse_result.clone();
+            if normalize_imports {
+                copy.normalize_imports();
+            }
+            if discount_guards {
+                copy.discount_guard_clauses();
+            }
+            adjusted = copy;
+            &adjusted
+        } else {
+            &parsed.parse_result
         };
 
-        // For now, return basic metrics and placeholder findings
-        // This will be replaced with actual parsing and analysis in later tasks
+        // `str::lines` also strips the `\r` of `\r\n`, so CRLF files count the
+        // same lines an editor shows.
+        let metrics_start = Instant::now();
         let lines_of_code = file.content.lines().count() as u32;
-        
-        // Create some basic findings for demonstration
-        let mut findings = Vec::new();
-        
-        // Simple demonstration: flag functions that might be too simple
-        if file.content.contains("function") && file.content.lines().count() < 5 {
-            findings.push(Finding {
-                rule_id: "demo-simple-function".to_string(),
-                severity: Severity::Low,
-                message: "Function appears to be very simple".to_string(),
-                location: crate::types::Location {
-                    line: 1,
-                    column: 1,
-                    end_line: None,
-                    end_column: None,
-                },
-                suggestion: Some("Consider if this function adds value".to_string()),
-            });
+        let todo_density = todo_density(parse_result.tree.root_node(), &file.content);
+        let iife_count = iife_count(parse_result.tree.root_node(), &file.content);
+        let longest_regex = longest_regex(parse_result.tree.root_node(), &file.content);
+        let class_cohesion = parse_result
+            .classes
+            .iter()
+            .filter_map(|class| {
+                Some(ClassCohesion {
+                    class_name: class.name.clone(),
+                    line: class.line,
+                    cohesion: class.cohesion?,
+                })
+            })
+            .collect();
+        let is_small = lines_of_code <= config.small_file_max_lines.unwrap_or(DEFAULT_SMALL_FILE_MAX_LINES);
+        let halstead = (!is_small).then(|| halstead::compute(parse_result.tree.root_node(), &file.content));
+        let metrics_micros = metrics_start.elapsed().as_micros() as u64;
+
+        let rule_run = if config.metrics_only.unwrap_or(false) {
+            self.rule_set.skip_all(METRICS_ONLY_REASON)
+        } else {
+            self.rule_set.run(&RuleContext {
+                file_name: &file.name,
+                language: language.clone(),
+                source: &file.content,
+                parse_result,
+                config,
+            })
+        };
+        let mut findings = rule_run.findings;
+
+        if config.dedupe_findings.unwrap_or(true) {
+            dedupe_findings(&mut findings);
+        }
+        if let Some(range) = file.analyze_range {
+            retain_in_range(&mut findings, range);
         }
+        if config.include_enclosing_function.unwrap_or(false) {
+            apply_enclosing_functions(&mut findings, &parse_result.functions);
+        }
+        if let Some(context) = config.snippet_context_lines {
+            for finding in &mut findings {
+                finding.snippet = Some(snippet::extract(&file.content, &finding.location, context));
+            }
+        }
+
+        // Coupling findings are only known once the whole batch is in, so
+        // their anchor gets the range and snippet treatment here instead
+        let anchor = Some(coupling::anchor(&parse_result.imports, &file.content))
+            .filter(|anchor| file.analyze_range.is_none_or(|range| in_range(anchor.line, range)));
+        let imports = FileImports {
+            specifiers: parse_result.imports.iter().map(|import| import.raw_module.clone()).collect(),
+            snippet: anchor
+                .as_ref()
+                .zip(config.snippet_context_lines)
+                .map(|(anchor, context)| snippet::extract(&file.content, anchor, context)),
+            anchor,
+        };
+
+        let timing_breakdown = config.include_timing.unwrap_or(false).then(|| {
+            let mut timings = HashMap::from([
+                ("parse".to_string(), parsed.parse_micros),
+                ("metrics".to_string(), metrics_micros),
+            ]);
+            for (rule_id, micros) in &rule_run.timings_micros {
+                timings.insert(format!("rule:{}", rule_id), *micros);
+            }
+            timings
+        });
 
-        Ok(FileAnalysisResult {
-            file_name: file.name,
-            language,
+        let result = FileAnalysisResult {
+            file_name: file.name.clone(),
+            language: language.clone(),
             findings,
             metrics: FileMetrics {
                 lines_of_code,
-                functions_count: count_functions(&file.content),
-                classes_count: count_classes(&file.content),
+                functions_count: parse_result.functions.len() as u32,
+                classes_count: parse_result.classes.len() as u32,
                 complexity_score: 1.0, // Placeholder
+                todo_density,
+                iife_count,
+                longest_regex,
+                class_cohesion,
+                halstead,
             },
-        })
+            rules_executed: rule_run.executed,
+            rules_skipped: rule_run.skipped,
+            coupling: CouplingMetrics::default(),
+            timing_breakdown,
+        };
+
+        (result, imports)
+    }
+}
+
+#[derive(Clone)]
+struct ParsedFile {
+    file: SourceFile,
+    language: Language,
+    parse_result: ParseResult,
+    parse_micros: u64,
+    /// Where to store the result, when caching applies to this batch.
+    cache_key: Option<ContentHash>,
+}
+
+enum BatchEntry {
+    Parsed(ParsedFile),
+    /// Result read from the disk cache; the file was not parsed.
+    Cached(CachedFile),
+    /// Same content as the entry at `original`, under another name.
+    Duplicate { file_name: String, original: usize },
+}
+
+#[derive(Default)]
+struct ParsedBatch {
+    entries: Vec<BatchEntry>,
+    files_skipped: u32,
+    budget_exceeded: bool,
+    unsupported_files: Vec<String>,
+}
+
+impl ParsedBatch {
+    /// Names of the parsed files the batch retains.
+    fn file_names(&self) -> HashSet<String> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match entry {
+                BatchEntry::Parsed(parsed) => Some(parsed.file.name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Adds the files of `update`, replacing entries with the same name.
+    /// `update` must be parsed without the cache, since a session keeps
+    /// only parsed entries.
+    fn merge(&mut self, update: ParsedBatch) {
+        let mut files: Vec<Option<ParsedFile>> = Vec::new();
+        for entry in update.entries {
+            let file = match entry {
+                BatchEntry::Parsed(parsed) => Some(parsed),
+                BatchEntry::Cached(_) => None,
+                BatchEntry::Duplicate { file_name, original } => files[original].clone().map(|mut copy| {
+                    copy.file.name = file_name;
+                    copy
+                }),
+            };
+            files.push(file);
+        }
+
+        for parsed in files.into_iter().flatten() {
+            let existing = self.entries.iter_mut().find(|entry| {
+                matches!(entry, BatchEntry::Parsed(retained) if retained.file.name == parsed.file.name)
+            });
+            match existing {
+                Some(entry) => *entry = BatchEntry::Parsed(parsed),
+                None => self.entries.push(BatchEntry::Parsed(parsed)),
+            }
+        }
+
+        self.files_skipped = update.files_skipped;
+        self.budget_exceeded = update.budget_exceeded;
+        self.unsupported_files = update.unsupported_files;
+    }
+}
+
+/// Removes findings that repeat an earlier `(rule_id, line, column, message)`,
+/// keeping the first occurrence.
+fn dedupe_findings(findings: &mut Vec<Finding>) {
+    let mut seen = HashSet::new();
+    findings.retain(|finding| {
+        seen.insert((
+            finding.rule_id.clone(),
+            finding.location.line,
+            finding.location.column,
+            finding.message.clone(),
+        ))
+    });
+}
+
+/// Replaces the suggestion of every finding whose rule has an override,
+/// expanding `{rule}` to the rule id.
+fn apply_suggestion_overrides(findings: &mut [Finding], overrides: &HashMap<String, String>) {
+    for finding in findings {
+        if let Some(template) = overrides.get(&finding.rule_id) {
+            finding.suggestion = Some(template.replace("{rule}", &finding.rule_id));
+        }
+    }
+}
+
+/// Links every finding to `<base_url>/<rule id>`.
+fn apply_doc_urls(findings: &mut [Finding], base_url: &str) {
+    let base_url = base_url.trim_end_matches('/');
+    for finding in findings {
+        finding.doc_url = Some(format!("{}/{}", base_url, finding.rule_id));
+    }
+}
+
+/// Most severe first; ties keep source order.
+fn sort_by_severity(findings: &mut [Finding]) {
+    findings.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then(a.location.line.cmp(&b.location.line))
+            .then(a.location.column.cmp(&b.location.column))
+    });
+}
+
+/// Names the innermost named function whose lines contain each finding.
+fn apply_enclosing_functions(findings: &mut [Finding], functions: &[FunctionInfo]) {
+    for finding in findings {
+        let line = finding.location.line;
+        finding.enclosing_function = functions
+            .iter()
+            .filter(|function| function.name != "anonymous" && (function.line..=function.end_line).contains(&line))
+            .max_by_key(|function| (function.line, Reverse(function.end_line)))
+            .map(|function| function.name.clone());
+    }
+}
+
+/// First path segment of `file_name`, or `.` for files at the root.
+fn top_level_directory(file_name: &str) -> String {
+    let path = file_name.trim_start_matches("./").trim_start_matches(['/', '\\']);
+    match path.split_once(['/', '\\']) {
+        Some((directory, _)) => directory.to_string(),
+        None => ".".to_string(),
+    }
+}
+
+/// The files in the `RuleConfig.sample_rate` sample, or all of them when
+/// sampling is off.
+fn sample(mut files: Vec<SourceFile>, config: &RuleConfig) -> Vec<SourceFile> {
+    if let Some(rate) = config.sample_rate {
+        let requested = files.len();
+        files.retain(|file| in_sample(&file.name, rate));
+        info!("Sampled {} of {} files", files.len(), requested);
+    }
+    files
+}
+
+/// Whether `file_name` falls in a `rate` sample. The name's hash decides,
+/// so a file is consistently in or out of the sample for a given rate.
+fn in_sample(file_name: &str, rate: f64) -> bool {
+    let ContentHash(digest) = ContentHash::from_content(file_name);
+    let position = u64::from_str_radix(&digest[..16], 16).unwrap_or(0);
+    (position as f64 / u64::MAX as f64) < rate
+}
+
+/// Keeps findings that start inside the inclusive line range. A reversed
+/// range is treated as its ascending equivalent, and a range past the end
+/// of the file simply leaves nothing to report.
+fn retain_in_range(findings: &mut Vec<Finding>, range: (u32, u32)) {
+    findings.retain(|finding| in_range(finding.location.line, range));
+}
+
+fn in_range(line: u32, (start, end): (u32, u32)) -> bool {
+    (start.min(end)..=start.max(end)).contains(&line)
+}
+
+/// Everything besides the path that rules depend on: content, language,
+/// extension (JSX or module handling), whether it is a test file, and the
+/// reported line range.
+#[derive(PartialEq, Eq, Hash)]
+struct DuplicateKey {
+    content: ContentHash,
+    language: Language,
+    extension: String,
+    is_test: bool,
+    analyze_range: Option<(u32, u32)>,
+    parser_override: Option<Language>,
+}
+
+fn duplicate_key(file: &SourceFile) -> AnalysisResult<DuplicateKey> {
+    Ok(DuplicateKey {
+        content: ContentHash::from_content(&file.content),
+        language: resolve_language(file)?,
+        extension: file.name.rsplit_once('.').map_or("", |(_, ext)| ext).to_string(),
+        is_test: is_test_file(&file.name),
+        analyze_range: file.analyze_range,
+        parser_override: file.parser_override.clone(),
+    })
+}
+
+// Detect language if not provided
+fn resolve_language(file: &SourceFile) -> AnalysisResult<Language> {
+    match &file.language {
+        Some(lang) => Ok(lang.clone()),
+        None => Language::from_filename(&file.name).ok_or_else(|| AnalysisError::UnsupportedLanguage {
+            language: file.name.split('.').next_back().unwrap_or("unknown").to_string(),
+        }),
     }
 }
 
-// Simple placeholder functions for basic metrics
-fn count_functions(content: &str) -> u32 {
-    content.matches("function").count() as u32
-        + content.matches("const ").count() as u32 // Arrow functions approximation
-        + content.matches("let ").count() as u32   // Arrow functions approximation
+/// Caps the total number of syntax nodes analyzed across one request so a
+/// single batch can't monopolize a worker. Once the budget is spent, the
+/// remaining files are skipped.
+struct NodeBudget {
+    limit: Option<u64>,
+    used: u64,
 }
 
-fn count_classes(content: &str) -> u32 {
-    content.matches("class ").count() as u32
-}
\ No newline at end of file
+impl NodeBudget {
+    fn new(limit: Option<u64>) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    fn charge(&mut self, tree: &Tree) {
+        if self.limit.is_some() {
+            self.used += count_nodes(tree);
+        }
+    }
+
+    fn is_exhausted(&self) -> bool {
+        self.limit.is_some_and(|limit| self.used >= limit)
+    }
+}
+
+fn count_nodes(tree: &Tree) -> u64 {
+    let mut cursor = tree.walk();
+    let mut count = 1;
+
+    loop {
+        if cursor.goto_first_child() || cursor.goto_next_sibling() {
+            count += 1;
+            continue;
+        }
+        loop {
+            if !cursor.goto_parent() {
+                return count;
+            }
+            if cursor.goto_next_sibling() {
+                count += 1;
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Source excerpts around a finding, for clients that show findings the
+//! way an editor would rather than as bare positions.
+
+use crate::types::{Location, SnippetLine};
+
+/// The lines `location` spans plus up to `context` lines either side,
+/// clamped to the file. The spanned lines are marked `highlighted`.
+pub fn extract(source: &str, location: &Location, context: u32) -> Vec<SnippetLine> {
+    let lines: Vec<&str> = source.lines().collect();
+    let total = lines.len() as u32;
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let start = location.line.clamp(1, total);
+    let end = location.end_line.unwrap_or(start).clamp(start, total);
+    let first = start.saturating_sub(context).max(1);
+    let last = end.saturating_add(context).min(total);
+
+    (first..=last)
+        .map(|line| SnippetLine {
+            line,
+            text: lines[line as usize - 1].to_string(),
+            highlighted: (start..=end).contains(&line),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(line: u32, end_line: Option<u32>) -> Location {
+        Location {
+            line,
+            column: 1,
+            end_line,
+            end_column: None,
+        }
+    }
+
+    fn window(snippet: &[SnippetLine]) -> Vec<(u32, bool)> {
+        snippet.iter().map(|line| (line.line, line.highlighted)).collect()
+    }
+
+    #[test]
+    fn test_window_is_clamped_at_the_start_of_the_file() {
+        let source = "const a = 1;\nconst b = a == 1;\nconst c = 3;\nconst d = 4;\nconst e = 5;\n";
+
+        let snippet = extract(source, &at(2, None), 2);
+
+        assert_eq!(window(&snippet), vec![(1, false), (2, true), (3, false), (4, false)]);
+        assert_eq!(snippet[1].text, "const b = a == 1;");
+    }
+
+    #[test]
+    fn test_multi_line_spans_and_the_end_of_the_file() {
+        let source = "one\r\ntwo\r\nthree\r\nfour";
+
+        let snippet = extract(source, &at(3, Some(4)), 1);
+
+        assert_eq!(window(&snippet), vec![(2, false), (3, true), (4, true)]);
+        assert_eq!(snippet[0].text, "two");
+        assert!(extract("", &at(1, None), 2).is_empty());
+    }
+}
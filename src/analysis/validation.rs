@@ -0,0 +1,118 @@
+//! Sanity checks for a `RuleConfig`, shared by `/rules/validate` and the
+//! analysis endpoints so a config is accepted or rejected the same way.
+
+use crate::types::RuleConfig;
+
+/// Describes every problem with `config`. `known_rules` are the ids that
+/// `disabled_rules`, `rule_order` and `suggestion_overrides` may refer to.
+pub fn config_errors(config: &RuleConfig, known_rules: &[&str]) -> Vec<String> {
+    let mut errors = Vec::new();
+
+    // A zero limit would flag every function or skip every file
+    let positive = [
+        ("complexity_threshold", config.complexity_threshold.map(u64::from)),
+        ("max_function_length", config.max_function_length.map(u64::from)),
+        ("max_statements", config.max_statements.map(u64::from)),
+        ("max_literal_elements", config.max_literal_elements.map(u64::from)),
+        ("max_return_object_properties", config.max_return_object_properties.map(u64::from)),
+        ("max_regex_length", config.max_regex_length.map(u64::from)),
+        ("max_destructure_depth", config.max_destructure_depth.map(u64::from)),
+        ("node_budget", config.node_budget),
+    ];
+    for (field, value) in positive {
+        if value == Some(0) {
+            errors.push(format!("{} must be at least 1", field));
+        }
+    }
+
+    if let Some(density) = config.max_todo_density {
+        if !density.is_finite() || density < 0.0 {
+            errors.push(format!("max_todo_density must be a non-negative number, got {}", density));
+        }
+    }
+
+    let fractions = [
+        ("sample_rate", config.sample_rate),
+        ("min_class_cohesion", config.min_class_cohesion),
+        ("min_confidence", config.min_confidence),
+    ];
+    for (field, value) in fractions {
+        if let Some(value) = value.filter(|value| !(0.0..=1.0).contains(value)) {
+            errors.push(format!("{} must be between 0 and 1, got {}", field, value));
+        }
+    }
+
+    let rule_lists = [("disabled_rules", &config.disabled_rules), ("rule_order", &config.rule_order)];
+    for (field, ids) in rule_lists {
+        for id in ids.iter().flatten() {
+            if !known_rules.contains(&id.as_str()) {
+                errors.push(format!("{}: unknown rule '{}'", field, id));
+            }
+        }
+    }
+
+    let mut overrides: Vec<_> = config.suggestion_overrides.iter().flatten().collect();
+    overrides.sort();
+    for (id, text) in overrides {
+        if !known_rules.contains(&id.as_str()) {
+            errors.push(format!("suggestion_overrides: unknown rule '{}'", id));
+        }
+        if text.trim().is_empty() {
+            errors.push(format!("suggestion_overrides: empty suggestion for '{}'", id));
+        }
+    }
+
+    let patterns = [
+        ("dead_code_exemptions", &config.dead_code_exemptions),
+        ("secret_name_patterns", &config.secret_name_patterns),
+        ("path_allowlist", &config.path_allowlist),
+    ];
+    for (field, values) in patterns {
+        if values.iter().flatten().any(|pattern| pattern.trim().is_empty()) {
+            errors.push(format!("{}: patterns must not be empty", field));
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const RULES: &[&str] = &["float-equality", "high-coupling"];
+
+    #[test]
+    fn test_default_and_empty_configs_are_valid() {
+        let empty: RuleConfig = serde_json::from_str("{}").unwrap();
+
+        assert!(config_errors(&RuleConfig::default(), RULES).is_empty());
+        assert!(config_errors(&empty, RULES).is_empty());
+    }
+
+    #[test]
+    fn test_every_problem_is_reported() {
+        let config = RuleConfig {
+            complexity_threshold: Some(0),
+            max_todo_density: Some(-1.0),
+            sample_rate: Some(1.5),
+            disabled_rules: Some(vec!["float-equality".to_string(), "float-equalty".to_string()]),
+            suggestion_overrides: Some(HashMap::from([("high-coupling".to_string(), " ".to_string())])),
+            secret_name_patterns: Some(vec!["".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config_errors(&config, RULES),
+            vec![
+                "complexity_threshold must be at least 1",
+                "max_todo_density must be a non-negative number, got -1",
+                "sample_rate must be between 0 and 1, got 1.5",
+                "disabled_rules: unknown rule 'float-equalty'",
+                "suggestion_overrides: empty suggestion for 'high-coupling'",
+                "secret_name_patterns: patterns must not be empty",
+            ]
+        );
+    }
+}
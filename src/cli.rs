@@ -0,0 +1,377 @@
+//! Command-line mode: `rust-analysis-engine analyze [OPTIONS] <FILES>...`
+//!
+//! Analyzes files from disk, or a single file piped to stdin with `--stdin`,
+//! prints one line per finding, and maps the result onto a process exit code
+//! for CI gates.
+
+use std::env;
+use std::io::{Read, Write};
+
+use crate::{
+    analysis::AnalysisEngine,
+    error::{AnalysisError, AnalysisResult},
+    github,
+    types::{AnalysisRequest, AnalysisResponse, AnalysisSummary, FindingWithFile, Language, SourceFile},
+};
+
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FINDINGS: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+/// Input couldn't be read or the analysis failed.
+pub const EXIT_ERROR: i32 = 3;
+
+pub const USAGE: &str = "Usage: rust-analysis-engine analyze [--max-warnings <N>] [--format text|github] <FILES>...
+       rust-analysis-engine analyze [--max-warnings <N>] [--format text|github] --stdin --lang <LANG>";
+
+/// File name reported for findings in content read from stdin.
+pub const STDIN_FILE_NAME: &str = "<stdin>";
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CliOptions {
+    pub files: Vec<String>,
+    /// Fail when the total number of findings exceeds this count, regardless
+    /// of severity. Falls back to `RAE_MAX_WARNINGS` when the flag is absent.
+    pub max_warnings: Option<u32>,
+    /// Report layout; `text` when unset.
+    pub format: OutputFormat,
+    /// Analyze stdin instead of `files`.
+    pub stdin: bool,
+    /// Language of the stdin content, which has no file name to detect it
+    /// from. Required with `stdin`.
+    pub language: Option<Language>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    /// `file:line:col: Severity [rule] message`, plus a summary line.
+    #[default]
+    Text,
+    /// GitHub Actions workflow commands, one per finding.
+    Github,
+}
+
+impl CliOptions {
+    /// Parses the arguments that follow the `analyze` subcommand.
+    pub fn parse<I>(args: I) -> AnalysisResult<Self>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut options = CliOptions {
+            max_warnings: env::var("RAE_MAX_WARNINGS")
+                .ok()
+                .map(|value| parse_count("RAE_MAX_WARNINGS", &value))
+                .transpose()?,
+            ..Default::default()
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--max-warnings" => {
+                    let value = args.next().ok_or_else(|| usage_error("--max-warnings requires a value"))?;
+                    options.max_warnings = Some(parse_count("--max-warnings", &value)?);
+                }
+                "--format" => {
+                    let value = args.next().ok_or_else(|| usage_error("--format requires a value"))?;
+                    options.format = match value.as_str() {
+                        "text" => OutputFormat::Text,
+                        "github" => OutputFormat::Github,
+                        _ => return Err(usage_error(&format!("Unknown format: {}", value))),
+                    };
+                }
+                "--stdin" => options.stdin = true,
+                "--lang" => {
+                    let value = args.next().ok_or_else(|| usage_error("--lang requires a value"))?;
+                    options.language = Some(parse_language(&value)?);
+                }
+                flag if flag.starts_with("--") => {
+                    return Err(usage_error(&format!("Unknown option: {}", flag)));
+                }
+                _ => options.files.push(arg),
+            }
+        }
+
+        if options.stdin {
+            if !options.files.is_empty() {
+                return Err(usage_error("Files cannot be combined with --stdin"));
+            }
+            if options.language.is_none() {
+                return Err(usage_error("--stdin requires --lang"));
+            }
+        } else if options.files.is_empty() {
+            return Err(usage_error("At least one file must be provided"));
+        }
+
+        Ok(options)
+    }
+}
+
+/// Runs the `analyze` subcommand on the arguments that follow it and
+/// returns the process exit code, reporting errors to `err`. Bad arguments
+/// give `EXIT_USAGE`; unreadable files and failed analyses give
+/// `EXIT_ERROR`, so a CI gate can't mistake them for findings.
+pub async fn execute<I>(args: I, input: &mut impl Read, out: &mut impl Write, err: &mut impl Write) -> i32
+where
+    I: IntoIterator<Item = String>,
+{
+    let options = match CliOptions::parse(args) {
+        Ok(options) => options,
+        Err(e) => {
+            let _ = writeln!(err, "{}\n{}", e, USAGE);
+            return EXIT_USAGE;
+        }
+    };
+
+    match run(&options, input, out).await {
+        Ok(code) => code,
+        Err(e) => {
+            let _ = writeln!(err, "{}", e);
+            EXIT_ERROR
+        }
+    }
+}
+
+/// Exit code for a finished analysis under the given options.
+pub fn exit_code(summary: &AnalysisSummary, options: &CliOptions) -> i32 {
+    match options.max_warnings {
+        Some(max) if summary.total_findings > max => EXIT_FINDINGS,
+        _ => EXIT_OK,
+    }
+}
+
+/// Reads the requested files, or all of `input` with `--stdin`, analyzes
+/// them, and writes a report to `out`.
+pub async fn run(options: &CliOptions, input: &mut impl Read, out: &mut impl Write) -> AnalysisResult<i32> {
+    let files = if options.stdin {
+        let mut content = String::new();
+        input.read_to_string(&mut content)?;
+        vec![SourceFile {
+            name: STDIN_FILE_NAME.to_string(),
+            content,
+            language: options.language.clone(),
+            analyze_range: None,
+            parser_override: None,
+        }]
+    } else {
+        options
+            .files
+            .iter()
+            .map(|path| {
+                Ok(SourceFile {
+                    name: path.clone(),
+                    content: std::fs::read_to_string(path)
+                        .map_err(|e| std::io::Error::new(e.kind(), format!("{}: {}", path, e)))?,
+                    language: None,
+                    analyze_range: None,
+                    parser_override: None,
+                })
+            })
+            .collect::<AnalysisResult<Vec<_>>>()?
+    };
+
+    let engine = AnalysisEngine::new().await?;
+    let response = engine.analyze(AnalysisRequest { files, rules: None }).await?;
+
+    match options.format {
+        OutputFormat::Text => write_report(&response, out)?,
+        OutputFormat::Github => write!(out, "{}", github::render(&response))?,
+    }
+
+    let code = exit_code(&response.summary, options);
+    if code == EXIT_FINDINGS {
+        if let Some(max) = options.max_warnings {
+            writeln!(
+                out,
+                "Too many findings: {} (max: {})",
+                response.summary.total_findings, max
+            )?;
+        }
+    }
+
+    Ok(code)
+}
+
+fn write_report(response: &AnalysisResponse, out: &mut impl Write) -> AnalysisResult<()> {
+    for FindingWithFile { file_name, finding } in response.reported_findings() {
+        writeln!(
+            out,
+            "{}:{}:{}: {:?} [{}] {}",
+            file_name,
+            finding.location.line,
+            finding.location.column,
+            finding.severity,
+            finding.rule_id,
+            finding.message
+        )?;
+    }
+
+    writeln!(
+        out,
+        "{} findings in {} files ({} lines)",
+        response.summary.total_findings,
+        response.summary.total_files,
+        response.summary.total_lines_analyzed
+    )?;
+
+    Ok(())
+}
+
+/// Languages `--lang` accepts: those with a parser.
+fn parse_language(value: &str) -> AnalysisResult<Language> {
+    match value.to_ascii_lowercase().as_str() {
+        "javascript" | "js" => Ok(Language::JavaScript),
+        "typescript" | "ts" => Ok(Language::TypeScript),
+        "json" => Ok(Language::Json),
+        _ => Err(usage_error(&format!(
+            "Unsupported language: {} (expected javascript, typescript or json)",
+            value
+        ))),
+    }
+}
+
+fn parse_count(name: &str, value: &str) -> AnalysisResult<u32> {
+    value
+        .parse()
+        .map_err(|_| usage_error(&format!("{} must be a non-negative integer, got '{}'", name, value)))
+}
+
+fn usage_error(message: &str) -> AnalysisError {
+    AnalysisError::ConfigError {
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    fn summary(total_findings: u32) -> AnalysisSummary {
+        AnalysisSummary {
+            total_files: 1,
+            total_findings,
+            findings_by_severity: HashMap::new(),
+            total_lines_analyzed: 10,
+            budget_exceeded: false,
+            files_skipped: 0,
+            unsupported_files: Vec::new(),
+            truncated: false,
+            truncation_reason: None,
+            findings_by_directory: None,
+            sampled: false,
+            sample_size: None,
+            languages_analyzed: HashMap::new(),
+            lines_by_language: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_parse_max_warnings() {
+        let options = CliOptions::parse(args(&["--max-warnings", "3", "a.js", "b.ts"])).unwrap();
+
+        assert_eq!(options.max_warnings, Some(3));
+        assert_eq!(options.files, vec!["a.js", "b.ts"]);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_max_warnings() {
+        assert!(CliOptions::parse(args(&["--max-warnings", "-1", "a.js"])).is_err());
+        assert!(CliOptions::parse(args(&["a.js", "--max-warnings"])).is_err());
+        assert!(CliOptions::parse(args(&["--max-warnings", "0"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let options = CliOptions::parse(args(&["--format", "github", "a.js"])).unwrap();
+
+        assert_eq!(options.format, OutputFormat::Github);
+        assert_eq!(CliOptions::parse(args(&["a.js"])).unwrap().format, OutputFormat::Text);
+        assert!(CliOptions::parse(args(&["--format", "sarif", "a.js"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_stdin_requires_supported_lang() {
+        let options = CliOptions::parse(args(&["--stdin", "--lang", "TypeScript"])).unwrap();
+
+        assert!(options.stdin);
+        assert_eq!(options.language, Some(Language::TypeScript));
+        assert!(CliOptions::parse(args(&["--stdin"])).is_err());
+        assert!(CliOptions::parse(args(&["--stdin", "--lang", "python"])).is_err());
+        assert!(CliOptions::parse(args(&["--stdin", "--lang", "js", "a.js"])).is_err());
+    }
+
+    #[test]
+    fn test_max_warnings_zero_fails_on_any_finding() {
+        let options = CliOptions {
+            max_warnings: Some(0),
+            ..Default::default()
+        };
+
+        assert_eq!(exit_code(&summary(0), &options), EXIT_OK);
+        assert_eq!(exit_code(&summary(1), &options), EXIT_FINDINGS);
+    }
+
+    #[test]
+    fn test_no_max_warnings_never_fails_on_count() {
+        assert_eq!(exit_code(&summary(500), &CliOptions::default()), EXIT_OK);
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_findings_and_exit_code() {
+        let path = env::temp_dir().join(format!("rae-cli-{}.jsx", std::process::id()));
+        std::fs::write(
+            &path,
+            "function App() {\n  if (x) { useState(0); }\n  return <div />;\n}\n",
+        )
+        .unwrap();
+
+        let options = CliOptions {
+            files: vec![path.display().to_string()],
+            max_warnings: Some(0),
+            ..Default::default()
+        };
+        let mut out = Vec::new();
+        let code = run(&options, &mut std::io::empty(), &mut out).await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let report = String::from_utf8(out).unwrap();
+        assert_eq!(code, EXIT_FINDINGS);
+        assert!(report.contains(":2:12: High [hooks-rule-violation]"));
+        assert!(report.contains("Too many findings"));
+    }
+
+    #[tokio::test]
+    async fn test_unreadable_file_exits_with_error_code() {
+        let path = env::temp_dir().join(format!("rae-cli-missing-{}.js", std::process::id()));
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+
+        let code = execute(args(&[&path.display().to_string()]), &mut std::io::empty(), &mut out, &mut err).await;
+
+        assert_eq!(code, EXIT_ERROR);
+        assert_ne!(code, EXIT_FINDINGS);
+        assert!(String::from_utf8(err).unwrap().contains(&path.display().to_string()));
+        assert_eq!(
+            execute(args(&["--format", "sarif", "a.js"]), &mut std::io::empty(), &mut out, &mut Vec::new()).await,
+            EXIT_USAGE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_reads_stdin() {
+        let options = CliOptions::parse(args(&["--stdin", "--lang", "typescript"])).unwrap();
+        let mut input = "const total: number = 0.1 + 0.2;\nif (total === 0.3) {}\n".as_bytes();
+        let mut out = Vec::new();
+
+        let code = run(&options, &mut input, &mut out).await.unwrap();
+
+        let report = String::from_utf8(out).unwrap();
+        assert_eq!(code, EXIT_OK);
+        assert!(report.contains("<stdin>:2:5: Medium [float-equality]"), "{}", report);
+        assert!(report.ends_with("in 1 files (2 lines)\n"));
+    }
+}
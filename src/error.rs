@@ -39,28 +39,78 @@ pub enum AnalysisError {
 
     #[error("Internal error: {message}")]
     InternalError { message: String },
+
+    #[error("Not found: {resource}")]
+    NotFound { resource: String },
+
+    #[error("Too many {resource} in flight; retry later")]
+    TooManyRequests { resource: String },
+
+    /// `error`, caused by the request field at `field` (e.g. `files[3].content`).
+    #[error("{error}")]
+    InvalidField { field: String, error: Box<AnalysisError> },
+}
+
+impl AnalysisError {
+    /// Attributes this error to the request field at `path`.
+    pub fn at_field(self, path: impl Into<String>) -> Self {
+        AnalysisError::InvalidField {
+            field: path.into(),
+            error: Box::new(self),
+        }
+    }
+
+    /// Stable identifier for the kind of error, for clients to match on.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AnalysisError::ParseError { .. } => "parse_error",
+            AnalysisError::TimeoutError { .. } => "timeout",
+            AnalysisError::ResourceError { .. } => "resource_exhausted",
+            AnalysisError::ConfigError { .. } => "config_error",
+            AnalysisError::ValidationError { .. } => "validation_error",
+            AnalysisError::UnsupportedLanguage { .. } => "unsupported_language",
+            AnalysisError::FileTooLarge { .. } => "file_too_large",
+            AnalysisError::IoError(_) => "io_error",
+            AnalysisError::JsonError(_) => "invalid_json",
+            AnalysisError::InternalError { .. } => "internal_error",
+            AnalysisError::NotFound { .. } => "not_found",
+            AnalysisError::TooManyRequests { .. } => "too_many_requests",
+            AnalysisError::InvalidField { error, .. } => error.code(),
+        }
+    }
 }
 
 impl IntoResponse for AnalysisError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            AnalysisError::ValidationError { .. } => (StatusCode::BAD_REQUEST, self.to_string()),
-            AnalysisError::UnsupportedLanguage { .. } => (StatusCode::BAD_REQUEST, self.to_string()),
-            AnalysisError::FileTooLarge { .. } => (StatusCode::PAYLOAD_TOO_LARGE, self.to_string()),
-            AnalysisError::TimeoutError { .. } => (StatusCode::REQUEST_TIMEOUT, self.to_string()),
-            AnalysisError::ResourceError { .. } => (StatusCode::SERVICE_UNAVAILABLE, self.to_string()),
+        let (field, error) = match self {
+            AnalysisError::InvalidField { field, error } => (Some(field), *error),
+            error => (None, error),
+        };
+
+        let (status, error_message) = match error {
+            AnalysisError::ValidationError { .. } => (StatusCode::BAD_REQUEST, error.to_string()),
+            AnalysisError::UnsupportedLanguage { .. } => (StatusCode::BAD_REQUEST, error.to_string()),
+            AnalysisError::FileTooLarge { .. } => (StatusCode::PAYLOAD_TOO_LARGE, error.to_string()),
+            AnalysisError::TimeoutError { .. } => (StatusCode::REQUEST_TIMEOUT, error.to_string()),
+            AnalysisError::ResourceError { .. } => (StatusCode::SERVICE_UNAVAILABLE, error.to_string()),
             AnalysisError::JsonError(_) => (StatusCode::BAD_REQUEST, "Invalid JSON format".to_string()),
+            AnalysisError::NotFound { .. } => (StatusCode::NOT_FOUND, error.to_string()),
+            AnalysisError::TooManyRequests { .. } => (StatusCode::TOO_MANY_REQUESTS, error.to_string()),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error".to_string()),
         };
 
-        let body = Json(json!({
+        let mut body = json!({
             "error": {
                 "message": error_message,
-                "type": format!("{:?}", self).split('(').next().unwrap_or("Unknown"),
+                "type": format!("{:?}", error).split('(').next().unwrap_or("Unknown"),
+                "code": error.code(),
                 "status": status.as_u16()
             }
-        }));
+        });
+        if let Some(field) = field {
+            body["error"]["field"] = json!(field);
+        }
 
-        (status, body).into_response()
+        (status, Json(body)).into_response()
     }
 }
\ No newline at end of file
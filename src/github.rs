@@ -0,0 +1,89 @@
+//! GitHub Actions workflow commands, for `/analyze?format=github` and
+//! `analyze --format github`.
+//!
+//! Each finding becomes one `::error`/`::warning`/`::notice` line, which the
+//! Actions runner turns into an inline annotation on the pull request.
+
+use std::fmt::Write;
+
+use crate::types::{AnalysisResponse, Finding, Severity};
+
+pub const CONTENT_TYPE: &str = "text/plain; charset=utf-8";
+
+pub fn render(response: &AnalysisResponse) -> String {
+    let mut out = String::new();
+
+    for reported in response.reported_findings() {
+        let _ = writeln!(out, "{}", command(&reported.file_name, &reported.finding));
+    }
+
+    out
+}
+
+fn command(file_name: &str, finding: &Finding) -> String {
+    let level = match finding.severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "notice",
+    };
+
+    format!(
+        "::{} file={},line={},col={},title={}::{}",
+        level,
+        escape_property(file_name),
+        finding.location.line,
+        finding.location.column,
+        escape_property(&finding.rule_id),
+        escape_data(&finding.message),
+    )
+}
+
+/// Escapes a command message, where `%`, CR and LF would otherwise be read
+/// as escapes or end the command.
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a property value, which additionally can't contain the `:` and
+/// `,` delimiters.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Location;
+
+    fn finding(severity: Severity, message: &str) -> Finding {
+        Finding::new(
+            "float-equality",
+            severity,
+            message.to_string(),
+            Location {
+                line: 3,
+                column: 7,
+                end_line: None,
+                end_column: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_severity_maps_to_command_level() {
+        assert!(command("a.js", &finding(Severity::Critical, "x")).starts_with("::error "));
+        assert!(command("a.js", &finding(Severity::High, "x")).starts_with("::error "));
+        assert!(command("a.js", &finding(Severity::Medium, "x")).starts_with("::warning "));
+        assert!(command("a.js", &finding(Severity::Low, "x")).starts_with("::notice "));
+    }
+
+    #[test]
+    fn test_message_and_properties_are_escaped() {
+        let line = command("src/a,b:c.js", &finding(Severity::Medium, "100% sure\r\nreally"));
+
+        assert_eq!(
+            line,
+            "::warning file=src/a%2Cb%3Ac.js,line=3,col=7,title=float-equality::100%25 sure%0D%0Areally"
+        );
+    }
+}
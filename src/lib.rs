@@ -3,5 +3,8 @@ pub mod server;
 pub mod types;
 pub mod parser;
 pub mod analysis;
+pub mod cli;
+pub mod github;
+pub mod rules;
 
 pub use error::{AnalysisError, AnalysisResult};
\ No newline at end of file
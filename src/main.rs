@@ -1,8 +1,16 @@
-use rust_analysis_engine::server::Server;
+use rust_analysis_engine::{cli, server::Server};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+
+    // CLI mode: analyze files from disk or stdin and exit
+    if args.next().as_deref() == Some("analyze") {
+        let code = cli::execute(args, &mut std::io::stdin(), &mut std::io::stdout(), &mut std::io::stderr()).await;
+        std::process::exit(code);
+    }
+
     // Initialize tracing
     tracing_subscriber::registry()
         .with(
@@ -17,4 +25,4 @@ async fn main() -> anyhow::Result<()> {
     server.run().await?;
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,13 +1,17 @@
-use std::collections::HashMap;
 use tracing::{debug, warn, instrument};
 use tree_sitter::{Language, Node, Parser as TSParser, Tree};
 
 use crate::{
     error::{AnalysisError, AnalysisResult},
-    types::{Language as EngineLanguage, Location},
+    types::Language as EngineLanguage,
 };
 
-use super::{Parser, ParseResult, FunctionInfo, ClassInfo, ImportInfo};
+use super::{
+    class_cohesion, class_references, count_guard_clauses, count_statements, function_signature, is_exported,
+    method_accesses,
+    visitor::{walk_tree, ComplexityVisitor, Visitor},
+    ClassInfo, FunctionInfo, ImportInfo, ImportKind, ParseResult, Parser,
+};
 
 pub struct JavaScriptParser {
     language: Language,
@@ -53,45 +57,14 @@ impl JavaScriptParser {
     }
 
     fn extract_functions(&self, tree: &Tree, source: &str) -> Vec<FunctionInfo> {
-        let mut functions = Vec::new();
-        let root_node = tree.root_node();
-        
-        self.traverse_for_functions(&root_node, source, &mut functions);
-        
-        debug!("Extracted {} functions", functions.len());
-        functions
-    }
-
-    fn traverse_for_functions(&self, node: &Node, source: &str, functions: &mut Vec<FunctionInfo>) {
-        match node.kind() {
-            "function_declaration" => {
-                if let Some(function_info) = self.extract_function_declaration(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "arrow_function" => {
-                if let Some(function_info) = self.extract_arrow_function(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "method_definition" => {
-                if let Some(function_info) = self.extract_method_definition(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "function_expression" => {
-                if let Some(function_info) = self.extract_function_expression(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            _ => {}
-        }
+        let mut collector = FunctionCollector {
+            parser: self,
+            functions: Vec::new(),
+        };
+        walk_tree(tree.root_node(), source, &mut collector);
 
-        // Recursively traverse child nodes
-        let mut cursor = node.walk();
-        for child in node.children(&mut cursor) {
-            self.traverse_for_functions(&child, source, functions);
-        }
+        debug!("Extracted {} functions", collector.functions.len());
+        collector.functions
     }
 
     fn extract_function_declaration(&self, node: &Node, source: &str) -> Option<FunctionInfo> {
@@ -101,7 +74,12 @@ impl JavaScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -127,7 +105,12 @@ impl JavaScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -138,7 +121,12 @@ impl JavaScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -165,7 +153,12 @@ impl JavaScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -196,10 +189,15 @@ impl JavaScriptParser {
     fn extract_class_declaration(&self, node: &Node, source: &str) -> Option<ClassInfo> {
         let name_node = node.child_by_field_name("name")?;
         let name = self.get_node_text(&name_node, source)?;
+        let methods = method_accesses(node, source);
         
         Some(ClassInfo {
             name,
             line: node.start_position().row as u32 + 1,
+            references: class_references(node, source),
+            is_exported: is_exported(node, source),
+            cohesion: class_cohesion(&methods),
+            methods,
         })
     }
 
@@ -244,8 +242,10 @@ impl JavaScriptParser {
         let module = module.trim_matches('"').trim_matches('\'').to_string();
         
         Some(ImportInfo {
+            raw_module: module.clone(),
             module,
             line: node.start_position().row as u32 + 1,
+            kind: ImportKind::Static,
         })
     }
 
@@ -264,8 +264,10 @@ impl JavaScriptParser {
                     let module = module.trim_matches('"').trim_matches('\'').to_string();
                     
                     return Some(ImportInfo {
+                        raw_module: module.clone(),
                         module,
                         line: node.start_position().row as u32 + 1,
+                        kind: ImportKind::Require,
                     });
                 }
             }
@@ -274,41 +276,10 @@ impl JavaScriptParser {
         None
     }
 
-    fn calculate_complexity(&self, node: &Node) -> u32 {
-        let mut complexity = 1; // Base complexity
-        
-        self.traverse_for_complexity(node, &mut complexity);
-        
-        complexity
-    }
-
-    fn traverse_for_complexity(&self, node: &Node, complexity: &mut u32) {
-        match node.kind() {
-            // Decision points that increase complexity
-            "if_statement" | "while_statement" | "for_statement" | "for_in_statement" 
-            | "for_of_statement" | "do_statement" | "switch_statement" | "catch_clause"
-            | "conditional_expression" => {
-                *complexity += 1;
-            }
-            // Logical operators
-            "binary_expression" => {
-                if let Some(operator) = node.child_by_field_name("operator") {
-                    let mut cursor = operator.walk();
-                    if let Some(op_node) = operator.children(&mut cursor).next() {
-                        if matches!(op_node.kind(), "&&" | "||") {
-                            *complexity += 1;
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-
-        // Recursively traverse child nodes
-        let mut cursor = node.walk();
-        for child in node.children(&mut cursor) {
-            self.traverse_for_complexity(&child, complexity);
-        }
+    fn calculate_complexity(&self, node: &Node, source: &str) -> u32 {
+        let mut visitor = ComplexityVisitor::default();
+        walk_tree(*node, source, &mut visitor);
+        visitor.complexity
     }
 
     fn get_node_text(&self, node: &Node, source: &str) -> Option<String> {
@@ -323,6 +294,40 @@ impl JavaScriptParser {
     }
 }
 
+/// Collects a `FunctionInfo` for every function-like node in the tree.
+struct FunctionCollector<'p> {
+    parser: &'p JavaScriptParser,
+    functions: Vec<FunctionInfo>,
+}
+
+impl Visitor<'_> for FunctionCollector<'_> {
+    fn visit(&mut self, node: &Node, source: &str) {
+        match node.kind() {
+            "function_declaration" => {
+                if let Some(function_info) = self.parser.extract_function_declaration(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "arrow_function" => {
+                if let Some(function_info) = self.parser.extract_arrow_function(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "method_definition" => {
+                if let Some(function_info) = self.parser.extract_method_definition(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "function_expression" => {
+                if let Some(function_info) = self.parser.extract_function_expression(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 impl Parser for JavaScriptParser {
     fn language(&self) -> EngineLanguage {
         EngineLanguage::JavaScript
@@ -341,13 +346,19 @@ impl Parser for JavaScriptParser {
             functions,
             classes,
             imports,
+            tree,
         })
     }
+
+    fn parse_tree(&self, _file_name: &str, content: &str) -> AnalysisResult<Tree> {
+        self.parse_with_tree_sitter(content)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::MAX_COMPLEXITY;
 
     #[test]
     fn test_parse_simple_function() {
@@ -433,6 +444,18 @@ mod tests {
         assert_eq!(result.functions[0].complexity, 6);
     }
 
+    #[test]
+    fn test_complexity_stops_at_ceiling() {
+        let parser = JavaScriptParser::new().unwrap();
+        let branches: String = (0..2000).map(|i| format!("if (x === {}) {{}}\n", i)).collect();
+        let content = format!("function huge(x) {{\n{}}}\n", branches);
+
+        let result = parser.parse(&content).unwrap();
+
+        assert_eq!(result.functions[0].complexity, MAX_COMPLEXITY);
+        assert!(result.functions[0].complexity_overflowed());
+    }
+
     #[test]
     fn test_syntax_error_handling() {
         let parser = JavaScriptParser::new().unwrap();
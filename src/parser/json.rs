@@ -0,0 +1,78 @@
+use tracing::{instrument, warn};
+use tree_sitter::{Language, Parser as TSParser, Tree};
+
+use crate::{
+    error::{AnalysisError, AnalysisResult},
+    types::Language as EngineLanguage,
+};
+
+use super::{ParseResult, Parser};
+
+/// Parses JSON config files so rules can inspect their key/value pairs.
+///
+/// JSON is a subset of JavaScript expression syntax, so the JavaScript
+/// grammar yields the same `object`/`pair`/`string` nodes a JSON grammar
+/// would. Config files have no functions, classes or imports.
+pub struct JsonParser {
+    language: Language,
+}
+
+impl JsonParser {
+    pub fn new() -> AnalysisResult<Self> {
+        let language = tree_sitter_javascript::language();
+        Ok(Self { language })
+    }
+}
+
+impl Parser for JsonParser {
+    fn language(&self) -> EngineLanguage {
+        EngineLanguage::Json
+    }
+
+    #[instrument(skip(self, content))]
+    fn parse(&self, content: &str) -> AnalysisResult<ParseResult> {
+        Ok(ParseResult {
+            language: EngineLanguage::Json,
+            functions: Vec::new(),
+            classes: Vec::new(),
+            imports: Vec::new(),
+            tree: self.parse_tree("", content)?,
+        })
+    }
+
+    fn parse_tree(&self, _file_name: &str, content: &str) -> AnalysisResult<Tree> {
+        let mut parser = TSParser::new();
+        parser.set_language(self.language).map_err(|e| AnalysisError::ConfigError {
+            message: format!("Failed to set JSON language: {}", e),
+        })?;
+        parser.set_timeout_micros(5_000_000);
+
+        let tree = parser.parse(content, None).ok_or_else(|| AnalysisError::ParseError {
+            message: "Failed to parse JSON content".to_string(),
+            line: 1,
+        })?;
+
+        if tree.root_node().has_error() {
+            warn!("JSON parsing completed with syntax errors");
+        }
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config_object() {
+        let parser = JsonParser::new().unwrap();
+        let result = parser.parse("{\n  \"name\": \"app\",\n  \"port\": 8080\n}\n").unwrap();
+
+        assert_eq!(result.language, EngineLanguage::Json);
+        assert!(result.functions.is_empty());
+        let root = result.tree.root_node();
+        assert!(!root.has_error());
+        assert!(root.to_sexp().contains("(object (pair"));
+    }
+}
@@ -1,13 +1,16 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use tracing::info;
+use tree_sitter::{Node, Tree};
 
 use crate::{
-    error::{AnalysisError, AnalysisResult},
+    error::AnalysisResult,
     types::Language,
 };
 
 pub mod javascript;
+pub mod json;
 pub mod typescript;
+pub mod visitor;
 
 pub struct ParserRegistry {
     parsers: HashMap<Language, Box<dyn Parser>>,
@@ -16,33 +19,427 @@ pub struct ParserRegistry {
 pub trait Parser: Send + Sync {
     fn language(&self) -> Language;
     fn parse(&self, content: &str) -> AnalysisResult<ParseResult>;
+
+    /// Parses `content` for the given file, letting a parser pick a dialect
+    /// from the file name (e.g. TSX for `.tsx` files).
+    fn parse_file(&self, _file_name: &str, content: &str) -> AnalysisResult<ParseResult> {
+        self.parse(content)
+    }
+
+    /// Builds only the syntax tree, skipping function, class and import
+    /// extraction. Used by syntax-only checks.
+    fn parse_tree(&self, file_name: &str, content: &str) -> AnalysisResult<Tree>;
 }
 
-#[derive(Debug)]
+/// Syntax errors found in a tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyntaxErrors {
+    /// `ERROR` and missing-token nodes. An error node's descendants are not
+    /// counted separately.
+    pub count: u32,
+    /// 1-based line of the first error.
+    pub first_line: Option<u32>,
+}
+
+pub fn syntax_errors(tree: &Tree) -> SyntaxErrors {
+    fn visit(node: Node, errors: &mut SyntaxErrors) {
+        if node.is_error() || node.is_missing() {
+            errors.count += 1;
+            let line = node.start_position().row as u32 + 1;
+            errors.first_line = Some(errors.first_line.map_or(line, |first| first.min(line)));
+            return;
+        }
+        if !node.has_error() {
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            visit(child, errors);
+        }
+    }
+
+    let mut errors = SyntaxErrors { count: 0, first_line: None };
+    visit(tree.root_node(), &mut errors);
+    errors
+}
+
+#[derive(Debug, Clone)]
 pub struct ParseResult {
     pub language: Language,
     pub functions: Vec<FunctionInfo>,
     pub classes: Vec<ClassInfo>,
     pub imports: Vec<ImportInfo>,
+    /// The syntax tree, retained so rules can run without re-parsing.
+    pub tree: Tree,
 }
 
-#[derive(Debug)]
+/// Hard ceiling for cyclomatic complexity. Counting stops once a function
+/// reaches it, so adversarial input can't overflow the counter or force a
+/// traversal of the whole body.
+pub const MAX_COMPLEXITY: u32 = 1000;
+
+#[derive(Debug, Clone)]
 pub struct FunctionInfo {
     pub name: String,
     pub line: u32,
+    /// Line the function ends on, inclusive.
+    pub end_line: u32,
     pub complexity: u32,
+    /// Leading `if (...) return/throw` guards, each counted in `complexity`.
+    pub guard_clauses: u32,
+    /// Statements anywhere in the body, excluding nested function bodies.
+    pub statement_count: u32,
+    /// Exported from the module, directly or through an `export { ... }` list.
+    pub is_exported: bool,
+    /// Parameter list plus return type annotation, if any, as written with
+    /// whitespace collapsed, e.g. `(a: number, b?: string): boolean`.
+    pub signature: String,
 }
 
-#[derive(Debug)]
+impl FunctionInfo {
+    /// Whether complexity counting stopped at `MAX_COMPLEXITY`.
+    pub fn complexity_overflowed(&self) -> bool {
+        self.complexity >= MAX_COMPLEXITY
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct ClassInfo {
     pub name: String,
     pub line: u32,
+    /// Names of types the body refers to through type annotations or `new`
+    /// expressions, sorted and deduplicated.
+    pub references: Vec<String>,
+    /// Exported from the module, directly or through an `export { ... }` list.
+    pub is_exported: bool,
+    /// See `class_cohesion`. Unset for interfaces and classes with fewer
+    /// than two instance methods.
+    pub cohesion: Option<f64>,
+    /// See `method_accesses`. Empty for interfaces.
+    pub methods: Vec<MethodAccess>,
+}
+
+/// The `this.` members one instance method uses, each list sorted.
+#[derive(Debug, Clone)]
+pub struct MethodAccess {
+    pub name: String,
+    pub fields: Vec<String>,
+    /// Other methods of the same class, including itself when recursive.
+    pub calls: Vec<String>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ImportInfo {
+    /// Module specifier, canonicalized when import path normalization is on.
     pub module: String,
+    /// The specifier exactly as written in the source.
+    pub raw_module: String,
     pub line: u32,
+    pub kind: ImportKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// `import ... from` or `export ... from`.
+    Static,
+    /// A CommonJS `require(...)` call.
+    Require,
+    /// An `import(...)` expression, which works in either module system.
+    Dynamic,
+}
+
+/// Extensions stripped from relative specifiers during normalization.
+pub(crate) const MODULE_EXTENSIONS: &[&str] = &[".d.ts", ".tsx", ".ts", ".jsx", ".mjs", ".cjs", ".js"];
+
+impl ParseResult {
+    /// Stops counting leading guard clauses towards each function's
+    /// complexity, for teams that treat early returns as good style.
+    pub fn discount_guard_clauses(&mut self) {
+        for function in &mut self.functions {
+            if !function.complexity_overflowed() {
+                function.complexity = function.complexity.saturating_sub(function.guard_clauses).max(1);
+            }
+        }
+    }
+
+    /// Canonicalizes every relative import so `./utils`, `./utils.js` and
+    /// `./utils/index` all report the same module. `raw_module` is untouched.
+    pub fn normalize_imports(&mut self) {
+        for import in &mut self.imports {
+            import.module = normalize_module_path(&import.raw_module);
+        }
+    }
+}
+
+/// Number of guard clauses at the start of `function`'s body: `if`
+/// statements without an `else` whose body is a lone `return` or `throw`.
+pub(crate) fn count_guard_clauses(function: &Node) -> u32 {
+    let Some(body) = function.child_by_field_name("body") else {
+        return 0;
+    };
+    if body.kind() != "statement_block" {
+        return 0;
+    }
+
+    let mut cursor = body.walk();
+    let count = body
+        .named_children(&mut cursor)
+        .filter(|statement| statement.kind() != "comment")
+        .take_while(is_guard_clause)
+        .count();
+    count as u32
+}
+
+const NESTED_FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function_declaration",
+    "generator_function",
+];
+
+/// Number of statements and declarations in `function`'s body, at any
+/// depth. A nested function counts as one statement if it is declared as
+/// one, but its own body is left to that function.
+pub(crate) fn count_statements(function: &Node) -> u32 {
+    fn visit(node: &Node, count: &mut u32) {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            let kind = child.kind();
+            if kind.ends_with("_statement") || kind.ends_with("_declaration") {
+                *count += 1;
+            }
+            if !NESTED_FUNCTION_KINDS.contains(&kind) {
+                visit(&child, count);
+            }
+        }
+    }
+
+    let mut count = 0;
+    if let Some(body) = function.child_by_field_name("body") {
+        visit(&body, &mut count);
+    }
+    count
+}
+
+/// Type names referenced in `class`'s body: annotated field, parameter and
+/// return types, plus constructors called with `new`.
+pub(crate) fn class_references(class: &Node, source: &str) -> Vec<String> {
+    fn visit(node: &Node, source: &str, references: &mut Vec<String>) {
+        let name = match node.kind() {
+            "type_identifier" => Some(*node),
+            "new_expression" => node
+                .child_by_field_name("constructor")
+                .filter(|constructor| constructor.kind() == "identifier"),
+            _ => None,
+        };
+        if let Some(text) = name.and_then(|name| source.get(name.start_byte()..name.end_byte())) {
+            references.push(text.to_string());
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            visit(&child, source, references);
+        }
+    }
+
+    let mut references = Vec::new();
+    if let Some(body) = class.child_by_field_name("body") {
+        visit(&body, source, &mut references);
+    }
+    references.sort();
+    references.dedup();
+    references
+}
+
+/// Share of instance method pairs that use at least one common `this.`
+/// field, from 0.0 (no two methods share state) to 1.0: the tight class
+/// cohesion variant of LCOM. None with fewer than two methods.
+pub(crate) fn class_cohesion(methods: &[MethodAccess]) -> Option<f64> {
+    if methods.len() < 2 {
+        return None;
+    }
+
+    let mut pairs = 0;
+    let mut connected = 0;
+    for (index, first) in methods.iter().enumerate() {
+        for second in &methods[index + 1..] {
+            pairs += 1;
+            if first.fields.iter().any(|field| second.fields.contains(field)) {
+                connected += 1;
+            }
+        }
+    }
+    Some(connected as f64 / pairs as f64)
+}
+
+/// The `this.` members each instance method of `class` uses, split into
+/// fields and calls to the class's own methods. Constructors and static
+/// methods are left out.
+pub(crate) fn method_accesses(class: &Node, source: &str) -> Vec<MethodAccess> {
+    fn visit<'a>(node: &Node, source: &'a str, members: &mut HashSet<&'a str>) {
+        if node.kind() == "member_expression"
+            && node.child_by_field_name("object").is_some_and(|object| object.kind() == "this")
+        {
+            if let Some(member) = node
+                .child_by_field_name("property")
+                .and_then(|property| source.get(property.start_byte()..property.end_byte()))
+            {
+                members.insert(member);
+            }
+        }
+
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            // A nested class has its own `this`
+            if !matches!(child.kind(), "class" | "class_declaration") {
+                visit(&child, source, members);
+            }
+        }
+    }
+
+    let Some(body) = class.child_by_field_name("body") else {
+        return Vec::new();
+    };
+    let mut cursor = body.walk();
+    let methods: Vec<(&str, Node)> = body
+        .named_children(&mut cursor)
+        .filter(|member| member.kind() == "method_definition")
+        .filter(|method| {
+            let mut cursor = method.walk();
+            let is_static = method.children(&mut cursor).any(|child| child.kind() == "static");
+            !is_static
+        })
+        .filter_map(|method| {
+            let name = method.child_by_field_name("name")?;
+            Some((source.get(name.start_byte()..name.end_byte())?, method))
+        })
+        .filter(|(name, _)| *name != "constructor")
+        .collect();
+
+    let method_names: HashSet<&str> = methods.iter().map(|(name, _)| *name).collect();
+    methods
+        .iter()
+        .map(|(name, method)| {
+            let mut members = HashSet::new();
+            visit(method, source, &mut members);
+            let (mut calls, mut fields): (Vec<&str>, Vec<&str>) =
+                members.into_iter().partition(|member| method_names.contains(member));
+            calls.sort_unstable();
+            fields.sort_unstable();
+            MethodAccess {
+                name: name.to_string(),
+                fields: fields.into_iter().map(String::from).collect(),
+                calls: calls.into_iter().map(String::from).collect(),
+            }
+        })
+        .collect()
+}
+
+/// Whether the declaration at `node` is exported: under an `export`
+/// statement (via its variable declaration, for function values), or named
+/// in a local `export { ... }` list.
+pub(crate) fn is_exported(node: &Node, source: &str) -> bool {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "export_statement" => return true,
+            "variable_declarator" | "lexical_declaration" | "variable_declaration" => current = parent,
+            _ => break,
+        }
+    }
+
+    let name = match node.parent().filter(|parent| parent.kind() == "variable_declarator") {
+        Some(declarator) => declarator.child_by_field_name("name"),
+        None => node.child_by_field_name("name"),
+    };
+    let Some(name) = name.and_then(|name| source.get(name.start_byte()..name.end_byte())) else {
+        return false;
+    };
+
+    let mut root = *node;
+    while let Some(parent) = root.parent() {
+        root = parent;
+    }
+    let mut cursor = root.walk();
+    let exported = root
+        .named_children(&mut cursor)
+        .filter(|statement| statement.kind() == "export_statement" && statement.child_by_field_name("source").is_none())
+        .filter_map(|statement| {
+            let mut cursor = statement.walk();
+            let clause = statement.named_children(&mut cursor).find(|child| child.kind() == "export_clause");
+            clause
+        })
+        .any(|clause| {
+            let mut cursor = clause.walk();
+            let found = clause.named_children(&mut cursor).any(|specifier| {
+                specifier
+                    .child_by_field_name("name")
+                    .and_then(|local| source.get(local.start_byte()..local.end_byte()))
+                    == Some(name)
+            });
+            found
+        });
+    exported
+}
+
+/// The parameters and return type of `function`, whitespace collapsed.
+pub(crate) fn function_signature(function: &Node, source: &str) -> String {
+    let text = |field| {
+        function
+            .child_by_field_name(field)
+            .and_then(|node| source.get(node.start_byte()..node.end_byte()))
+    };
+
+    let parameters = match (text("parameters"), text("parameter")) {
+        (Some(parameters), _) => parameters.to_string(),
+        // `x => ...` has a bare identifier instead of a parameter list
+        (None, Some(parameter)) => format!("({})", parameter),
+        (None, None) => "()".to_string(),
+    };
+    let signature = format!("{}{}", parameters, text("return_type").unwrap_or_default());
+    signature.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn is_guard_clause(statement: &Node) -> bool {
+    if statement.kind() != "if_statement" || statement.child_by_field_name("alternative").is_some() {
+        return false;
+    }
+    let Some(consequence) = statement.child_by_field_name("consequence") else {
+        return false;
+    };
+
+    let exit = if consequence.kind() == "statement_block" {
+        if consequence.named_child_count() != 1 {
+            return false;
+        }
+        consequence.named_child(0)
+    } else {
+        Some(consequence)
+    };
+
+    exit.is_some_and(|exit| matches!(exit.kind(), "return_statement" | "throw_statement"))
+}
+
+/// Strips the file extension and a trailing `/index` from relative module
+/// specifiers. Bare package specifiers are returned unchanged.
+pub fn normalize_module_path(module: &str) -> String {
+    let is_relative = module == "." || module == ".." || module.starts_with("./") || module.starts_with("../");
+    if !is_relative {
+        return module.to_string();
+    }
+
+    let mut path = module.trim_end_matches('/');
+    if let Some(extension) = MODULE_EXTENSIONS.iter().find(|ext| path.ends_with(*ext)) {
+        path = &path[..path.len() - extension.len()];
+    }
+    if let Some(dir) = path.strip_suffix("/index") {
+        path = dir;
+    }
+
+    path.to_string()
 }
 
 impl ParserRegistry {
@@ -56,6 +453,10 @@ impl ParserRegistry {
         // Register TypeScript parser
         let ts_parser = typescript::TypeScriptParser::new()?;
         parsers.insert(Language::TypeScript, Box::new(ts_parser));
+
+        // Register JSON parser
+        let json_parser = json::JsonParser::new()?;
+        parsers.insert(Language::Json, Box::new(json_parser));
         
         info!("Parser registry initialized with {} parsers", parsers.len());
         
@@ -69,4 +470,118 @@ impl ParserRegistry {
     pub fn supported_languages(&self) -> Vec<Language> {
         self.parsers.keys().cloned().collect()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_variants_normalize_to_same_module() {
+        assert_eq!(normalize_module_path("./utils"), "./utils");
+        assert_eq!(normalize_module_path("./utils.js"), "./utils");
+        assert_eq!(normalize_module_path("./utils/index"), "./utils");
+        assert_eq!(normalize_module_path("./utils/index.ts"), "./utils");
+        assert_eq!(normalize_module_path("../lib/types.d.ts"), "../lib/types");
+        assert_eq!(normalize_module_path("./index"), ".");
+    }
+
+    #[test]
+    fn test_guard_clauses_can_be_discounted() {
+        let parser = javascript::JavaScriptParser::new().unwrap();
+        let content = r#"
+            function save(user) {
+                if (!user) return;
+                if (!user.id) {
+                    throw new Error('missing id');
+                }
+                if (user.deleted) { log(user); return; }
+                for (const field of user.fields) {
+                    if (field.dirty) write(field);
+                }
+            }
+        "#;
+
+        let mut result = parser.parse(content).unwrap();
+        // Base(1) + 3 leading ifs + for + nested if
+        assert_eq!(result.functions[0].complexity, 6);
+        assert_eq!(result.functions[0].guard_clauses, 2);
+
+        result.discount_guard_clauses();
+        assert_eq!(result.functions[0].complexity, 4);
+    }
+
+    #[test]
+    fn test_bare_specifiers_are_not_normalized() {
+        assert_eq!(normalize_module_path("lodash/index.js"), "lodash/index.js");
+        assert_eq!(normalize_module_path("@scope/pkg/index"), "@scope/pkg/index");
+        assert_eq!(normalize_module_path("chart.js"), "chart.js");
+    }
+
+    #[test]
+    fn test_statement_count_excludes_nested_function_bodies() {
+        let parser = javascript::JavaScriptParser::new().unwrap();
+        let content = r#"
+            function process(items) {
+                const total = 0;
+                for (const item of items) {
+                    if (item.skip) {
+                        continue;
+                    }
+                    total += item.value;
+                }
+                items.forEach((item) => {
+                    log(item);
+                    audit(item);
+                });
+                return total;
+            }
+        "#;
+
+        let result = parser.parse(content).unwrap();
+        let process = result.functions.iter().find(|f| f.name == "process").unwrap();
+
+        // const, for, if, continue, +=, forEach call, return
+        assert_eq!(process.statement_count, 7);
+    }
+
+    #[test]
+    fn test_class_cohesion_counts_method_pairs_sharing_fields() {
+        let parser = javascript::JavaScriptParser::new().unwrap();
+        let content = r#"
+            class Cart {
+                constructor() { this.items = []; this.total = 0; }
+                add(item) { this.items.push(item); this.refresh(); }
+                remove(item) { this.items = this.items.filter((i) => i !== item); }
+                refresh() { this.total = 0; }
+                static empty() { return new Cart(); }
+            }
+            class Single {
+                run() { this.state = 1; }
+            }
+        "#;
+
+        let result = parser.parse(content).unwrap();
+
+        // Of add/remove, add/refresh and remove/refresh, only add/remove share a field
+        assert!((result.classes[0].cohesion.unwrap() - 1.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(result.classes[1].cohesion, None);
+
+        let add = &result.classes[0].methods[0];
+        assert_eq!((add.name.as_str(), add.fields.clone(), add.calls.clone()), ("add", vec!["items".to_string()], vec!["refresh".to_string()]));
+        assert_eq!(result.classes[0].methods.len(), 3);
+    }
+
+    #[test]
+    fn test_syntax_errors_count_and_first_line() {
+        let parser = javascript::JavaScriptParser::new().unwrap();
+
+        let valid = parser.parse_tree("ok.js", "const a = 1;\n").unwrap();
+        assert_eq!(syntax_errors(&valid), SyntaxErrors { count: 0, first_line: None });
+
+        let invalid = parser.parse_tree("bad.js", "const a = 1;\nfunction (\nconst b = ;\n").unwrap();
+        let errors = syntax_errors(&invalid);
+        assert!(errors.count >= 1);
+        assert_eq!(errors.first_line, Some(2));
+    }
+}
@@ -1,19 +1,25 @@
-use std::collections::HashMap;
 use tracing::{debug, warn, instrument};
 use tree_sitter::{Language, Node, Parser as TSParser, Tree};
 
 use crate::{
     error::{AnalysisError, AnalysisResult},
-    types::{Language as EngineLanguage, Location},
+    types::Language as EngineLanguage,
 };
 
-use super::{Parser, ParseResult, FunctionInfo, ClassInfo, ImportInfo};
+use super::{
+    class_cohesion, class_references, count_guard_clauses, count_statements, function_signature, is_exported,
+    method_accesses,
+    visitor::{walk_tree, ComplexityVisitor, Visitor},
+    ClassInfo, FunctionInfo, ImportInfo, ImportKind, ParseResult, Parser,
+};
 
 #[derive(Debug)]
 pub struct TypeInfo {
     pub name: String,
     pub line: u32,
     pub kind: TypeKind,
+    /// Member names, for enums.
+    pub members: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -33,20 +39,41 @@ pub struct InterfaceInfo {
     pub extends: Vec<String>,
 }
 
+/// Member names of an `enum_declaration`, in declaration order.
+pub fn enum_members(node: &Node, source: &str) -> Vec<String> {
+    let Some(body) = node.child_by_field_name("body") else {
+        return Vec::new();
+    };
+
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter_map(|member| match member.kind() {
+            "property_identifier" => Some(member),
+            "enum_assignment" => member.child_by_field_name("name"),
+            _ => None,
+        })
+        .filter_map(|name| name.utf8_text(source.as_bytes()).ok())
+        .map(str::to_string)
+        .collect()
+}
+
 pub struct TypeScriptParser {
     language: Language,
+    tsx_language: Language,
 }
 
 impl TypeScriptParser {
     pub fn new() -> AnalysisResult<Self> {
         let language = tree_sitter_typescript::language_typescript();
-        Ok(Self { language })
+        let tsx_language = tree_sitter_typescript::language_tsx();
+        Ok(Self { language, tsx_language })
     }
 
     #[instrument(skip(self, content))]
-    fn parse_with_tree_sitter(&self, content: &str) -> AnalysisResult<Tree> {
+    fn parse_with_tree_sitter(&self, content: &str, tsx: bool) -> AnalysisResult<Tree> {
+        let language = if tsx { self.tsx_language } else { self.language };
         let mut parser = TSParser::new();
-        parser.set_language(self.language).map_err(|e| {
+        parser.set_language(language).map_err(|e| {
             AnalysisError::ConfigError {
                 message: format!("Failed to set TypeScript language: {}", e),
             }
@@ -77,50 +104,14 @@ impl TypeScriptParser {
     }
 
     fn extract_functions(&self, tree: &Tree, source: &str) -> Vec<FunctionInfo> {
-        let mut functions = Vec::new();
-        let root_node = tree.root_node();
-        
-        self.traverse_for_functions(&root_node, source, &mut functions);
-        
-        debug!("Extracted {} functions", functions.len());
-        functions
-    }
-
-    fn traverse_for_functions(&self, node: &Node, source: &str, functions: &mut Vec<FunctionInfo>) {
-        match node.kind() {
-            "function_declaration" => {
-                if let Some(function_info) = self.extract_function_declaration(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "arrow_function" => {
-                if let Some(function_info) = self.extract_arrow_function(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "method_definition" | "method_signature" => {
-                if let Some(function_info) = self.extract_method_definition(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "function_expression" => {
-                if let Some(function_info) = self.extract_function_expression(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            "function_signature" => {
-                if let Some(function_info) = self.extract_function_signature(node, source) {
-                    functions.push(function_info);
-                }
-            }
-            _ => {}
-        }
+        let mut collector = FunctionCollector {
+            parser: self,
+            functions: Vec::new(),
+        };
+        walk_tree(tree.root_node(), source, &mut collector);
 
-        // Recursively traverse child nodes
-        let mut cursor = node.walk();
-        for child in node.children(&mut cursor) {
-            self.traverse_for_functions(&child, source, functions);
-        }
+        debug!("Extracted {} functions", collector.functions.len());
+        collector.functions
     }
 
     fn extract_function_declaration(&self, node: &Node, source: &str) -> Option<FunctionInfo> {
@@ -130,7 +121,12 @@ impl TypeScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -156,7 +152,12 @@ impl TypeScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -167,7 +168,12 @@ impl TypeScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -193,7 +199,12 @@ impl TypeScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
-            complexity: self.calculate_complexity(node),
+            end_line: node.end_position().row as u32 + 1,
+            complexity: self.calculate_complexity(node, source),
+            guard_clauses: count_guard_clauses(node),
+            statement_count: count_statements(node),
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -204,7 +215,12 @@ impl TypeScriptParser {
         Some(FunctionInfo {
             name,
             line: node.start_position().row as u32 + 1,
+            end_line: node.end_position().row as u32 + 1,
             complexity: 1, // Function signatures have minimal complexity
+            guard_clauses: 0,
+            statement_count: 0,
+            is_exported: is_exported(node, source),
+            signature: function_signature(node, source),
         })
     }
 
@@ -243,10 +259,15 @@ impl TypeScriptParser {
     fn extract_class_declaration(&self, node: &Node, source: &str) -> Option<ClassInfo> {
         let name_node = node.child_by_field_name("name")?;
         let name = self.get_node_text(&name_node, source)?;
+        let methods = method_accesses(node, source);
         
         Some(ClassInfo {
             name,
             line: node.start_position().row as u32 + 1,
+            references: class_references(node, source),
+            is_exported: is_exported(node, source),
+            cohesion: class_cohesion(&methods),
+            methods,
         })
     }
 
@@ -257,6 +278,10 @@ impl TypeScriptParser {
         Some(ClassInfo {
             name,
             line: node.start_position().row as u32 + 1,
+            references: class_references(node, source),
+            is_exported: is_exported(node, source),
+            cohesion: None,
+            methods: Vec::new(),
         })
     }
 
@@ -306,8 +331,10 @@ impl TypeScriptParser {
         let module = module.trim_matches('"').trim_matches('\'').to_string();
         
         Some(ImportInfo {
+            raw_module: module.clone(),
             module,
             line: node.start_position().row as u32 + 1,
+            kind: ImportKind::Static,
         })
     }
 
@@ -318,8 +345,10 @@ impl TypeScriptParser {
             let module = module.trim_matches('"').trim_matches('\'').to_string();
             
             Some(ImportInfo {
+                raw_module: module.clone(),
                 module,
                 line: node.start_position().row as u32 + 1,
+                kind: ImportKind::Static,
             })
         } else {
             None
@@ -341,8 +370,10 @@ impl TypeScriptParser {
                     let module = module.trim_matches('"').trim_matches('\'').to_string();
                     
                     return Some(ImportInfo {
+                        raw_module: module.clone(),
                         module,
                         line: node.start_position().row as u32 + 1,
+                        kind: if function_text == "require" { ImportKind::Require } else { ImportKind::Dynamic },
                     });
                 }
             }
@@ -473,6 +504,7 @@ impl TypeScriptParser {
             name,
             line: node.start_position().row as u32 + 1,
             kind: TypeKind::TypeAlias,
+            members: Vec::new(),
         })
     }
 
@@ -484,44 +516,14 @@ impl TypeScriptParser {
             name,
             line: node.start_position().row as u32 + 1,
             kind: TypeKind::Enum,
+            members: enum_members(node, source),
         })
     }
 
-    fn calculate_complexity(&self, node: &Node) -> u32 {
-        let mut complexity = 1; // Base complexity
-        
-        self.traverse_for_complexity(node, &mut complexity);
-        
-        complexity
-    }
-
-    fn traverse_for_complexity(&self, node: &Node, complexity: &mut u32) {
-        match node.kind() {
-            // Decision points that increase complexity
-            "if_statement" | "while_statement" | "for_statement" | "for_in_statement" 
-            | "for_of_statement" | "do_statement" | "switch_statement" | "catch_clause"
-            | "conditional_expression" => {
-                *complexity += 1;
-            }
-            // Logical operators
-            "binary_expression" => {
-                if let Some(operator) = node.child_by_field_name("operator") {
-                    let mut cursor = operator.walk();
-                    if let Some(op_node) = operator.children(&mut cursor).next() {
-                        if matches!(op_node.kind(), "&&" | "||") {
-                            *complexity += 1;
-                        }
-                    }
-                }
-            }
-            _ => {}
-        }
-
-        // Recursively traverse child nodes
-        let mut cursor = node.walk();
-        for child in node.children(&mut cursor) {
-            self.traverse_for_complexity(&child, complexity);
-        }
+    fn calculate_complexity(&self, node: &Node, source: &str) -> u32 {
+        let mut visitor = ComplexityVisitor::default();
+        walk_tree(*node, source, &mut visitor);
+        visitor.complexity
     }
 
     fn get_node_text(&self, node: &Node, source: &str) -> Option<String> {
@@ -534,16 +536,10 @@ impl TypeScriptParser {
             None
         }
     }
-}
-
-impl Parser for TypeScriptParser {
-    fn language(&self) -> EngineLanguage {
-        EngineLanguage::TypeScript
-    }
 
     #[instrument(skip(self, content))]
-    fn parse(&self, content: &str) -> AnalysisResult<ParseResult> {
-        let tree = self.parse_with_tree_sitter(content)?;
+    fn parse_source(&self, content: &str, tsx: bool) -> AnalysisResult<ParseResult> {
+        let tree = self.parse_with_tree_sitter(content, tsx)?;
         
         let functions = self.extract_functions(&tree, content);
         let classes = self.extract_classes(&tree, content);
@@ -558,10 +554,68 @@ impl Parser for TypeScriptParser {
             functions,
             classes,
             imports,
+            tree,
         })
     }
 }
 
+/// Collects a `FunctionInfo` for every function-like node in the tree.
+struct FunctionCollector<'p> {
+    parser: &'p TypeScriptParser,
+    functions: Vec<FunctionInfo>,
+}
+
+impl Visitor<'_> for FunctionCollector<'_> {
+    fn visit(&mut self, node: &Node, source: &str) {
+        match node.kind() {
+            "function_declaration" => {
+                if let Some(function_info) = self.parser.extract_function_declaration(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "arrow_function" => {
+                if let Some(function_info) = self.parser.extract_arrow_function(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "method_definition" | "method_signature" => {
+                if let Some(function_info) = self.parser.extract_method_definition(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "function_expression" => {
+                if let Some(function_info) = self.parser.extract_function_expression(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            "function_signature" => {
+                if let Some(function_info) = self.parser.extract_function_signature(node, source) {
+                    self.functions.push(function_info);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Parser for TypeScriptParser {
+    fn language(&self) -> EngineLanguage {
+        EngineLanguage::TypeScript
+    }
+
+    fn parse(&self, content: &str) -> AnalysisResult<ParseResult> {
+        self.parse_source(content, false)
+    }
+
+    fn parse_file(&self, file_name: &str, content: &str) -> AnalysisResult<ParseResult> {
+        self.parse_source(content, file_name.ends_with(".tsx"))
+    }
+
+    fn parse_tree(&self, file_name: &str, content: &str) -> AnalysisResult<Tree> {
+        self.parse_with_tree_sitter(content, file_name.ends_with(".tsx"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -628,6 +682,7 @@ mod tests {
         
         assert_eq!(result.classes.len(), 1);
         assert_eq!(result.classes[0].name, "Calculator");
+        assert_eq!(result.classes[0].references, vec!["Promise", "T"]);
         
         // Should find constructor, add, and fetchData methods
         assert_eq!(result.functions.len(), 3);
@@ -711,6 +766,10 @@ mod tests {
         "#;
         
         let result = parser.parse(content).unwrap();
+        let tree = parser.parse_with_tree_sitter(content, false).unwrap();
+        let types = parser.extract_types(&tree, content);
+        let color = types.iter().find(|t| matches!(t.kind, TypeKind::Enum)).unwrap();
+        assert_eq!(color.members, vec!["Red", "Green", "Blue"]);
         
         // Enums and types don't count as classes in our current implementation
         // but they are parsed successfully
@@ -0,0 +1,104 @@
+use tree_sitter::Node;
+
+use super::MAX_COMPLEXITY;
+
+/// One pass over a syntax tree. Implementors keep whatever state they
+/// collect and let [`walk_tree`] handle the recursion.
+pub trait Visitor<'tree> {
+    /// Called for every node, parents before children, in source order.
+    fn visit(&mut self, node: &Node<'tree>, source: &str);
+
+    /// Whether to walk into `node`'s children, checked after `visit(node)`.
+    fn descend(&self, _node: &Node<'tree>) -> bool {
+        true
+    }
+}
+
+/// Closures are visitors too, for passes whose only state is what they
+/// capture. Annotate the parameter (`|node: Node|`) so it can be inferred.
+impl<'tree, F: FnMut(Node<'tree>)> Visitor<'tree> for F {
+    fn visit(&mut self, node: &Node<'tree>, _source: &str) {
+        self(*node);
+    }
+}
+
+/// Drives `visitor` over `root` and its descendants.
+pub fn walk_tree<'tree, V: Visitor<'tree> + ?Sized>(root: Node<'tree>, source: &str, visitor: &mut V) {
+    visitor.visit(&root, source);
+    if !visitor.descend(&root) {
+        return;
+    }
+
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        walk_tree(child, source, visitor);
+    }
+}
+
+/// Cyclomatic complexity: 1 plus each branch, loop, catch, ternary and
+/// short-circuit operator. Stops at `MAX_COMPLEXITY`.
+pub struct ComplexityVisitor {
+    pub complexity: u32,
+}
+
+impl Default for ComplexityVisitor {
+    fn default() -> Self {
+        Self { complexity: 1 }
+    }
+}
+
+impl Visitor<'_> for ComplexityVisitor {
+    fn visit(&mut self, node: &Node, _source: &str) {
+        if self.complexity >= MAX_COMPLEXITY {
+            return;
+        }
+
+        let is_decision_point = match node.kind() {
+            "if_statement" | "while_statement" | "for_statement" | "for_in_statement"
+            | "for_of_statement" | "do_statement" | "switch_statement" | "catch_clause"
+            | "conditional_expression" => true,
+            "binary_expression" => node
+                .child_by_field_name("operator")
+                .is_some_and(|operator| matches!(operator.kind(), "&&" | "||")),
+            _ => false,
+        };
+        if is_decision_point {
+            self.complexity = self.complexity.saturating_add(1);
+        }
+    }
+
+    fn descend(&self, _node: &Node) -> bool {
+        self.complexity < MAX_COMPLEXITY
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct KindCounter {
+        kind: &'static str,
+        count: usize,
+    }
+
+    impl Visitor<'_> for KindCounter {
+        fn visit(&mut self, node: &Node, _source: &str) {
+            if node.kind() == self.kind {
+                self.count += 1;
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_tree_visits_every_node() {
+        let source = "const a = 1; const b = [2, 3];";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_javascript::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+
+        let mut numbers = KindCounter { kind: "number", count: 0 };
+        walk_tree(tree.root_node(), source, &mut numbers);
+
+        assert_eq!(numbers.count, 3);
+    }
+}
@@ -0,0 +1,133 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags code whose meaning depends on automatic semicolon insertion: a
+/// `return` whose value starts on the next line (JavaScript returns
+/// `undefined` there), and lines starting with `(` or `[` that silently
+/// continue the previous statement. Opt-in, since teams that always write
+/// semicolons never hit these.
+pub struct AsiHazardRule;
+
+impl Rule for AsiHazardRule {
+    fn id(&self) -> &'static str {
+        "asi-hazard"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Without semicolons, a `return` followed by a line break returns `undefined`, and a line starting with `(` or `[` silently continues the statement above it."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_asi_hazards.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let hazard = match node.kind() {
+                "return_statement" if has_detached_value(&node) => Some((
+                    "Value after 'return' is on the next line, so the function returns undefined",
+                    "Start the returned expression on the same line as 'return'",
+                )),
+                "call_expression" if continued_line(&node, "function", "(") => Some((
+                    "Line starting with '(' is parsed as a call on the previous line",
+                    "End the previous statement with a semicolon",
+                )),
+                "subscript_expression" if continued_line(&node, "object", "[") => Some((
+                    "Line starting with '[' is parsed as an index into the previous line",
+                    "End the previous statement with a semicolon",
+                )),
+                _ => None,
+            };
+
+            if let Some((message, suggestion)) = hazard {
+                findings.push(
+                    Finding::new(self.id(), Severity::Medium, message.to_string(), node_location(&node))
+                        .with_suggestion(suggestion),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Whether the returned expression starts on a later line than `return`.
+fn has_detached_value(statement: &Node) -> bool {
+    statement
+        .named_child(0)
+        .filter(|value| value.kind() != "comment")
+        .is_some_and(|value| value.start_position().row > statement.start_position().row)
+}
+
+/// Whether the `opening` token (`(` or `[`) of `node` begins a new line
+/// after the end of the `head` expression it got attached to.
+fn continued_line(node: &Node, head: &str, opening: &str) -> bool {
+    let Some(head) = node.child_by_field_name(head) else {
+        return false;
+    };
+    let Some(token) = head.next_sibling() else {
+        return false;
+    };
+    // Calls hold their `(` inside the `arguments` node
+    let token = if token.kind() == "arguments" {
+        match token.child(0) {
+            Some(token) => token,
+            None => return false,
+        }
+    } else {
+        token
+    };
+
+    token.kind() == opening && token.start_position().row > head.end_position().row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_asi_hazards: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_return_followed_by_newline_is_flagged() {
+        let source = "function total(items) {\n  return\n    (items.length * 2)\n}\n";
+
+        let findings = check_source_with_config(&AsiHazardRule, "app.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "asi-hazard");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert!(findings[0].message.contains("returns undefined"));
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_lines_starting_with_paren_or_bracket_are_flagged() {
+        let source = "const a = b\n(c || d).run()\nconst e = f\n[1, 2].forEach(g)\n";
+
+        let findings = check_source_with_config(&AsiHazardRule, "app.js", source, &enabled());
+
+        assert_eq!(findings.len(), 2);
+        assert!(findings[0].message.contains("'('"));
+        assert!(findings[1].message.contains("'['"));
+    }
+
+    #[test]
+    fn test_safe_code_and_default_config_are_quiet() {
+        let safe = "function f() {\n  return (\n    value\n  );\n}\nrun(\n  a\n);\nconst x = items[\n  0\n];\n";
+        let hazard = "function f() {\n  return\n  value\n}\n";
+
+        assert!(check_source_with_config(&AsiHazardRule, "app.js", safe, &enabled()).is_empty());
+        assert!(check_source(&AsiHazardRule, "app.js", hazard).is_empty());
+    }
+}
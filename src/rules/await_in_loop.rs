@@ -0,0 +1,115 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `await` in the body of a `for`, `for-in`/`for-of`, `while` or
+/// `do` loop, which runs the iterations one after another. Awaits that
+/// depend on the previous iteration are flagged too, since that can't be
+/// told apart reliably, so the rule is opt-in via
+/// `RuleConfig.detect_await_in_loop`.
+pub struct AwaitInLoopRule;
+
+const LOOP_KINDS: &[&str] = &["for_statement", "for_in_statement", "while_statement", "do_statement"];
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+impl Rule for AwaitInLoopRule {
+    fn id(&self) -> &'static str {
+        "await-in-loop"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Awaiting inside a loop runs independent operations one at a time, so total latency grows with every iteration instead of overlapping."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_await_in_loop.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "await_expression" || !in_loop_body(&node) {
+                return;
+            }
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    "'await' inside a loop runs each iteration sequentially".to_string(),
+                    node_location(&node),
+                )
+                .with_suggestion("If iterations are independent, start them together and await Promise.all"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Whether the nearest enclosing loop of `node`, within the same function,
+/// has it in its body rather than its header.
+fn in_loop_body(node: &Node) -> bool {
+    let mut child = *node;
+    while let Some(parent) = child.parent() {
+        if FUNCTION_KINDS.contains(&parent.kind()) {
+            return false;
+        }
+        if LOOP_KINDS.contains(&parent.kind()) {
+            return parent.child_by_field_name("body").is_some_and(|body| body.id() == child.id());
+        }
+        child = parent;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_await_in_loop: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_await_in_for_of_body_is_flagged() {
+        let source = "async function load(ids) {\n  for (const id of ids) {\n    const user = await fetchUser(id);\n    cache.set(id, user);\n  }\n}\n";
+
+        let findings = check_source_with_config(&AwaitInLoopRule, "load.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "await-in-loop");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (3, 18));
+        assert!(check_source(&AwaitInLoopRule, "load.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_awaits_outside_loop_bodies_are_ignored() {
+        let source = r#"
+            async function run(jobs) {
+                while (await queue.hasNext()) { count++; }
+                for (const job of jobs) {
+                    tasks.push(async () => await job.start());
+                }
+                await Promise.all(tasks);
+            }
+        "#;
+
+        assert!(check_source_with_config(&AwaitInLoopRule, "run.ts", source, &enabled()).is_empty());
+    }
+}
@@ -0,0 +1,117 @@
+use tree_sitter::Node;
+
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, Severity};
+
+const DEFAULT_MAX_REEXPORTS: u32 = 20;
+
+/// Flags barrel files: modules made up almost entirely of `export ... from`
+/// statements, which force bundlers to load every re-exported module.
+pub struct BarrelFileRule;
+
+impl Rule for BarrelFileRule {
+    fn id(&self) -> &'static str {
+        "barrel-file"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Files that only re-export other modules make every importer load the whole tree, slowing builds and defeating tree shaking."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max_reexports = ctx.config.max_reexports.unwrap_or(DEFAULT_MAX_REEXPORTS);
+        let root = ctx.parse_result.tree.root_node();
+
+        let mut reexports = 0u32;
+        let mut cursor = root.walk();
+        for statement in root.named_children(&mut cursor) {
+            if is_reexport(&statement) {
+                reexports += 1;
+            } else if !is_module_plumbing(&statement) {
+                return Vec::new();
+            }
+        }
+
+        if reexports <= max_reexports {
+            return Vec::new();
+        }
+
+        vec![Finding::new(
+            self.id(),
+            Severity::Low,
+            format!(
+                "File only re-exports {} modules (max: {}), which slows down bundlers",
+                reexports, max_reexports
+            ),
+            Location {
+                line: 1,
+                column: 1,
+                end_line: None,
+                end_column: None,
+            },
+        )
+        .with_suggestion("Import from the source modules directly or split the barrel into smaller entry points")]
+    }
+}
+
+fn is_reexport(statement: &Node) -> bool {
+    statement.kind() == "export_statement" && statement.child_by_field_name("source").is_some()
+}
+
+/// Statements that carry no logic of their own: imports, comments, and
+/// plain `export { a, b }` lists.
+fn is_module_plumbing(statement: &Node) -> bool {
+    match statement.kind() {
+        "comment" | "import_statement" | "empty_statement" => true,
+        "export_statement" => {
+            let mut cursor = statement.walk();
+            let mut children = statement.named_children(&mut cursor);
+            children.all(|child| child.kind() == "export_clause")
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    fn barrel(count: usize) -> String {
+        (0..count)
+            .map(|i| format!("export * from './module{}';\n", i))
+            .collect()
+    }
+
+    #[test]
+    fn test_large_barrel_file_is_flagged() {
+        let findings = check_source(&BarrelFileRule, "index.ts", &barrel(25));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "barrel-file");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("25"));
+    }
+
+    #[test]
+    fn test_small_barrel_file_is_allowed() {
+        assert!(check_source(&BarrelFileRule, "index.ts", &barrel(5)).is_empty());
+    }
+
+    #[test]
+    fn test_file_with_logic_is_not_a_barrel() {
+        let mut source = barrel(25);
+        source.push_str("export const version = computeVersion();\n");
+
+        assert!(check_source(&BarrelFileRule, "index.js", &source).is_empty());
+    }
+
+    #[test]
+    fn test_imports_and_export_lists_are_allowed() {
+        let mut source = String::from("// Public API\nimport { a } from './a';\n");
+        source.push_str(&barrel(21));
+        source.push_str("export { a };\n");
+
+        assert_eq!(check_source(&BarrelFileRule, "index.ts", &source).len(), 1);
+    }
+}
@@ -0,0 +1,145 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags synchronous, blocking APIs (`fs.readFileSync`, `execSync`, ...)
+/// called directly inside an `async` function, where they stall the event
+/// loop the function was written to stay off.
+pub struct BlockingInAsyncRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+impl Rule for BlockingInAsyncRule {
+    fn id(&self) -> &'static str {
+        "blocking-in-async"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Synchronous I/O inside an async function blocks the event loop, stalling every other request the process is serving."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some(callee) = node.child_by_field_name("function") else {
+                return;
+            };
+            let Some(name) = blocking_call_name(&callee, ctx.source) else {
+                return;
+            };
+            if !enclosing_function(&node).is_some_and(|function| is_async(&function)) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!("Blocking call '{}' inside an async function", node_text(&callee, ctx.source)),
+                    node_location(&node),
+                )
+                .with_suggestion(&format!(
+                    "Use the asynchronous variant of '{}' and await it",
+                    name.trim_end_matches("Sync")
+                )),
+            );
+        });
+
+        findings
+    }
+}
+
+/// The called function's own name when it follows Node's `*Sync` naming
+/// convention for blocking APIs.
+fn blocking_call_name<'a>(callee: &Node, source: &'a str) -> Option<&'a str> {
+    let name_node = match callee.kind() {
+        "identifier" => *callee,
+        "member_expression" => callee.child_by_field_name("property")?,
+        _ => return None,
+    };
+    let name = node_text(&name_node, source);
+
+    (name.len() > "Sync".len() && name.ends_with("Sync")).then_some(name)
+}
+
+fn enclosing_function<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut current = node.parent();
+    while let Some(ancestor) = current {
+        if FUNCTION_KINDS.contains(&ancestor.kind()) {
+            return Some(ancestor);
+        }
+        current = ancestor.parent();
+    }
+    None
+}
+
+pub(crate) fn is_async(function: &Node) -> bool {
+    let mut cursor = function.walk();
+    let found = function.children(&mut cursor).any(|child| child.kind() == "async");
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_read_file_sync_in_async_function_is_flagged() {
+        let source = r#"
+            const fs = require('fs');
+            async function loadConfig(path) {
+                const raw = fs.readFileSync(path, 'utf8');
+                return JSON.parse(raw);
+            }
+        "#;
+
+        let findings = check_source(&BlockingInAsyncRule, "config.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "blocking-in-async");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert!(findings[0].message.contains("fs.readFileSync"));
+        assert_eq!(findings[0].location.line, 4);
+        assert!(findings[0].suggestion.as_deref().unwrap().contains("'readFile'"));
+    }
+
+    #[test]
+    fn test_async_arrows_and_methods_are_covered() {
+        let source = r#"
+            const build = async () => { execSync('make'); };
+            class Store {
+                async save(data) { writeFileSync('db.json', data); }
+            }
+        "#;
+
+        assert_eq!(check_source(&BlockingInAsyncRule, "build.ts", source).len(), 2);
+    }
+
+    #[test]
+    fn test_sync_calls_outside_async_context_are_ignored() {
+        let source = r#"
+            const config = fs.readFileSync('config.json');
+            function load() { return fs.existsSync('x'); }
+            async function schedule() {
+                setTimeout(function () { fs.unlinkSync('tmp'); }, 10);
+                await fs.promises.readFile('y');
+            }
+        "#;
+
+        assert!(check_source(&BlockingInAsyncRule, "app.js", source).is_empty());
+    }
+}
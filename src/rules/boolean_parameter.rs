@@ -0,0 +1,99 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags functions with two or more boolean parameters, either typed
+/// `boolean` (TypeScript) or defaulting to `true`/`false`. Call sites like
+/// `render(true, false)` don't say what each flag means.
+pub struct BooleanParameterRule;
+
+const MIN_BOOLEAN_PARAMETERS: usize = 2;
+
+impl Rule for BooleanParameterRule {
+    fn id(&self) -> &'static str {
+        "boolean-parameter-smell"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Several boolean parameters make call sites like `render(true, false)` unreadable and easy to get in the wrong order."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "formal_parameters" {
+                return;
+            }
+            let count = (0..node.named_child_count())
+                .filter_map(|i| node.named_child(i))
+                .filter(|parameter| is_boolean_parameter(parameter, ctx.source))
+                .count();
+            if count < MIN_BOOLEAN_PARAMETERS {
+                return;
+            }
+
+            let function = node.parent().unwrap_or(node);
+            let message = match function.child_by_field_name("name") {
+                Some(name) => format!("'{}' takes {} boolean parameters", node_text(&name, ctx.source), count),
+                None => format!("Function takes {} boolean parameters", count),
+            };
+            findings.push(
+                Finding::new(self.id(), Severity::Low, message, node_location(&function))
+                    .with_suggestion("Replace the flags with an options object or an enum"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn is_boolean_parameter(parameter: &Node, source: &str) -> bool {
+    let (annotation, default) = match parameter.kind() {
+        "required_parameter" | "optional_parameter" => {
+            (parameter.child_by_field_name("type"), parameter.child_by_field_name("value"))
+        }
+        "assignment_pattern" => (None, parameter.child_by_field_name("right")),
+        _ => return false,
+    };
+
+    let typed_boolean = annotation
+        .and_then(|annotation| annotation.named_child(0))
+        .is_some_and(|ty| node_text(&ty, source) == "boolean");
+    let boolean_default = default.is_some_and(|value| matches!(value.kind(), "true" | "false"));
+
+    typed_boolean || boolean_default
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_typed_boolean_parameters_are_flagged() {
+        let source = "function f(a: boolean, b: boolean) {}\nfunction g(a: boolean, name: string) {}\nconst h = (x: boolean, y?: boolean) => x && y;\n";
+
+        let findings = check_source(&BooleanParameterRule, "flags.ts", source);
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "boolean-parameter-smell");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "'f' takes 2 boolean parameters");
+        assert_eq!(findings[1].message, "Function takes 2 boolean parameters");
+        assert_eq!(findings[1].location.line, 3);
+    }
+
+    #[test]
+    fn test_boolean_defaults_are_flagged() {
+        let source = "class Table {\n  render(rows, striped = true, compact = false) {}\n  sort(rows, ascending = true) {}\n}\n";
+
+        let findings = check_source(&BooleanParameterRule, "table.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "'render' takes 2 boolean parameters");
+        assert_eq!(findings[0].location.line, 2);
+    }
+}
@@ -0,0 +1,188 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags `catch` clauses that swallow every error: empty handlers, and
+/// handlers that never narrow the error (`instanceof`, `typeof`, comparing
+/// `err.code` / `err.name`, an `isX(err)` guard) and never rethrow it.
+/// Logging alone still hides failures the handler wasn't written for.
+pub struct BroadCatchRule;
+
+/// Error properties that identify what was thrown when compared.
+const DISCRIMINANT_PROPERTIES: &[&str] = &["code", "name", "status", "type"];
+
+const NESTED_FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+impl Rule for BroadCatchRule {
+    fn id(&self) -> &'static str {
+        "broad-catch"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A catch that swallows or uniformly handles every error hides unexpected failures that should have crashed loudly or been reported."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "catch_clause" {
+                return;
+            }
+            let Some(body) = node.child_by_field_name("body") else {
+                return;
+            };
+
+            let mut cursor = body.walk();
+            let is_empty = body.named_children(&mut cursor).all(|child| child.kind() == "comment");
+            let message = if is_empty {
+                "Empty catch block swallows every error"
+            } else if !handles_selectively(&body, node.child_by_field_name("parameter"), ctx.source) {
+                "Catch block handles every error the same way and never rethrows"
+            } else {
+                return;
+            };
+
+            findings.push(
+                Finding::new(self.id(), Severity::Low, message.to_string(), node_location(&node))
+                    .with_suggestion("Check the error type (e.g. `instanceof`) and rethrow errors you don't handle"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Whether `body` rethrows or inspects the caught error somewhere outside
+/// nested functions.
+fn handles_selectively(body: &Node, parameter: Option<Node>, source: &str) -> bool {
+    let error = parameter
+        .filter(|parameter| parameter.kind() == "identifier")
+        .map(|parameter| node_text(&parameter, source));
+
+    fn visit(node: &Node, error: Option<&str>, source: &str) -> bool {
+        if inspects(node, error, source) {
+            return true;
+        }
+
+        let mut cursor = node.walk();
+        let found = node
+            .named_children(&mut cursor)
+            .filter(|child| !NESTED_FUNCTION_KINDS.contains(&child.kind()))
+            .any(|child| visit(&child, error, source));
+        found
+    }
+
+    visit(body, error, source)
+}
+
+fn inspects(node: &Node, error: Option<&str>, source: &str) -> bool {
+    let is_error = |node: Option<Node>| node.is_some_and(|node| Some(node_text(&node, source)) == error);
+    let is_discriminant = |node: Option<Node>| {
+        node.is_some_and(|node| {
+            node.kind() == "member_expression"
+                && is_error(node.child_by_field_name("object"))
+                && node
+                    .child_by_field_name("property")
+                    .is_some_and(|property| DISCRIMINANT_PROPERTIES.contains(&node_text(&property, source)))
+        })
+    };
+
+    match node.kind() {
+        "throw_statement" => true,
+        "binary_expression" => {
+            let operator = node
+                .child_by_field_name("operator")
+                .map_or("", |operator| node_text(&operator, source));
+            let (left, right) = (node.child_by_field_name("left"), node.child_by_field_name("right"));
+
+            operator == "instanceof"
+                || (matches!(operator, "===" | "!==" | "==" | "!=") && (is_discriminant(left) || is_discriminant(right)))
+        }
+        "unary_expression" => {
+            node.child_by_field_name("operator")
+                .is_some_and(|operator| node_text(&operator, source) == "typeof")
+                && is_error(node.child_by_field_name("argument"))
+        }
+        "switch_statement" => {
+            let value = node.child_by_field_name("value").and_then(|value| value.named_child(0));
+            is_discriminant(value)
+        }
+        "call_expression" => {
+            let is_guard = node
+                .child_by_field_name("function")
+                .is_some_and(|callee| callee.kind() == "identifier" && node_text(&callee, source).starts_with("is"));
+            let passes_error = node.child_by_field_name("arguments").is_some_and(|arguments| {
+                let mut cursor = arguments.walk();
+                let found = arguments.named_children(&mut cursor).any(|argument| is_error(Some(argument)));
+                found
+            });
+            is_guard && passes_error
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_catch_that_only_logs_is_flagged() {
+        let source = r#"
+            async function save(data) {
+                try {
+                    await db.write(data);
+                } catch (err) {
+                    console.error("save failed", err.message);
+                }
+            }
+        "#;
+
+        let findings = check_source(&BroadCatchRule, "store.ts", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "broad-catch");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("never rethrows"));
+        assert_eq!(findings[0].location.line, 5);
+    }
+
+    #[test]
+    fn test_empty_catch_is_reported_separately() {
+        let findings = check_source(&BroadCatchRule, "app.js", "try { run(); } catch (e) { /* ignore */ }\n");
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.starts_with("Empty catch block"));
+    }
+
+    #[test]
+    fn test_narrowing_and_rethrowing_handlers_are_ignored() {
+        let source = r#"
+            try { a(); } catch (e: unknown) { if (e instanceof HttpError) { retry(); } else { throw e; } }
+            try { b(); } catch (e) { if (e.code === "ENOENT") { return null; } report(e); }
+            try { c(); } catch (e) { switch (e.name) { case "AbortError": return; } }
+            try { d(); } catch (e) { if (isNetworkError(e)) { retry(); } }
+            try { f(); } catch (e) { cleanup(); throw e; }
+        "#;
+
+        assert!(check_source(&BroadCatchRule, "client.ts", source).is_empty());
+    }
+
+    #[test]
+    fn test_checks_inside_nested_callbacks_do_not_count() {
+        let source = "try { a(); } catch (e) { later(() => { throw e; }); }\n";
+
+        assert_eq!(check_source(&BroadCatchRule, "app.js", source).len(), 1);
+    }
+}
@@ -0,0 +1,130 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+const DEFAULT_MAX_CALLBACK_DEPTH: u32 = 2;
+
+/// Flags callbacks nested inside other callbacks beyond
+/// `RuleConfig.max_callback_depth`, the "pyramid of doom" that async/await
+/// flattens.
+pub struct CallbackHellRule;
+
+const CALLBACK_KINDS: &[&str] = &["function_expression", "arrow_function"];
+
+impl Rule for CallbackHellRule {
+    fn id(&self) -> &'static str {
+        "callback-hell"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Deeply nested callbacks are hard to follow and make error propagation easy to get wrong."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max_depth = ctx.config.max_callback_depth.unwrap_or(DEFAULT_MAX_CALLBACK_DEPTH);
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if !is_callback(&node) {
+                return;
+            }
+
+            // Report only the first level past the limit, not every deeper one
+            let depth = callback_depth(&node);
+            if depth != max_depth + 1 {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Callback nested {} levels deep (max: {})", depth, max_depth),
+                    node_location(&node),
+                )
+                .with_suggestion("Flatten the nesting with promises and async/await"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// A function literal passed directly as a call argument.
+fn is_callback(node: &Node) -> bool {
+    CALLBACK_KINDS.contains(&node.kind())
+        && node.parent().is_some_and(|parent| {
+            parent.kind() == "arguments"
+                && parent
+                    .parent()
+                    .is_some_and(|call| matches!(call.kind(), "call_expression" | "new_expression"))
+        })
+}
+
+/// Number of callbacks enclosing `node`, counting `node` itself.
+fn callback_depth(node: &Node) -> u32 {
+    let mut depth = 0;
+    let mut current = Some(*node);
+    while let Some(ancestor) = current {
+        if is_callback(&ancestor) {
+            depth += 1;
+        }
+        current = ancestor.parent();
+    }
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    const PYRAMID: &str = r#"
+        readConfig(path, function (err, config) {
+            connect(config.url, (err, db) => {
+                db.query('SELECT 1', (err, rows) => {
+                    done(rows);
+                });
+            });
+        });
+    "#;
+
+    #[test]
+    fn test_three_levels_of_callbacks_are_flagged() {
+        let findings = check_source(&CallbackHellRule, "legacy.js", PYRAMID);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "callback-hell");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].location.line, 4);
+    }
+
+    #[test]
+    fn test_shallow_callbacks_are_allowed() {
+        let source = r#"
+            items.forEach((item) => {
+                setTimeout(() => process(item), 0);
+            });
+            function outer() { return inner(() => 1); }
+        "#;
+
+        assert!(check_source(&CallbackHellRule, "app.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_max_depth_is_configurable() {
+        let config = RuleConfig {
+            max_callback_depth: Some(3),
+            ..Default::default()
+        };
+
+        assert!(check_source_with_config(&CallbackHellRule, "legacy.js", PYRAMID, &config).is_empty());
+    }
+}
@@ -0,0 +1,136 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags regex literals whose pattern is longer than
+/// `RuleConfig.max_regex_length` characters or has more than
+/// `RuleConfig.max_regex_groups` groups. Unlike `potential-redos`, this is
+/// about readability, not runtime cost.
+pub struct ComplexRegexRule;
+
+const DEFAULT_MAX_REGEX_LENGTH: u32 = 80;
+const DEFAULT_MAX_REGEX_GROUPS: u32 = 6;
+
+impl Rule for ComplexRegexRule {
+    fn id(&self) -> &'static str {
+        "complex-regex"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Long regular expressions with many groups are hard to read, review and change without breaking them."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max_length = ctx.config.max_regex_length.unwrap_or(DEFAULT_MAX_REGEX_LENGTH);
+        let max_groups = ctx.config.max_regex_groups.unwrap_or(DEFAULT_MAX_REGEX_GROUPS);
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let Some(pattern) = regex_pattern(&node, ctx.source) else {
+                return;
+            };
+            let length = pattern.chars().count() as u32;
+            let groups = count_groups(pattern);
+
+            let message = if length > max_length {
+                format!("Regex is {} characters long (max: {})", length, max_length)
+            } else if groups > max_groups {
+                format!("Regex has {} groups (max: {})", groups, max_groups)
+            } else {
+                return;
+            };
+            findings.push(
+                Finding::new(self.id(), Severity::Low, message, node_location(&node))
+                    .with_suggestion("Build the regex from named parts, or document it with a comment"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Length of the longest regex literal pattern in the tree.
+pub fn longest_regex(root: Node, source: &str) -> u32 {
+    let mut longest = 0;
+    walk_tree(root, source, &mut |node: Node| {
+        if let Some(pattern) = regex_pattern(&node, source) {
+            longest = longest.max(pattern.chars().count() as u32);
+        }
+    });
+    longest
+}
+
+fn regex_pattern<'a>(node: &Node, source: &'a str) -> Option<&'a str> {
+    if node.kind() != "regex" {
+        return None;
+    }
+    node.child_by_field_name("pattern").map(|pattern| node_text(&pattern, source))
+}
+
+/// Opening parentheses that start a group: not escaped and not inside a
+/// character class.
+fn count_groups(pattern: &str) -> u32 {
+    let mut groups = 0;
+    let mut in_class = false;
+    let mut chars = pattern.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => groups += 1,
+            _ => {}
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{javascript::JavaScriptParser, Parser};
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    const EMAIL: &str = r"const email = /^[a-zA-Z0-9.!#$%&'*+\/=?^_`{|}~-]+@[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?(?:\.[a-zA-Z0-9](?:[a-zA-Z0-9-]{0,61}[a-zA-Z0-9])?)*$/;
+const digits = /^\d+$/;
+";
+
+    #[test]
+    fn test_long_regex_is_flagged() {
+        let findings = check_source(&ComplexRegexRule, "validate.js", EMAIL);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "complex-regex");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Regex is 133 characters long (max: 80)");
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_group_limit_ignores_escaped_and_class_parentheses() {
+        let source = r"const date = /(\d{4})-(\d{2})-(\d{2})[()]\(/;";
+        let config = RuleConfig {
+            max_regex_groups: Some(2),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&ComplexRegexRule, "date.js", source, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "Regex has 3 groups (max: 2)");
+    }
+
+    #[test]
+    fn test_longest_regex() {
+        let parse_result = JavaScriptParser::new().unwrap().parse(EMAIL).unwrap();
+
+        assert_eq!(longest_regex(parse_result.tree.root_node(), EMAIL), 133);
+    }
+}
@@ -0,0 +1,82 @@
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, Severity};
+
+/// Flags functions whose cyclomatic complexity exceeds
+/// `RuleConfig.complexity_threshold`.
+pub struct HighComplexityRule;
+
+const DEFAULT_COMPLEXITY_THRESHOLD: u32 = 10;
+
+impl Rule for HighComplexityRule {
+    fn id(&self) -> &'static str {
+        "high-complexity"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Functions with many branches are hard to understand and test exhaustively, and they attract bugs."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let threshold = ctx.config.complexity_threshold.unwrap_or(DEFAULT_COMPLEXITY_THRESHOLD);
+
+        ctx.parse_result
+            .functions
+            .iter()
+            // Functions at the ceiling are reported by `complexity-overflow`
+            .filter(|function| function.complexity > threshold && !function.complexity_overflowed())
+            .map(|function| {
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!(
+                        "Function '{}' has cyclomatic complexity {} (threshold: {})",
+                        function.name, function.complexity, threshold
+                    ),
+                    Location {
+                        line: function.line,
+                        column: 1,
+                        end_line: None,
+                        end_column: None,
+                    },
+                )
+                .with_suggestion("Consider breaking this function into smaller parts")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    const BRANCHY: &str = r#"
+        function route(a, b) {
+            if (a) { return 1; }
+            if (b) { return 2; }
+            if (a && b) { return 3; }
+            return 4;
+        }
+    "#;
+
+    #[test]
+    fn test_function_over_threshold_is_flagged() {
+        let config = RuleConfig {
+            complexity_threshold: Some(3),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&HighComplexityRule, "app.js", BRANCHY, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "high-complexity");
+        assert!(findings[0].message.contains("'route' has cyclomatic complexity 5 (threshold: 3)"));
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_function_under_default_threshold_is_not_flagged() {
+        assert!(check_source(&HighComplexityRule, "app.ts", BRANCHY).is_empty());
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Rule, RuleContext};
+use crate::parser::MAX_COMPLEXITY;
+use crate::types::{Finding, Location, Severity};
+
+/// Flags functions whose complexity hit the parser's hard ceiling. Counting
+/// stopped there, so the reported complexity is a lower bound.
+pub struct ComplexityOverflowRule;
+
+impl Rule for ComplexityOverflowRule {
+    fn id(&self) -> &'static str {
+        "complexity-overflow"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Complexity counting stopped at the ceiling, so the function is far beyond any reasonable size and its reported score is a lower bound."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        ctx.parse_result
+            .functions
+            .iter()
+            .filter(|function| function.complexity_overflowed())
+            .map(|function| {
+                Finding::new(
+                    self.id(),
+                    Severity::Critical,
+                    format!(
+                        "Function '{}' exceeds the maximum measurable complexity of {}",
+                        function.name, MAX_COMPLEXITY
+                    ),
+                    Location {
+                        line: function.line,
+                        column: 1,
+                        end_line: None,
+                        end_column: None,
+                    },
+                )
+                .with_suggestion("Split this function up; it is too large to analyze reliably")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    fn branching_function(branches: usize) -> String {
+        let body: String = (0..branches)
+            .map(|i| format!("  if (x === {}) {{ y++; }}\n", i))
+            .collect();
+        format!("function huge(x) {{\n  let y = 0;\n{}  return y;\n}}\n", body)
+    }
+
+    #[test]
+    fn test_function_over_ceiling_is_flagged_once() {
+        let findings = check_source(&ComplexityOverflowRule, "huge.js", &branching_function(2000));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "complexity-overflow");
+        assert!(matches!(findings[0].severity, Severity::Critical));
+        assert!(findings[0].message.contains("'huge'"));
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_function_under_ceiling_is_not_flagged() {
+        assert!(check_source(&ComplexityOverflowRule, "small.ts", &branching_function(50)).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+use super::{glob_match, node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags function declarations that are never referenced or exported in
+/// their file. Framework entry points are exempt by default, and
+/// `RuleConfig.dead_code_exemptions` adds more names or glob patterns.
+pub struct DeadCodeRule;
+
+/// Names frameworks and runtimes call on the file's behalf.
+const DEFAULT_EXEMPTIONS: &[&str] = &[
+    "main",
+    "handler",
+    "render",
+    "constructor",
+    "componentDidMount",
+    "componentDidUpdate",
+    "componentWillUnmount",
+    "getServerSideProps",
+    "getStaticProps",
+    "getStaticPaths",
+    "setup",
+    "teardown",
+];
+
+const REFERENCE_KINDS: &[&str] = &["identifier", "shorthand_property_identifier"];
+
+impl Rule for DeadCodeRule {
+    fn id(&self) -> &'static str {
+        "dead-code"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Functions nothing calls or exports still have to be read, maintained and shipped, and they hide which code actually matters."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_dead_code_detection.unwrap_or(true)
+    }
+
+    fn check<'a>(&self, ctx: &RuleContext<'a>) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let exemptions = ctx.config.dead_code_exemptions.as_deref().unwrap_or_default();
+
+        let mut references: HashMap<&str, Vec<Node>> = HashMap::new();
+        walk_tree(root, ctx.source, &mut |node: Node<'a>| {
+            if REFERENCE_KINDS.contains(&node.kind()) {
+                references.entry(node_text(&node, ctx.source)).or_default().push(node);
+            }
+        });
+
+        let mut findings = Vec::new();
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if node.kind() != "function_declaration" && node.kind() != "generator_function_declaration" {
+                return;
+            }
+            let Some(name_node) = node.child_by_field_name("name") else {
+                return;
+            };
+            let name = node_text(&name_node, ctx.source);
+
+            let is_exported = node.parent().is_some_and(|parent| parent.kind() == "export_statement");
+            let is_exempt = DEFAULT_EXEMPTIONS.contains(&name)
+                || exemptions.iter().any(|pattern| glob_match(pattern, name));
+            if is_exported || is_exempt {
+                return;
+            }
+
+            // Recursive calls from the function's own body don't count
+            let used = references
+                .get(name)
+                .is_some_and(|uses| uses.iter().any(|usage| !contains(&node, usage)));
+            if used {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Function '{}' is never used", name),
+                    node_location(&node),
+                )
+                .with_suggestion("Remove the function, or add it to dead_code_exemptions if it is an entry point"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn contains(outer: &Node, inner: &Node) -> bool {
+    outer.start_byte() <= inner.start_byte() && inner.end_byte() <= outer.end_byte()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    #[test]
+    fn test_unused_function_is_flagged() {
+        let source = r#"
+            function used() { return 1; }
+            function unused() { return unused(); }
+            console.log(used());
+        "#;
+
+        let findings = check_source(&DeadCodeRule, "app.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "dead-code");
+        assert!(findings[0].message.contains("'unused'"));
+        assert_eq!(findings[0].location.line, 3);
+    }
+
+    #[test]
+    fn test_exported_and_default_entry_points_are_not_flagged() {
+        let source = r#"
+            export function api() {}
+            function helper() {}
+            function main() {}
+            module.exports = { helper };
+        "#;
+
+        assert!(check_source(&DeadCodeRule, "app.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_configured_exemptions_support_globs() {
+        let source = "function onInit() {}\nfunction lambdaHandler() {}\nfunction stray() {}\n";
+        let config = RuleConfig {
+            dead_code_exemptions: Some(vec!["on*".to_string(), "lambdaHandler".to_string()]),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&DeadCodeRule, "app.js", source, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("'stray'"));
+    }
+
+    #[test]
+    fn test_handler_exemption_applies() {
+        let source = "function handle(event) { return event; }\n";
+        let config = RuleConfig {
+            dead_code_exemptions: Some(vec!["handle".to_string()]),
+            ..Default::default()
+        };
+
+        assert_eq!(check_source(&DeadCodeRule, "lambda.js", source).len(), 1);
+        assert!(check_source_with_config(&DeadCodeRule, "lambda.js", source, &config).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_by_config() {
+        let config = RuleConfig {
+            enable_dead_code_detection: Some(false),
+            ..Default::default()
+        };
+
+        assert!(!DeadCodeRule.is_enabled_by_config(&config));
+    }
+}
@@ -0,0 +1,120 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags parameters whose destructuring pattern nests more object or array
+/// patterns than `RuleConfig.max_destructure_depth`, as in
+/// `function f({ a: { b: { c } } })`. Default values and type annotations
+/// are not part of the pattern.
+pub struct DeepDestructuringRule;
+
+impl Rule for DeepDestructuringRule {
+    fn id(&self) -> &'static str {
+        "deep-destructuring"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A deeply nested parameter pattern hides the shape the function expects and is hard to read at a glance."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max_depth = ctx.config.max_destructure_depth.unwrap_or(2);
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "formal_parameters" {
+                return;
+            }
+            let mut cursor = node.walk();
+            for parameter in node.named_children(&mut cursor) {
+                let depth = pattern_depth(&parameter);
+                if depth <= max_depth {
+                    continue;
+                }
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("Parameter is destructured {} levels deep (max: {})", depth, max_depth),
+                        node_location(&parameter),
+                    )
+                    .with_suggestion("Destructure the outer levels in the signature and the rest in the body"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Nesting of object and array patterns under `node`, skipping default
+/// values (`right`, or a TypeScript parameter's `value`) and types.
+fn pattern_depth(node: &Node) -> u32 {
+    let mut deepest = 0;
+    let mut cursor = node.walk();
+    if cursor.goto_first_child() {
+        loop {
+            let child = cursor.node();
+            let skipped = matches!(cursor.field_name(), Some("right" | "value" | "type"))
+                && node.kind() != "pair_pattern";
+            if child.is_named() && !skipped {
+                deepest = deepest.max(pattern_depth(&child));
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    match node.kind() {
+        "object_pattern" | "array_pattern" => deepest + 1,
+        _ => deepest,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    #[test]
+    fn test_three_level_parameter_is_flagged() {
+        let source = "function render({ user: { address: { city } } }, [first, [second]]) {\n  return city;\n}\n";
+
+        let findings = check_source(&DeepDestructuringRule, "render.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "deep-destructuring");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Parameter is destructured 3 levels deep (max: 2)");
+        assert_eq!(findings[0].location.column, 17);
+    }
+
+    #[test]
+    fn test_defaults_and_types_do_not_add_depth() {
+        let source = r#"
+function load({ options = { retry: { count: 3 } } } = {}, { a: { b } }: { a: { b: { c: number } } }) {}
+const handler = ({ onDone = ({ result: { value } }) => value }) => onDone;
+"#;
+
+        assert!(check_source(&DeepDestructuringRule, "load.ts", source).is_empty());
+    }
+
+    #[test]
+    fn test_depth_is_configurable() {
+        let config = RuleConfig {
+            max_destructure_depth: Some(1),
+            ..Default::default()
+        };
+
+        let source = "const f = ({ at: [x, y] }) => x + y;\n";
+
+        let findings = check_source_with_config(&DeepDestructuringRule, "point.js", source, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "Parameter is destructured 2 levels deep (max: 1)");
+    }
+}
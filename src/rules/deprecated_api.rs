@@ -0,0 +1,106 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags calls to the functions and methods listed in
+/// `RuleConfig.deprecated_symbols`, for teams migrating off internal APIs.
+pub struct DeprecatedApiRule;
+
+impl Rule for DeprecatedApiRule {
+    fn id(&self) -> &'static str {
+        "deprecated-api"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Calls to deprecated APIs keep code tied to implementations that are slated for removal."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let Some(symbols) = ctx.config.deprecated_symbols.as_deref().filter(|symbols| !symbols.is_empty()) else {
+            return Vec::new();
+        };
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some(callee) = node.child_by_field_name("function") else {
+                return;
+            };
+            let Some(symbol) = symbols.iter().find(|symbol| matches_callee(&callee, symbol, ctx.source)) else {
+                return;
+            };
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!("Call to deprecated API '{}'", symbol),
+                    node_location(&node),
+                )
+                .with_suggestion("Migrate to the API's replacement"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// `fetchLegacy` matches `fetchLegacy()` and `api.fetchLegacy()`, while
+/// `api.fetchLegacy` matches only the latter.
+pub(crate) fn matches_callee(callee: &Node, symbol: &str, source: &str) -> bool {
+    if symbol.contains('.') {
+        return node_text(callee, source) == symbol;
+    }
+    let name = match callee.kind() {
+        "identifier" => Some(*callee),
+        "member_expression" => callee.child_by_field_name("property"),
+        _ => None,
+    };
+    name.is_some_and(|name| node_text(&name, source) == symbol)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    fn deprecating(symbols: &[&str]) -> RuleConfig {
+        RuleConfig {
+            deprecated_symbols: Some(symbols.iter().map(|symbol| symbol.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_call_to_deprecated_function_is_flagged() {
+        let source = "const user = fetchUserV1(id);\nclient.fetchUserV1(id);\nfetchUser(id);\n";
+
+        let findings = check_source_with_config(&DeprecatedApiRule, "user.js", source, &deprecating(&["fetchUserV1"]));
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "deprecated-api");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].message, "Call to deprecated API 'fetchUserV1'");
+        assert_eq!(findings[1].location.line, 2);
+    }
+
+    #[test]
+    fn test_dotted_symbol_matches_only_that_receiver() {
+        let source = "legacy.request('/a');\nhttp.request('/b');\nrequest('/c');\n";
+
+        let findings = check_source_with_config(&DeprecatedApiRule, "http.ts", source, &deprecating(&["legacy.request"]));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.line, 1);
+        assert!(check_source(&DeprecatedApiRule, "http.ts", source).is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags object literal keys that repeat an earlier key in the same
+/// literal, where the later value silently overwrites the first.
+pub struct DuplicateObjectKeyRule;
+
+impl Rule for DuplicateObjectKeyRule {
+    fn id(&self) -> &'static str {
+        "duplicate-object-key"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "When an object literal repeats a key, the later value silently wins, which is almost always a copy-paste mistake."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "object" {
+                return;
+            }
+
+            let mut seen = HashSet::new();
+            let mut cursor = node.walk();
+            for member in node.named_children(&mut cursor) {
+                let Some((key_node, key)) = member_key(&member, ctx.source) else {
+                    continue;
+                };
+                if seen.insert(key) {
+                    continue;
+                }
+
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Medium,
+                        format!("Duplicate key '{}' overwrites an earlier value", key),
+                        node_location(&key_node),
+                    )
+                    .with_suggestion("Remove or rename one of the duplicate keys"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// The key node and its name for a static object member. Computed keys,
+/// spreads, and getters/setters (which legitimately share a name) yield
+/// `None`.
+fn member_key<'a>(member: &Node<'a>, source: &'a str) -> Option<(Node<'a>, &'a str)> {
+    let key = match member.kind() {
+        "pair" => member.child_by_field_name("key")?,
+        "method_definition" => {
+            let mut cursor = member.walk();
+            let is_accessor = member
+                .children(&mut cursor)
+                .any(|child| matches!(child.kind(), "get" | "set"));
+            if is_accessor {
+                return None;
+            }
+            member.child_by_field_name("name")?
+        }
+        "shorthand_property_identifier" => *member,
+        _ => return None,
+    };
+
+    let name = match key.kind() {
+        "property_identifier" | "shorthand_property_identifier" | "number" => node_text(&key, source),
+        "string" => {
+            let text = node_text(&key, source);
+            text.get(1..text.len().saturating_sub(1))?
+        }
+        _ => return None,
+    };
+    Some((key, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_repeated_key_is_flagged_at_later_occurrence() {
+        let findings = check_source(&DuplicateObjectKeyRule, "app.js", "const o = { a: 1, b: 2, a: 2 };\n");
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "duplicate-object-key");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert!(findings[0].message.contains("'a'"));
+        assert_eq!(findings[0].location.column, 25);
+    }
+
+    #[test]
+    fn test_quoted_shorthand_and_method_keys_collide() {
+        let source = "const o = { name, 'name': 1, run() {}, \"run\": 2 };\n";
+
+        assert_eq!(check_source(&DuplicateObjectKeyRule, "app.ts", source).len(), 2);
+    }
+
+    #[test]
+    fn test_computed_keys_accessors_and_nested_objects_are_not_flagged() {
+        let source = r#"
+            const o = {
+                [key]: 1,
+                [key]: 2,
+                get value() { return 1; },
+                set value(v) {},
+                inner: { a: 1 },
+                outer: { a: 2 },
+            };
+        "#;
+
+        assert!(check_source(&DuplicateObjectKeyRule, "app.js", source).is_empty());
+    }
+}
@@ -0,0 +1,122 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags exact equality checks against floating-point values, such as
+/// `x === 0.1 + 0.2`, which fail because of rounding.
+pub struct FloatEqualityRule;
+
+const EQUALITY_OPERATORS: &[&str] = &["==", "===", "!=", "!=="];
+
+const ARITHMETIC_OPERATORS: &[&str] = &["+", "-", "*", "/", "%", "**"];
+
+impl Rule for FloatEqualityRule {
+    fn id(&self) -> &'static str {
+        "float-equality"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Floating-point arithmetic is inexact, so comparing results with `===` fails for values that are mathematically equal."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "binary_expression" {
+                return;
+            }
+            let Some(operator) = node.child_by_field_name("operator") else {
+                return;
+            };
+            if !EQUALITY_OPERATORS.contains(&operator.kind()) {
+                return;
+            }
+
+            let operands = [node.child_by_field_name("left"), node.child_by_field_name("right")];
+            if !operands.iter().flatten().any(|operand| is_float(operand, ctx.source)) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!(
+                        "Floating-point values compared with '{}': {}",
+                        operator.kind(),
+                        node_text(&node, ctx.source)
+                    ),
+                    node_location(&node),
+                )
+                .with_suggestion("Compare with a tolerance, e.g. Math.abs(a - b) < Number.EPSILON"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// A float literal, or arithmetic involving one.
+fn is_float(node: &Node, source: &str) -> bool {
+    match node.kind() {
+        "number" => is_float_literal(node_text(node, source)),
+        "parenthesized_expression" => node.named_child(0).is_some_and(|inner| is_float(&inner, source)),
+        "unary_expression" => node
+            .child_by_field_name("argument")
+            .is_some_and(|argument| is_float(&argument, source)),
+        "binary_expression" => {
+            let is_arithmetic = node
+                .child_by_field_name("operator")
+                .is_some_and(|operator| ARITHMETIC_OPERATORS.contains(&operator.kind()));
+            is_arithmetic
+                && [node.child_by_field_name("left"), node.child_by_field_name("right")]
+                    .iter()
+                    .flatten()
+                    .any(|operand| is_float(operand, source))
+        }
+        _ => false,
+    }
+}
+
+/// `0.1`, `.5`, `1.5e3` or `1e-3`; not integers, hex/octal/binary or BigInt.
+fn is_float_literal(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    if lower.starts_with("0x") || lower.starts_with("0o") || lower.starts_with("0b") || lower.ends_with('n') {
+        return false;
+    }
+
+    lower.contains('.') || lower.contains("e-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_comparison_with_float_literal_is_flagged() {
+        let findings = check_source(&FloatEqualityRule, "math.js", "if (total === 0.1) { pay(); }\n");
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "float-equality");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert!(findings[0].message.contains("total === 0.1"));
+    }
+
+    #[test]
+    fn test_float_arithmetic_operands_are_flagged() {
+        let source = "a == 0.1 + 0.2;\nb !== -(1.5 * c);\nd != 1e-3;\n";
+
+        assert_eq!(check_source(&FloatEqualityRule, "math.ts", source).len(), 3);
+    }
+
+    #[test]
+    fn test_integer_and_non_numeric_comparisons_are_ignored() {
+        let source = "a === 0;\nb == 0x1F;\nc !== 10n;\nd === 'x.y';\ne < 0.5;\nf === 1 + 2;\n";
+
+        assert!(check_source(&FloatEqualityRule, "math.js", source).is_empty());
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use super::blocking_in_async::is_async;
+use super::deprecated_api::matches_callee;
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags calls used as statements whose promise is dropped: calls to
+/// `async` functions declared in the same file, and to functions known to
+/// return promises (`THENABLE_FUNCTIONS`, plus
+/// `RuleConfig.thenable_functions`) whose async-ness can't be seen from
+/// here. Awaited, returned, assigned and chained calls are left alone, as
+/// are ones discarded explicitly with `void`.
+pub struct FloatingPromiseRule;
+
+/// Library calls that return promises. A bare name also matches it as a
+/// method (`window.fetch`); a dotted one matches only that exact callee.
+const THENABLE_FUNCTIONS: &[&str] = &[
+    "fetch",
+    "axios",
+    "axios.get",
+    "axios.post",
+    "axios.put",
+    "axios.patch",
+    "axios.delete",
+    "axios.request",
+];
+
+const FUNCTION_KINDS: &[&str] = &["function_declaration", "function_expression", "arrow_function"];
+
+impl Rule for FloatingPromiseRule {
+    fn id(&self) -> &'static str {
+        "floating-promise"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A promise nobody awaits runs out of order with the code after it, and its rejection goes unhandled."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let local_async = async_function_names(&root, ctx.source);
+        let extra = ctx.config.thenable_functions.iter().flatten().map(String::as_str);
+        let thenables: Vec<&str> = THENABLE_FUNCTIONS.iter().copied().chain(extra).collect();
+
+        let mut findings = Vec::new();
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if node.kind() != "expression_statement" {
+                return;
+            }
+            let Some(callee) = node
+                .named_child(0)
+                .filter(|expression| expression.kind() == "call_expression")
+                .and_then(|call| call.child_by_field_name("function"))
+            else {
+                return;
+            };
+
+            let name = if callee.kind() == "identifier" && local_async.contains(node_text(&callee, ctx.source)) {
+                node_text(&callee, ctx.source)
+            } else {
+                match thenables.iter().find(|symbol| matches_callee(&callee, symbol, ctx.source)) {
+                    Some(symbol) => symbol,
+                    None => return,
+                }
+            };
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!("Promise returned by '{}' is neither awaited nor handled", name),
+                    node_location(&node),
+                )
+                .with_suggestion("Await the call, return it, or attach a .catch() handler"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Names of the file's `async function` declarations and of variables
+/// holding an async function or arrow function.
+fn async_function_names<'a>(root: &Node, source: &'a str) -> HashSet<&'a str> {
+    let mut names = HashSet::new();
+
+    walk_tree(*root, source, &mut |node: Node| {
+        let (name, function) = match node.kind() {
+            "function_declaration" => (node.child_by_field_name("name"), Some(node)),
+            "variable_declarator" => (node.child_by_field_name("name"), node.child_by_field_name("value")),
+            _ => return,
+        };
+        let (Some(name), Some(function)) = (name, function) else {
+            return;
+        };
+        if name.kind() == "identifier" && FUNCTION_KINDS.contains(&function.kind()) && is_async(&function) {
+            names.insert(node_text(&name, source));
+        }
+    });
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    #[test]
+    fn test_unawaited_fetch_is_flagged() {
+        let source = "function ping(url) {\n  fetch(url);\n}\n";
+
+        let findings = check_source(&FloatingPromiseRule, "ping.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "floating-promise");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].message, "Promise returned by 'fetch' is neither awaited nor handled");
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_local_async_and_configured_functions_are_flagged() {
+        let source = r#"
+async function save(user) {}
+const load = async () => {};
+async function run(user) {
+    save(user);
+    load();
+    db.users.insert(user);
+    await save(user);
+    void load();
+    fetch('/ping').catch(report);
+    const pending = axios.get('/users');
+    log(user);
+}
+"#;
+        let config = RuleConfig {
+            thenable_functions: Some(vec!["db.users.insert".to_string()]),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&FloatingPromiseRule, "users.js", source, &config);
+
+        let lines: Vec<u32> = findings.iter().map(|finding| finding.location.line).collect();
+        assert_eq!(lines, vec![5, 6, 7]);
+    }
+}
@@ -0,0 +1,316 @@
+use std::collections::{HashMap, HashSet};
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags code that leaks into the global scope: assignments to identifiers
+/// that are never declared, and top-level `var` declarations in scripts.
+pub struct GlobalPollutionRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+const BLOCK_SCOPE_KINDS: &[&str] = &[
+    "statement_block",
+    "for_statement",
+    "for_in_statement",
+    "switch_body",
+    "class_body",
+];
+
+impl Rule for GlobalPollutionRule {
+    fn id(&self) -> &'static str {
+        "global-pollution"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Undeclared assignments and top-level `var` in scripts create globals that leak across files and collide with other code."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let scopes = collect_declarations(root, ctx.source);
+        let is_module = ctx.file_name.ends_with(".mjs") || has_module_syntax(&root);
+        let mut findings = Vec::new();
+
+        // In ES modules top-level bindings are module-scoped, so only
+        // classic scripts leak `var` onto the global object.
+        if !is_module {
+            let mut cursor = root.walk();
+            for statement in root.named_children(&mut cursor) {
+                if statement.kind() != "variable_declaration" {
+                    continue;
+                }
+                for name in declared_names(&statement, ctx.source) {
+                    findings.push(
+                        Finding::new(
+                            self.id(),
+                            Severity::Medium,
+                            format!("Top-level 'var {}' is added to the global scope", name),
+                            node_location(&statement),
+                        )
+                        .with_suggestion("Use let/const inside a module or an enclosing function scope"),
+                    );
+                }
+            }
+        }
+
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if !matches!(node.kind(), "assignment_expression" | "augmented_assignment_expression") {
+                return;
+            }
+            let Some(left) = node.child_by_field_name("left") else {
+                return;
+            };
+            if left.kind() != "identifier" {
+                return;
+            }
+
+            let name = node_text(&left, ctx.source);
+            if !is_declared(&node, name, &scopes) {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Medium,
+                        format!("Assignment to undeclared variable '{}' creates an implicit global", name),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Declare the variable with let or const before assigning to it"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+fn has_module_syntax(root: &Node) -> bool {
+    let mut cursor = root.walk();
+    let found = root
+        .named_children(&mut cursor)
+        .any(|statement| matches!(statement.kind(), "import_statement" | "export_statement"));
+    found
+}
+
+/// Maps each scope node id to the names declared directly in it. Hoisting is
+/// handled by collecting every declaration before any lookup happens.
+fn collect_declarations(root: Node, source: &str) -> HashMap<usize, HashSet<String>> {
+    let mut scopes: HashMap<usize, HashSet<String>> = HashMap::new();
+    let mut declare = |scope: Option<Node>, names: Vec<String>| {
+        if let Some(scope) = scope {
+            scopes.entry(scope.id()).or_default().extend(names);
+        }
+    };
+
+    walk_tree(root, source, &mut |node: Node| match node.kind() {
+        "variable_declaration" => {
+            declare(function_scope(&node), declared_names(&node, source));
+        }
+        "lexical_declaration" => {
+            declare(block_scope(&node), declared_names(&node, source));
+        }
+        "class_declaration" => {
+            if let Some(name) = node.child_by_field_name("name") {
+                declare(block_scope(&node), vec![node_text(&name, source).to_string()]);
+            }
+        }
+        kind if FUNCTION_KINDS.contains(&kind) => {
+            let mut names = Vec::new();
+            if let Some(name) = node.child_by_field_name("name") {
+                let name = node_text(&name, source).to_string();
+                match kind {
+                    // Declarations bind their name in the enclosing scope,
+                    // named expressions only inside their own body.
+                    "function_declaration" | "generator_function_declaration" => {
+                        declare(block_scope(&node), vec![name]);
+                    }
+                    "method_definition" => {}
+                    _ => names.push(name),
+                }
+            }
+            for field in ["parameters", "parameter"] {
+                if let Some(params) = node.child_by_field_name(field) {
+                    pattern_names(&params, source, &mut names);
+                }
+            }
+            declare(Some(node), names);
+        }
+        "catch_clause" => {
+            let mut names = Vec::new();
+            if let Some(param) = node.child_by_field_name("parameter") {
+                pattern_names(&param, source, &mut names);
+            }
+            declare(Some(node), names);
+        }
+        "import_clause" => {
+            let mut names = Vec::new();
+            walk_tree(node, source, &mut |child: Node| {
+                if child.kind() == "identifier" {
+                    names.push(node_text(&child, source).to_string());
+                }
+            });
+            declare(Some(root), names);
+        }
+        _ => {}
+    });
+
+    scopes
+}
+
+fn is_declared(node: &Node, name: &str, scopes: &HashMap<usize, HashSet<String>>) -> bool {
+    let mut current = Some(*node);
+    while let Some(scope) = current {
+        if scopes.get(&scope.id()).is_some_and(|names| names.contains(name)) {
+            return true;
+        }
+        current = scope.parent();
+    }
+    false
+}
+
+/// Nearest function (or the program) — where `var` bindings live.
+fn function_scope<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut current = node.parent();
+    while let Some(scope) = current {
+        if scope.kind() == "program" || FUNCTION_KINDS.contains(&scope.kind()) {
+            return Some(scope);
+        }
+        current = scope.parent();
+    }
+    None
+}
+
+/// Nearest block, function, or program — where `let`/`const` bindings live.
+fn block_scope<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let mut current = node.parent();
+    while let Some(scope) = current {
+        if scope.kind() == "program"
+            || FUNCTION_KINDS.contains(&scope.kind())
+            || BLOCK_SCOPE_KINDS.contains(&scope.kind())
+        {
+            return Some(scope);
+        }
+        current = scope.parent();
+    }
+    None
+}
+
+fn declared_names(declaration: &Node, source: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut cursor = declaration.walk();
+    for declarator in declaration.named_children(&mut cursor) {
+        if let Some(name) = declarator.child_by_field_name("name") {
+            pattern_names(&name, source, &mut names);
+        }
+    }
+    names
+}
+
+/// Collects the identifiers bound by a (possibly destructuring) pattern,
+/// skipping default values and type annotations.
+fn pattern_names(pattern: &Node, source: &str, names: &mut Vec<String>) {
+    match pattern.kind() {
+        "identifier" | "shorthand_property_identifier_pattern" => {
+            names.push(node_text(pattern, source).to_string());
+        }
+        "assignment_pattern" | "object_assignment_pattern" => {
+            if let Some(left) = pattern.child_by_field_name("left") {
+                pattern_names(&left, source, names);
+            }
+        }
+        "pair_pattern" => {
+            if let Some(value) = pattern.child_by_field_name("value") {
+                pattern_names(&value, source, names);
+            }
+        }
+        "required_parameter" | "optional_parameter" => {
+            if let Some(inner) = pattern.child_by_field_name("pattern") {
+                pattern_names(&inner, source, names);
+            }
+        }
+        "object_pattern" | "array_pattern" | "formal_parameters" | "rest_pattern" => {
+            let mut cursor = pattern.walk();
+            for child in pattern.named_children(&mut cursor) {
+                pattern_names(&child, source, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_undeclared_top_level_assignment_is_flagged() {
+        let findings = check_source(&GlobalPollutionRule, "app.js", "counter = 0;\n");
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "global-pollution");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert!(findings[0].message.contains("'counter'"));
+    }
+
+    #[test]
+    fn test_undeclared_assignment_inside_function_is_flagged() {
+        let source = r#"
+            function start() {
+                let local = 1;
+                local = 2;
+                leaked = local;
+            }
+        "#;
+
+        let findings = check_source(&GlobalPollutionRule, "app.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("'leaked'"));
+        assert_eq!(findings[0].location.line, 5);
+    }
+
+    #[test]
+    fn test_declared_bindings_are_not_flagged() {
+        let source = r#"
+            import config from './config';
+            total = 0;
+            let total;
+            function add({ amount }, [first, ...rest], factor = 2) {
+                amount = first;
+                factor = rest.length;
+                try { run(); } catch (err) { err = null; }
+                config = null;
+                hoisted = 1;
+                var hoisted;
+            }
+        "#;
+
+        assert!(check_source(&GlobalPollutionRule, "app.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_top_level_var_is_flagged_in_scripts_only() {
+        let script = "var shared = 1;\nfunction f() { var local = 2; }\n";
+        let module = "import x from './x';\nvar shared = 1;\n";
+
+        let findings = check_source(&GlobalPollutionRule, "legacy.js", script);
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("var shared"));
+
+        assert!(check_source(&GlobalPollutionRule, "modern.js", module).is_empty());
+        assert!(check_source(&GlobalPollutionRule, "modern.mjs", "var shared = 1;").is_empty());
+    }
+}
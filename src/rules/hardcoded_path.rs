@@ -0,0 +1,118 @@
+use tree_sitter::Node;
+
+use super::hardcoded_secret::string_literal;
+use super::{glob_match, node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags string literals holding an absolute file-system path: a Unix path
+/// of two or more segments under a standard root such as `/etc` or
+/// `/home`, or a Windows drive path like `C:\Users`. URL paths such as
+/// `/api/users` aren't under those roots and pass. Paths matching
+/// `RuleConfig.path_allowlist` globs, and device files like `/dev/null`,
+/// are allowed.
+pub struct HardcodedPathRule;
+
+const UNIX_ROOTS: &[&str] = &[
+    "Applications", "Library", "Users", "Volumes", "bin", "dev", "etc", "home", "media", "mnt", "opt", "private",
+    "proc", "root", "sbin", "srv", "tmp", "usr", "var",
+];
+
+const ALLOWED_PATHS: &[&str] = &["/dev/null", "/dev/stdin", "/dev/stdout", "/dev/stderr"];
+
+impl Rule for HardcodedPathRule {
+    fn id(&self) -> &'static str {
+        "hardcoded-path"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Absolute paths tie code to one machine's layout, so it breaks on other hosts, containers and operating systems."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let allowlist = ctx.config.path_allowlist.as_deref().unwrap_or_default();
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let Some(value) = string_literal(&node, ctx.source) else {
+                return;
+            };
+            if !is_absolute_path(value)
+                || ALLOWED_PATHS.contains(&value)
+                || allowlist.iter().any(|pattern| glob_match(pattern, value))
+            {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Hardcoded absolute path '{}'", value),
+                    node_location(&node),
+                )
+                .with_suggestion("Read the path from configuration or build it from a base directory"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn is_absolute_path(value: &str) -> bool {
+    if value.chars().any(char::is_whitespace) {
+        return false;
+    }
+    if let Some(path) = value.strip_prefix('/') {
+        let mut segments = path.split('/');
+        let root = segments.next().unwrap_or_default();
+        return UNIX_ROOTS.contains(&root) && segments.next().is_some_and(|segment| !segment.is_empty());
+    }
+
+    // The literal's source text, so `C:\Users` is written `C:\\Users`
+    let mut chars = value.chars();
+    let drive = chars.next().is_some_and(|c| c.is_ascii_alphabetic()) && chars.next() == Some(':');
+    let rest = chars.as_str();
+    drive && rest.starts_with(['\\', '/']) && rest.trim_start_matches(['\\', '/']).len() > 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    #[test]
+    fn test_absolute_paths_are_flagged() {
+        let source = "const config = read('/etc/config');\nconst relative = read('./config');\nconst log = 'C:\\\\Logs\\\\app.log';\n";
+
+        let findings = check_source(&HardcodedPathRule, "paths.js", source);
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "hardcoded-path");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Hardcoded absolute path '/etc/config'");
+        assert_eq!(findings[1].location.line, 3);
+    }
+
+    #[test]
+    fn test_urls_short_and_allowed_paths_are_ignored() {
+        let source = r#"
+            fetch('/api/users/1');
+            fetch('https://example.com/etc/config');
+            const root = '/etc';
+            const sink = '/dev/null';
+            const scratch = `/tmp/build/out`;
+        "#;
+        let config = RuleConfig {
+            path_allowlist: Some(vec!["/tmp/*".to_string()]),
+            ..Default::default()
+        };
+
+        assert!(check_source_with_config(&HardcodedPathRule, "paths.ts", source, &config).is_empty());
+    }
+}
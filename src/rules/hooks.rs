@@ -0,0 +1,264 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags React hook calls that break the Rules of Hooks: hooks called
+/// conditionally, in loops, or outside a component or custom hook.
+pub struct HooksRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+const JSX_KINDS: &[&str] = &["jsx_element", "jsx_self_closing_element"];
+
+impl Rule for HooksRule {
+    fn id(&self) -> &'static str {
+        "hooks-rule-violation"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "React relies on hooks being called in the same order on every render; conditional or nested calls corrupt component state."
+    }
+
+    fn is_enabled(&self, ctx: &RuleContext) -> bool {
+        ctx.file_name.ends_with(".jsx") || ctx.file_name.ends_with(".tsx")
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some(hook) = hook_name(&node, ctx.source) else {
+                return;
+            };
+
+            let problem = match enclosing_function(&node) {
+                Some((function, conditional)) => {
+                    if !is_component_or_hook(&function, ctx.source) {
+                        Some("outside a component or custom hook")
+                    } else if let Some(kind) = conditional {
+                        Some(if is_loop(kind) { "inside a loop" } else { "conditionally" })
+                    } else {
+                        None
+                    }
+                }
+                None => Some("outside a component or custom hook"),
+            };
+
+            if let Some(problem) = problem {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::High,
+                        format!("React hook '{}' is called {}", hook, problem),
+                        node_location(&node),
+                    )
+                    .with_suggestion(
+                        "Call hooks unconditionally at the top level of a component or custom hook",
+                    ),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Returns the hook name if `call` invokes `useX(...)` or `React.useX(...)`.
+fn hook_name<'a>(call: &Node, source: &'a str) -> Option<&'a str> {
+    let callee = call.child_by_field_name("function")?;
+    let name = match callee.kind() {
+        "identifier" => node_text(&callee, source),
+        "member_expression" => node_text(&callee.child_by_field_name("property")?, source),
+        _ => return None,
+    };
+
+    is_hook_name(name).then_some(name)
+}
+
+fn is_hook_name(name: &str) -> bool {
+    name.strip_prefix("use")
+        .and_then(|rest| rest.chars().next())
+        .is_some_and(|c| c.is_ascii_uppercase())
+}
+
+/// Walks up from `node` to its innermost enclosing function, recording the
+/// first conditional or loop construct crossed on the way.
+fn enclosing_function<'a>(node: &Node<'a>) -> Option<(Node<'a>, Option<&'static str>)> {
+    let mut conditional = None;
+    let mut child = *node;
+
+    while let Some(parent) = child.parent() {
+        if FUNCTION_KINDS.contains(&parent.kind()) {
+            return Some((parent, conditional));
+        }
+        if conditional.is_none() {
+            conditional = conditional_kind(&parent, &child);
+        }
+        child = parent;
+    }
+
+    None
+}
+
+fn conditional_kind(parent: &Node, child: &Node) -> Option<&'static str> {
+    let is_condition = parent
+        .child_by_field_name("condition")
+        .is_some_and(|condition| condition.id() == child.id());
+
+    match parent.kind() {
+        "if_statement" if !is_condition => Some("if_statement"),
+        "ternary_expression" if !is_condition => Some("ternary_expression"),
+        "switch_case" => Some("switch_case"),
+        "for_statement" => Some("for_statement"),
+        "for_in_statement" => Some("for_in_statement"),
+        "while_statement" => Some("while_statement"),
+        "do_statement" => Some("do_statement"),
+        "binary_expression" => {
+            let operator = parent.child_by_field_name("operator")?;
+            let is_right = parent
+                .child_by_field_name("right")
+                .is_some_and(|right| right.id() == child.id());
+
+            (is_right && matches!(operator.kind(), "&&" | "||" | "??")).then_some("binary_expression")
+        }
+        _ => None,
+    }
+}
+
+fn is_loop(kind: &str) -> bool {
+    matches!(
+        kind,
+        "for_statement" | "for_in_statement" | "while_statement" | "do_statement"
+    )
+}
+
+/// A component is a capitalized function that renders JSX; a custom hook
+/// is any function named `useX`.
+fn is_component_or_hook(function: &Node, source: &str) -> bool {
+    let Some(name) = function_name(function, source) else {
+        return false;
+    };
+
+    if is_hook_name(name) {
+        return true;
+    }
+
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase()) && contains_jsx(function, source)
+}
+
+fn function_name<'a>(function: &Node, source: &'a str) -> Option<&'a str> {
+    if let Some(name) = function.child_by_field_name("name") {
+        return Some(node_text(&name, source));
+    }
+
+    // `const Foo = () => ...`, optionally wrapped as `memo(() => ...)`
+    let mut parent = function.parent()?;
+    if parent.kind() == "arguments" {
+        parent = parent.parent()?.parent()?;
+    }
+
+    match parent.kind() {
+        "variable_declarator" => Some(node_text(&parent.child_by_field_name("name")?, source)),
+        _ => None,
+    }
+}
+
+fn contains_jsx(node: &Node, source: &str) -> bool {
+    let mut found = false;
+    walk_tree(*node, source, &mut |child: Node| {
+        found |= JSX_KINDS.contains(&child.kind());
+    });
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_hook_inside_if_is_flagged() {
+        let source = r#"
+            function Profile({ user }) {
+                if (user) {
+                    const [name, setName] = useState(user.name);
+                }
+                return <div>{user.id}</div>;
+            }
+        "#;
+
+        let findings = check_source(&HooksRule, "profile.jsx", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "hooks-rule-violation");
+        assert!(matches!(findings[0].severity, Severity::High));
+        assert!(findings[0].message.contains("conditionally"));
+        assert_eq!(findings[0].location.line, 4);
+    }
+
+    #[test]
+    fn test_top_level_hook_in_component_is_allowed() {
+        let source = r#"
+            const Counter = () => {
+                const [count, setCount] = React.useState(0);
+                return <button onClick={() => setCount(count + 1)}>{count}</button>;
+            };
+
+            function useCounter() {
+                return useState(0);
+            }
+        "#;
+
+        assert!(check_source(&HooksRule, "counter.jsx", source).is_empty());
+    }
+
+    #[test]
+    fn test_hook_in_plain_function_is_flagged() {
+        let source = r#"
+            function loadData() {
+                useEffect(() => {});
+            }
+        "#;
+
+        let findings = check_source(&HooksRule, "data.tsx", source);
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("outside a component"));
+    }
+
+    #[test]
+    fn test_hook_in_loop_is_flagged() {
+        let source = r#"
+            function List({ items }) {
+                for (const item of items) {
+                    useMemo(() => item, [item]);
+                }
+                return <ul />;
+            }
+        "#;
+
+        let findings = check_source(&HooksRule, "list.jsx", source);
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("inside a loop"));
+    }
+
+    #[test]
+    fn test_only_runs_on_jsx_files() {
+        let source = "function helper() { useState(0); }";
+
+        assert!(check_source(&HooksRule, "helper.js", source).is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `Math.random()` calls. Its output is predictable, so it must not
+/// back tokens, ids or anything else security-sensitive.
+pub struct InsecureRandomRule;
+
+impl Rule for InsecureRandomRule {
+    fn id(&self) -> &'static str {
+        "insecure-random"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Math.random() is predictable, so tokens or ids derived from it can be guessed by an attacker."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.5
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_security_rules.unwrap_or(true)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let is_math_random = node
+                .child_by_field_name("function")
+                .is_some_and(|callee| is_math_random(&callee, ctx.source));
+
+            if is_math_random {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Medium,
+                        "Math.random() is not cryptographically secure".to_string(),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Use crypto.getRandomValues() in browsers or crypto.randomBytes() in Node"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+fn is_math_random(callee: &Node, source: &str) -> bool {
+    if callee.kind() != "member_expression" {
+        return false;
+    }
+
+    let object = callee.child_by_field_name("object");
+    let property = callee.child_by_field_name("property");
+    object.is_some_and(|object| node_text(&object, source) == "Math")
+        && property.is_some_and(|property| node_text(&property, source) == "random")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_math_random_is_flagged() {
+        let source = "const token = Math.random().toString(36).slice(2);\n";
+
+        let findings = check_source(&InsecureRandomRule, "session.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "insecure-random");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (1, 15));
+    }
+
+    #[test]
+    fn test_other_random_functions_are_ignored() {
+        let source = r#"
+            function random() { return 4; }
+            const a = random();
+            const b = utils.random();
+            const c = crypto.getRandomValues(new Uint32Array(1));
+        "#;
+
+        assert!(check_source(&InsecureRandomRule, "dice.ts", source).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_with_security_rules() {
+        let config = RuleConfig {
+            enable_security_rules: Some(false),
+            ..Default::default()
+        };
+
+        assert!(!InsecureRandomRule.is_enabled_by_config(&config));
+    }
+}
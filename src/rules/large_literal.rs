@@ -0,0 +1,105 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Language, Severity};
+
+/// Flags array and object literals with more than
+/// `RuleConfig.max_literal_elements` direct elements, which usually means
+/// generated data was pasted into source. JSON files are data already and
+/// are skipped.
+pub struct LargeInlineLiteralRule;
+
+const DEFAULT_MAX_LITERAL_ELEMENTS: u32 = 100;
+
+impl Rule for LargeInlineLiteralRule {
+    fn id(&self) -> &'static str {
+        "large-inline-literal"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Large data literals bloat source files and bundles and are easier to maintain as separate data files."
+    }
+
+    fn is_enabled(&self, ctx: &RuleContext) -> bool {
+        ctx.language != Language::Json
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max = ctx.config.max_literal_elements.unwrap_or(DEFAULT_MAX_LITERAL_ELEMENTS);
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let kind = match node.kind() {
+                "array" => "Array",
+                "object" => "Object",
+                _ => return,
+            };
+
+            let mut cursor = node.walk();
+            let elements = node
+                .named_children(&mut cursor)
+                .filter(|child| child.kind() != "comment")
+                .count() as u32;
+
+            if elements > max {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("{} literal has {} elements (max: {})", kind, elements, max),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Move the data to a JSON file or load it at runtime"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    fn array_literal(count: usize) -> String {
+        let elements: Vec<String> = (0..count).map(|i| i.to_string()).collect();
+        format!("export const data = [{}];\n", elements.join(", "))
+    }
+
+    #[test]
+    fn test_large_array_is_flagged() {
+        let findings = check_source(&LargeInlineLiteralRule, "data.js", &array_literal(150));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "large-inline-literal");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Array literal has 150 elements (max: 100)");
+        assert_eq!(findings[0].location.column, 21);
+    }
+
+    #[test]
+    fn test_only_direct_elements_count() {
+        let rows: Vec<String> = (0..20).map(|i| format!("{{ id: {}, name: 'n{}' }}", i, i)).collect();
+        let source = format!("const rows = [\n  // generated\n  {}\n];\n", rows.join(",\n  "));
+
+        assert!(check_source(&LargeInlineLiteralRule, "rows.ts", &source).is_empty());
+        assert!(check_source(&LargeInlineLiteralRule, "data.js", &array_literal(100)).is_empty());
+    }
+
+    #[test]
+    fn test_configured_limit() {
+        let config = RuleConfig {
+            max_literal_elements: Some(2),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&LargeInlineLiteralRule, "app.js", "const o = { a: 1, b, ...rest };\n", &config);
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.starts_with("Object literal has 3"));
+    }
+}
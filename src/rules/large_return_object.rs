@@ -0,0 +1,107 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags functions that return an object literal with more than
+/// `RuleConfig.max_return_object_properties` properties, either from a
+/// `return` statement or as an arrow function's `=> ({ ... })` body. Such
+/// factories are easier to follow split into smaller builders.
+pub struct LargeReturnObjectRule;
+
+const DEFAULT_MAX_RETURN_OBJECT_PROPERTIES: u32 = 15;
+
+impl Rule for LargeReturnObjectRule {
+    fn id(&self) -> &'static str {
+        "large-return-object"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Factories returning huge object literals are hard to review and usually mix several concerns worth splitting."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max = ctx
+            .config
+            .max_return_object_properties
+            .unwrap_or(DEFAULT_MAX_RETURN_OBJECT_PROPERTIES);
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let returned = match node.kind() {
+                "return_statement" => node.named_child(0),
+                "arrow_function" => node.child_by_field_name("body"),
+                _ => return,
+            };
+            let Some(object) = returned.and_then(unwrap_parentheses).filter(|value| value.kind() == "object") else {
+                return;
+            };
+
+            let mut cursor = object.walk();
+            let properties = object
+                .named_children(&mut cursor)
+                .filter(|child| child.kind() != "comment")
+                .count() as u32;
+
+            if properties > max {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("Returned object literal has {} properties (max: {})", properties, max),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Build the object from smaller, named parts"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+fn unwrap_parentheses(mut node: Node) -> Option<Node> {
+    while node.kind() == "parenthesized_expression" {
+        node = node.named_child(0)?;
+    }
+    Some(node)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    fn returning(properties: usize) -> String {
+        let body: Vec<String> = (1..=properties).map(|i| format!("    key{}: {},", i, i)).collect();
+        format!("function createConfig() {{\n  return {{\n{}\n  }};\n}}\n", body.join("\n"))
+    }
+
+    #[test]
+    fn test_large_returned_object_is_flagged() {
+        let findings = check_source(&LargeReturnObjectRule, "config.js", &returning(20));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "large-return-object");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Returned object literal has 20 properties (max: 15)");
+        assert_eq!(findings[0].location.line, 2);
+        assert!(check_source(&LargeReturnObjectRule, "config.js", &returning(15)).is_empty());
+    }
+
+    #[test]
+    fn test_arrow_object_body_uses_configured_limit() {
+        let source = "const point = (x, y) => ({ x, y, z: 0 });\n";
+        let config = RuleConfig {
+            max_return_object_properties: Some(2),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&LargeReturnObjectRule, "point.ts", source, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].message, "Returned object literal has 3 properties (max: 2)");
+    }
+}
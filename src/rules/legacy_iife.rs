@@ -0,0 +1,111 @@
+use tree_sitter::Node;
+
+use super::{node_location, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags immediately-invoked function expressions such as
+/// `(function () { ... })()` and `(() => { ... })()`, the pre-ES-module way
+/// of scoping a file. Opt-in via `RuleConfig.detect_legacy_iife`, for
+/// codebases being modernized.
+pub struct LegacyIifeRule;
+
+const FUNCTION_KINDS: &[&str] = &["function_expression", "function", "arrow_function"];
+
+impl Rule for LegacyIifeRule {
+    fn id(&self) -> &'static str {
+        "legacy-iife"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "IIFEs emulate module scope by hand; ES modules provide it natively and make dependencies explicit."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_legacy_iife.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if is_iife(&node) {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        "Immediately-invoked function expression".to_string(),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Use an ES module, whose top-level scope is already private"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Number of IIFEs anywhere in the tree.
+pub fn iife_count(root: Node, source: &str) -> u32 {
+    let mut count = 0;
+    walk_tree(root, source, &mut |node: Node| {
+        if is_iife(&node) {
+            count += 1;
+        }
+    });
+    count
+}
+
+/// A call whose callee is a function expression, parenthesized as in
+/// `(function () {})()` or bare inside the parentheses of `(function () {}())`.
+fn is_iife(node: &Node) -> bool {
+    if node.kind() != "call_expression" {
+        return false;
+    }
+    let Some(mut callee) = node.child_by_field_name("function") else {
+        return false;
+    };
+    let mut parenthesized = node.parent().is_some_and(|parent| parent.kind() == "parenthesized_expression");
+    while callee.kind() == "parenthesized_expression" {
+        parenthesized = true;
+        match callee.named_child(0) {
+            Some(inner) => callee = inner,
+            None => return false,
+        }
+    }
+
+    parenthesized && FUNCTION_KINDS.contains(&callee.kind())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{javascript::JavaScriptParser, Parser};
+    use crate::rules::check_source_with_config;
+
+    const SOURCE: &str = "(function () {\n  var counter = 0;\n})();\n(() => init())();\n(function () { run(); }());\nconst value = (function () { return 1; });\nhandler(function () {})();\n";
+
+    #[test]
+    fn test_classic_iife_is_flagged() {
+        let config = RuleConfig {
+            detect_legacy_iife: Some(true),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&LegacyIifeRule, "legacy.js", SOURCE, &config);
+
+        assert_eq!(findings.len(), 3);
+        assert_eq!(findings[0].rule_id, "legacy-iife");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (1, 1));
+        assert_eq!(findings[2].location.line, 5);
+    }
+
+    #[test]
+    fn test_iife_count() {
+        let parse_result = JavaScriptParser::new().unwrap().parse(SOURCE).unwrap();
+
+        assert_eq!(iife_count(parse_result.tree.root_node(), SOURCE), 3);
+    }
+}
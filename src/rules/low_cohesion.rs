@@ -0,0 +1,88 @@
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, RuleConfig, Severity};
+
+/// Flags classes whose cohesion (the share of method pairs using a common
+/// field, see `parser::class_cohesion`) is below
+/// `RuleConfig.min_class_cohesion`. Opt-in, since the threshold depends on
+/// the codebase.
+pub struct LowCohesionRule;
+
+impl Rule for LowCohesionRule {
+    fn id(&self) -> &'static str {
+        "low-cohesion"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Methods that share no state are separate responsibilities living in one class."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.6
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.min_class_cohesion.is_some()
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let Some(min_cohesion) = ctx.config.min_class_cohesion else {
+            return Vec::new();
+        };
+
+        ctx.parse_result
+            .classes
+            .iter()
+            .filter_map(|class| {
+                let cohesion = class.cohesion.filter(|cohesion| *cohesion < min_cohesion)?;
+                Some(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("Class '{}' has cohesion {:.2} (min: {:.2})", class.name, cohesion, min_cohesion),
+                        Location {
+                            line: class.line,
+                            column: 1,
+                            end_line: None,
+                            end_column: None,
+                        },
+                    )
+                    .with_suggestion("Split the groups of methods that share no fields into separate classes"),
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    const SOURCE: &str = r#"
+class Account {
+    deposit(amount) { this.balance += amount; }
+    render() { return `<b>${this.title}</b>`; }
+}
+"#;
+
+    #[test]
+    fn test_disjoint_methods_are_flagged() {
+        let config = RuleConfig {
+            min_class_cohesion: Some(0.5),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&LowCohesionRule, "account.js", SOURCE, &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "low-cohesion");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Class 'Account' has cohesion 0.00 (min: 0.50)");
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        assert!(check_source(&LowCohesionRule, "account.js", SOURCE).is_empty());
+    }
+}
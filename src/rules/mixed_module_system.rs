@@ -0,0 +1,120 @@
+use tree_sitter::Node;
+
+use super::{node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::parser::ImportKind;
+use crate::types::{Finding, Location, Severity};
+
+/// Flags files that use both CommonJS (`require(...)`, `module.exports`,
+/// `exports.x = ...`) and ES module syntax (`import`, `export`). Dynamic
+/// `import(...)` works under either system and doesn't count.
+pub struct MixedModuleSystemRule;
+
+impl Rule for MixedModuleSystemRule {
+    fn id(&self) -> &'static str {
+        "mixed-module-system"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A file using both require and import is a half-finished migration whose behavior depends on how the bundler interops them."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let imports = &ctx.parse_result.imports;
+        let first_import = |kind| imports.iter().filter(|import| import.kind == kind).map(|import| import.line).min();
+        let (commonjs_exports, esm_exports) = export_lines(&ctx.parse_result.tree.root_node(), ctx.source);
+
+        let commonjs = first_import(ImportKind::Require).into_iter().chain(commonjs_exports).min();
+        let esm = first_import(ImportKind::Static).into_iter().chain(esm_exports).min();
+        let (Some(commonjs), Some(esm)) = (commonjs, esm) else {
+            return Vec::new();
+        };
+
+        vec![Finding::new(
+            self.id(),
+            Severity::Low,
+            format!("File mixes CommonJS (line {}) and ES module (line {}) syntax", commonjs, esm),
+            Location {
+                line: commonjs.max(esm),
+                column: 1,
+                end_line: None,
+                end_column: None,
+            },
+        )
+        .with_suggestion("Convert the file to one module system, preferably ES modules")]
+    }
+}
+
+/// First lines of a CommonJS export assignment and of an `export`
+/// statement, if any.
+fn export_lines(root: &Node, source: &str) -> (Option<u32>, Option<u32>) {
+    let mut commonjs = None;
+    let mut esm = None;
+
+    walk_tree(*root, source, &mut |node: Node| {
+        let line = node.start_position().row as u32 + 1;
+        match node.kind() {
+            "export_statement" => {
+                esm.get_or_insert(line);
+            }
+            "assignment_expression" => {
+                let is_commonjs = node
+                    .child_by_field_name("left")
+                    .is_some_and(|target| is_commonjs_export(&target, source));
+                if is_commonjs {
+                    commonjs.get_or_insert(line);
+                }
+            }
+            _ => {}
+        }
+    });
+
+    (commonjs, esm)
+}
+
+/// `module.exports`, `exports.x` and `module.exports.x`.
+fn is_commonjs_export(target: &Node, source: &str) -> bool {
+    if target.kind() != "member_expression" {
+        return false;
+    }
+    let text = node_text(target, source);
+    text == "module.exports"
+        || target
+            .child_by_field_name("object")
+            .is_some_and(|object| matches!(node_text(&object, source), "exports" | "module.exports"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_require_and_import_in_one_file_is_flagged() {
+        let source = "import path from 'path';\nconst fs = require('fs');\nexport const read = (file) => fs.readFileSync(file);\n";
+
+        let findings = check_source(&MixedModuleSystemRule, "read.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "mixed-module-system");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "File mixes CommonJS (line 2) and ES module (line 1) syntax");
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_module_exports_with_export_statement_is_flagged() {
+        let source = "export function a() {}\nmodule.exports.b = function () {};\n";
+
+        assert_eq!(check_source(&MixedModuleSystemRule, "mixed.ts", source).len(), 1);
+    }
+
+    #[test]
+    fn test_single_module_system_is_ignored() {
+        let commonjs = "const fs = require('fs');\nexports.load = () => import('./lazy.js');\nmodule.exports = { fs };\n";
+        let esm = "import fs from 'fs';\nexport const lazy = () => import('./lazy.js');\n";
+
+        assert!(check_source(&MixedModuleSystemRule, "common.js", commonjs).is_empty());
+        assert!(check_source(&MixedModuleSystemRule, "esm.ts", esm).is_empty());
+    }
+}
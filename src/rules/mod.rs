@@ -0,0 +1,471 @@
+use std::time::Instant;
+use tree_sitter::Node;
+
+use crate::{
+    parser::ParseResult,
+    types::{Finding, Language, Location, RuleConfig, RuleDescriptor, Severity, SkippedRule},
+};
+
+pub mod asi_hazard;
+pub mod await_in_loop;
+pub mod barrel;
+pub mod blocking_in_async;
+pub mod boolean_parameter;
+pub mod broad_catch;
+pub mod callback_hell;
+pub mod complex_regex;
+pub mod complexity;
+pub mod complexity_overflow;
+pub mod dead_code;
+pub mod deep_destructuring;
+pub mod deprecated_api;
+pub mod duplicate_key;
+pub mod float_equality;
+pub mod floating_promise;
+pub mod globals;
+pub mod hardcoded_path;
+pub mod hardcoded_secret;
+pub mod hooks;
+pub mod insecure_random;
+pub mod large_literal;
+pub mod large_return_object;
+pub mod legacy_iife;
+pub mod low_cohesion;
+pub mod mixed_module_system;
+pub mod multiple_responsibilities;
+pub mod non_exhaustive_switch;
+pub mod prototype_pollution;
+pub mod quadratic_spread;
+pub mod redos;
+pub mod redundant_boolean;
+pub mod skipped_tests;
+pub mod sql_injection;
+pub mod string_concat;
+pub mod switch_fallthrough;
+pub mod sync_xhr;
+pub mod tight_coupling;
+pub mod todo_density;
+pub mod too_many_imports;
+pub mod too_many_statements;
+pub mod trivial_function;
+pub mod unassigned_todo;
+pub mod unchecked_env;
+pub mod unnecessary_async;
+pub mod unsafe_cast;
+pub mod unused_private_field;
+pub mod use_before_init;
+pub mod variable_shadowing;
+pub mod xss;
+pub mod zero_delay_timer;
+
+/// Everything a rule needs to inspect a single parsed file.
+pub struct RuleContext<'a> {
+    pub file_name: &'a str,
+    pub language: Language,
+    pub source: &'a str,
+    pub parse_result: &'a ParseResult,
+    pub config: &'a RuleConfig,
+}
+
+pub trait Rule: Send + Sync {
+    fn id(&self) -> &'static str;
+
+    /// Why the rule exists and the risk it guards against, in a sentence.
+    fn rationale(&self) -> &'static str;
+
+    /// Whether the rule is switched on by the request's config. Opt-in
+    /// rules override this; `disabled_rules` is checked separately.
+    fn is_enabled_by_config(&self, _config: &RuleConfig) -> bool {
+        true
+    }
+
+    /// How often a finding from this rule is a real problem, from 0.0 to
+    /// 1.0. Rules matching exact syntax keep the default; rules that guess
+    /// from names, values or shapes report less.
+    fn confidence(&self) -> f64 {
+        1.0
+    }
+
+    /// Whether the rule applies to this file.
+    fn is_enabled(&self, _ctx: &RuleContext) -> bool {
+        true
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding>;
+}
+
+pub struct RuleSet {
+    rules: Vec<Box<dyn Rule>>,
+}
+
+impl RuleSet {
+    pub fn new() -> Self {
+        let rules: Vec<Box<dyn Rule>> = vec![
+            Box::new(hooks::HooksRule),
+            Box::new(barrel::BarrelFileRule),
+            Box::new(globals::GlobalPollutionRule),
+            Box::new(skipped_tests::SkippedTestRule),
+            Box::new(todo_density::TodoDensityRule),
+            Box::new(complexity::HighComplexityRule),
+            Box::new(complexity_overflow::ComplexityOverflowRule),
+            Box::new(string_concat::StringConcatInLoopRule),
+            Box::new(trivial_function::TrivialFunctionRule),
+            Box::new(callback_hell::CallbackHellRule),
+            Box::new(float_equality::FloatEqualityRule),
+            Box::new(blocking_in_async::BlockingInAsyncRule),
+            Box::new(redundant_boolean::RedundantBooleanRule),
+            Box::new(asi_hazard::AsiHazardRule),
+            Box::new(dead_code::DeadCodeRule),
+            Box::new(hardcoded_secret::HardcodedSecretRule),
+            Box::new(too_many_statements::TooManyStatementsRule),
+            Box::new(unassigned_todo::UnassignedTodoRule),
+            Box::new(duplicate_key::DuplicateObjectKeyRule),
+            Box::new(quadratic_spread::QuadraticSpreadRule),
+            Box::new(switch_fallthrough::SwitchFallthroughRule),
+            Box::new(redos::PotentialRedosRule),
+            Box::new(large_literal::LargeInlineLiteralRule),
+            Box::new(insecure_random::InsecureRandomRule),
+            Box::new(xss::XssRiskRule),
+            Box::new(tight_coupling::TightCouplingRule),
+            Box::new(unchecked_env::UncheckedEnvAccessRule),
+            Box::new(broad_catch::BroadCatchRule),
+            Box::new(unsafe_cast::UnsafeCastRule),
+            Box::new(unused_private_field::UnusedPrivateFieldRule),
+            Box::new(boolean_parameter::BooleanParameterRule),
+            Box::new(too_many_imports::TooManyImportsRule),
+            Box::new(await_in_loop::AwaitInLoopRule),
+            Box::new(sync_xhr::SyncXhrRule),
+            Box::new(large_return_object::LargeReturnObjectRule),
+            Box::new(zero_delay_timer::ZeroDelayTimerRule),
+            Box::new(legacy_iife::LegacyIifeRule),
+            Box::new(non_exhaustive_switch::NonExhaustiveSwitchRule),
+            Box::new(deprecated_api::DeprecatedApiRule),
+            Box::new(low_cohesion::LowCohesionRule),
+            Box::new(unnecessary_async::UnnecessaryAsyncRule),
+            Box::new(prototype_pollution::PrototypePollutionRule),
+            Box::new(variable_shadowing::VariableShadowingRule),
+            Box::new(complex_regex::ComplexRegexRule),
+            Box::new(use_before_init::UseBeforeInitRule),
+            Box::new(hardcoded_path::HardcodedPathRule),
+            Box::new(multiple_responsibilities::MultipleResponsibilitiesRule),
+            Box::new(sql_injection::SqlInjectionRule),
+            Box::new(floating_promise::FloatingPromiseRule),
+            Box::new(deep_destructuring::DeepDestructuringRule),
+            Box::new(mixed_module_system::MixedModuleSystemRule),
+        ];
+
+        Self { rules }
+    }
+
+    pub fn ids(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.id()).collect()
+    }
+
+    /// Catalog entries for every rule, in registration order.
+    pub fn descriptors(&self) -> Vec<RuleDescriptor> {
+        let defaults = RuleConfig::default();
+        self.rules
+            .iter()
+            .map(|rule| RuleDescriptor {
+                id: rule.id().to_string(),
+                rationale: rule.rationale().to_string(),
+                enabled_by_default: rule.is_enabled_by_config(&defaults),
+                confidence: rule.confidence(),
+            })
+            .collect()
+    }
+
+    /// Identifies this build's rules, so cached results are invalidated when
+    /// rules are added or removed or the crate version changes.
+    pub fn fingerprint(&self) -> String {
+        format!("{}:{}", env!("CARGO_PKG_VERSION"), self.ids().join(","))
+    }
+
+    /// A run in which every rule was skipped for `reason`.
+    pub fn skip_all(&self, reason: &str) -> RuleRun {
+        RuleRun {
+            skipped: self
+                .rules
+                .iter()
+                .map(|rule| SkippedRule {
+                    rule_id: rule.id().to_string(),
+                    reason: reason.to_string(),
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Runs each enabled rule in `RuleConfig.rule_order`, then registration
+    /// order. With `stop_on_critical`, rules after the first one to report a
+    /// `Critical` finding are skipped.
+    pub fn run(&self, ctx: &RuleContext) -> RuleRun {
+        let stop_on_critical = ctx.config.stop_on_critical.unwrap_or(false);
+        let mut stopped = false;
+        let mut run = RuleRun::default();
+
+        for rule in self.ordered(ctx.config) {
+            let disabled = !rule.is_enabled_by_config(ctx.config)
+                || ctx
+                    .config
+                    .disabled_rules
+                    .as_ref()
+                    .is_some_and(|ids| ids.iter().any(|id| id == rule.id()));
+
+            let reason = if disabled {
+                Some("disabled by config")
+            } else if !rule.is_enabled(ctx) {
+                Some("not applicable to this file")
+            } else if stopped {
+                Some("stopped after a critical finding")
+            } else {
+                None
+            };
+
+            match reason {
+                Some(reason) => run.skipped.push(SkippedRule {
+                    rule_id: rule.id().to_string(),
+                    reason: reason.to_string(),
+                }),
+                None => {
+                    let start = Instant::now();
+                    let mut findings = rule.check(ctx);
+                    for finding in &mut findings {
+                        finding.confidence = rule.confidence();
+                    }
+                    run.timings_micros.push((rule.id(), start.elapsed().as_micros() as u64));
+                    run.executed.push(rule.id().to_string());
+
+                    stopped = stop_on_critical
+                        && findings
+                            .iter()
+                            .any(|finding| matches!(finding.severity, Severity::Critical));
+                    run.findings.extend(findings);
+                }
+            }
+        }
+
+        run
+    }
+
+    fn ordered(&self, config: &RuleConfig) -> Vec<&dyn Rule> {
+        let first = config.rule_order.as_deref().unwrap_or_default();
+        let mut rules: Vec<&dyn Rule> = Vec::new();
+        for id in first {
+            // An id listed twice still runs its rule once, at the first place
+            let listed = rules.iter().any(|rule| rule.id() == id);
+            if let Some(rule) = self.rules.iter().find(|rule| rule.id() == id).filter(|_| !listed) {
+                rules.push(rule.as_ref());
+            }
+        }
+        rules.extend(
+            self.rules
+                .iter()
+                .filter(|rule| !first.iter().any(|id| id == rule.id()))
+                .map(|rule| rule.as_ref()),
+        );
+        rules
+    }
+}
+
+/// Outcome of running a `RuleSet` over one file.
+#[derive(Debug, Default)]
+pub struct RuleRun {
+    pub findings: Vec<Finding>,
+    pub executed: Vec<String>,
+    pub skipped: Vec<SkippedRule>,
+    /// Time spent in each executed rule's `check`, in microseconds.
+    pub timings_micros: Vec<(&'static str, u64)>,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) fn node_text<'a>(node: &Node, source: &'a str) -> &'a str {
+    source.get(node.start_byte()..node.end_byte()).unwrap_or("")
+}
+
+pub(crate) fn node_location(node: &Node) -> Location {
+    let start = node.start_position();
+    let end = node.end_position();
+
+    Location {
+        line: start.row as u32 + 1,
+        column: start.column as u32 + 1,
+        end_line: Some(end.row as u32 + 1),
+        end_column: Some(end.column as u32 + 1),
+    }
+}
+
+/// Whether `file_name` looks like a test file (`*.test.*`, `*.spec.*`, or a
+/// file under a `test`, `tests` or `__tests__` directory).
+pub(crate) fn is_test_file(file_name: &str) -> bool {
+    let mut segments: Vec<&str> = file_name.split(['/', '\\']).collect();
+    let Some(base) = segments.pop() else {
+        return false;
+    };
+
+    base.contains(".test.")
+        || base.contains(".spec.")
+        || segments
+            .iter()
+            .any(|dir| matches!(*dir, "test" | "tests" | "__tests__"))
+}
+
+/// Matches `name` against a pattern where `*` is any run of characters and
+/// `?` any single character. Patterns without wildcards must match exactly.
+pub(crate) fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                // Let the last `*` absorb one more character
+                Some((star, matched)) => {
+                    p = star + 1;
+                    n = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+pub(crate) fn check_source(rule: &dyn Rule, file_name: &str, source: &str) -> Vec<Finding> {
+    check_source_with_config(rule, file_name, source, &RuleConfig::default())
+}
+
+#[cfg(test)]
+pub(crate) fn check_source_with_config(
+    rule: &dyn Rule,
+    file_name: &str,
+    source: &str,
+    config: &RuleConfig,
+) -> Vec<Finding> {
+    use crate::parser::{javascript::JavaScriptParser, json::JsonParser, typescript::TypeScriptParser, Parser};
+
+    let language = Language::from_filename(file_name).unwrap();
+    let parser: Box<dyn Parser> = match language {
+        Language::TypeScript => Box::new(TypeScriptParser::new().unwrap()),
+        Language::Json => Box::new(JsonParser::new().unwrap()),
+        _ => Box::new(JavaScriptParser::new().unwrap()),
+    };
+    let parse_result = parser.parse_file(file_name, source).unwrap();
+    let ctx = RuleContext {
+        file_name,
+        language,
+        source,
+        parse_result: &parse_result,
+        config,
+    };
+
+    if rule.is_enabled_by_config(config) && rule.is_enabled(&ctx) {
+        rule.check(&ctx)
+    } else {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_default_rules(source: &str, config: &RuleConfig) -> RuleRun {
+        use crate::parser::{javascript::JavaScriptParser, Parser};
+
+        let parse_result = JavaScriptParser::new().unwrap().parse(source).unwrap();
+        RuleSet::new().run(&RuleContext {
+            file_name: "config.js",
+            language: Language::JavaScript,
+            source,
+            parse_result: &parse_result,
+            config,
+        })
+    }
+
+    #[test]
+    fn test_stop_on_critical_skips_later_rules() {
+        let source = "const password = \"hunter2-prod-123\";\nif (ratio === 0.1) { leaked = 1; }\n";
+        let config = RuleConfig {
+            stop_on_critical: Some(true),
+            rule_order: Some(vec!["hardcoded-secret".to_string()]),
+            ..Default::default()
+        };
+
+        let run = run_default_rules(source, &config);
+
+        assert_eq!(run.executed, vec!["hardcoded-secret"]);
+        assert_eq!(run.findings.len(), 1);
+        assert!(matches!(run.findings[0].severity, Severity::Critical));
+        assert!(run
+            .skipped
+            .iter()
+            .any(|skipped| skipped.rule_id == "float-equality" && skipped.reason == "stopped after a critical finding"));
+
+        // Without the flag every rule still runs
+        let run = run_default_rules(source, &RuleConfig::default());
+        assert!(run.executed.iter().any(|id| id == "float-equality"));
+        assert!(run.findings.len() > 1);
+    }
+
+    #[test]
+    fn test_rule_order_runs_listed_rules_first() {
+        let config = RuleConfig {
+            rule_order: Some(vec!["float-equality".to_string(), "hooks-rule-violation".to_string()]),
+            ..Default::default()
+        };
+
+        let run = run_default_rules("const a = 1;\n", &config);
+
+        assert_eq!(run.executed[0], "float-equality");
+        assert_eq!(run.executed.len() + run.skipped.len(), RuleSet::new().ids().len());
+    }
+
+    #[test]
+    fn test_rule_listed_twice_in_order_runs_once() {
+        let source = "if (ratio === 0.1) { run(); }\n";
+        let config = RuleConfig {
+            rule_order: Some(vec![
+                "float-equality".to_string(),
+                "hooks-rule-violation".to_string(),
+                "float-equality".to_string(),
+            ]),
+            ..Default::default()
+        };
+
+        let run = run_default_rules(source, &config);
+
+        assert_eq!(run.executed.iter().filter(|id| *id == "float-equality").count(), 1);
+        assert_eq!(run.executed[0], "float-equality");
+        assert_eq!(run.findings.iter().filter(|f| f.rule_id == "float-equality").count(), 1);
+        assert_eq!(run.executed.len() + run.skipped.len(), RuleSet::new().ids().len());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("handle*", "handleClick"));
+        assert!(glob_match("*Handler", "lambdaHandler"));
+        assert!(glob_match("get?", "getX"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("handle*", "onHandle"));
+        assert!(!glob_match("main", "mainly"));
+    }
+}
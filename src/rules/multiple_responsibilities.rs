@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use super::{Rule, RuleContext};
+use crate::parser::MethodAccess;
+use crate::types::{Finding, Location, RuleConfig, Severity};
+
+/// Flags classes whose instance methods split into two or more groups that
+/// share no fields. Methods are grouped when they use a common `this.`
+/// field or one calls the other. Methods that touch no state are left out,
+/// as are single-method groups, so a class of plain accessors isn't
+/// flagged. Opt-in via `RuleConfig.detect_multiple_responsibilities`.
+pub struct MultipleResponsibilitiesRule;
+
+impl Rule for MultipleResponsibilitiesRule {
+    fn id(&self) -> &'static str {
+        "multiple-responsibilities"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Groups of methods with no state in common are separate classes waiting to be extracted."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.6
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_multiple_responsibilities.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        ctx.parse_result
+            .classes
+            .iter()
+            .filter_map(|class| {
+                let groups = method_groups(&class.methods);
+                if groups.len() < 2 {
+                    return None;
+                }
+
+                let listed: Vec<String> = groups.iter().map(|group| group.join(", ")).collect();
+                Some(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!(
+                            "Class '{}' has {} unrelated groups of methods: {}",
+                            class.name,
+                            groups.len(),
+                            listed.join(" | ")
+                        ),
+                        Location {
+                            line: class.line,
+                            column: 1,
+                            end_line: None,
+                            end_column: None,
+                        },
+                    )
+                    .with_suggestion(&format!("Consider splitting it into {} classes, one per group", groups.len())),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Methods connected through shared fields or calls, in declaration order,
+/// keeping only groups of two or more methods.
+fn method_groups(methods: &[MethodAccess]) -> Vec<Vec<&str>> {
+    fn root(parents: &mut [usize], index: usize) -> usize {
+        let mut current = index;
+        while parents[current] != current {
+            parents[current] = parents[parents[current]];
+            current = parents[current];
+        }
+        current
+    }
+
+    let stateful: Vec<&MethodAccess> = methods.iter().filter(|method| !method.fields.is_empty()).collect();
+    let positions: HashMap<&str, usize> = stateful
+        .iter()
+        .enumerate()
+        .map(|(index, method)| (method.name.as_str(), index))
+        .collect();
+
+    let mut parents: Vec<usize> = (0..stateful.len()).collect();
+    let mut field_owners: HashMap<&str, usize> = HashMap::new();
+    for (index, method) in stateful.iter().enumerate() {
+        let linked = method
+            .fields
+            .iter()
+            .filter_map(|field| field_owners.insert(field.as_str(), index))
+            .chain(method.calls.iter().filter_map(|call| positions.get(call.as_str()).copied()));
+        for other in linked.collect::<Vec<_>>() {
+            let (first, second) = (root(&mut parents, index), root(&mut parents, other));
+            parents[first] = second;
+        }
+    }
+
+    let mut groups: Vec<(usize, Vec<&str>)> = Vec::new();
+    for (index, method) in stateful.iter().enumerate() {
+        let group_root = root(&mut parents, index);
+        match groups.iter_mut().find(|(root, _)| *root == group_root) {
+            Some((_, names)) => names.push(&method.name),
+            None => groups.push((group_root, vec![&method.name])),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(_, names)| names)
+        .filter(|names| names.len() > 1)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    const SOURCE: &str = r#"
+class Account {
+    deposit(amount) { this.balance += amount; this.log(amount); }
+    withdraw(amount) { this.balance -= amount; }
+    log(amount) { this.history.push(amount); }
+    render() { return `<b>${this.title}</b>`; }
+    rename(title) { this.title = title; }
+    static open() { return new Account(); }
+}
+"#;
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_multiple_responsibilities: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_disjoint_method_groups_are_flagged() {
+        let findings = check_source_with_config(&MultipleResponsibilitiesRule, "account.js", SOURCE, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "multiple-responsibilities");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(
+            findings[0].message,
+            "Class 'Account' has 2 unrelated groups of methods: deposit, withdraw, log | render, rename"
+        );
+        assert_eq!(findings[0].suggestion.as_deref(), Some("Consider splitting it into 2 classes, one per group"));
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_single_group_and_accessors_are_ignored() {
+        let source = r#"
+class Cart {
+    add(item) { this.items.push(item); this.refresh(); }
+    refresh() { this.total = this.items.length; }
+}
+class Person {
+    getName() { return this.name; }
+    getAge() { return this.age; }
+    greet() { return 'hi'; }
+}
+"#;
+
+        assert!(check_source_with_config(&MultipleResponsibilitiesRule, "cart.js", source, &enabled()).is_empty());
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        assert!(check_source(&MultipleResponsibilitiesRule, "account.js", SOURCE).is_empty());
+    }
+}
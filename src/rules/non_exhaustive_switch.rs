@@ -0,0 +1,144 @@
+use std::collections::{HashMap, HashSet};
+
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::parser::typescript::enum_members;
+use crate::types::{Finding, Severity};
+
+/// Flags a `switch` over a TypeScript enum declared in the same file that
+/// has no `default` and leaves some members without a `case`. A switch is
+/// taken to be over an enum when every case value is one of its members,
+/// as in `case Color.Red:`.
+pub struct NonExhaustiveSwitchRule;
+
+impl Rule for NonExhaustiveSwitchRule {
+    fn id(&self) -> &'static str {
+        "non-exhaustive-switch"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A switch that skips enum members silently does nothing for them, and new members go unhandled too."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let enums = enum_declarations(&root, ctx.source);
+        if enums.is_empty() {
+            return Vec::new();
+        }
+
+        let mut findings = Vec::new();
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if node.kind() != "switch_statement" {
+                return;
+            }
+            let Some((name, handled)) = node
+                .child_by_field_name("body")
+                .and_then(|body| enum_cases(&body, ctx.source))
+            else {
+                return;
+            };
+            let Some(members) = enums.get(name) else {
+                return;
+            };
+
+            let missing: Vec<&str> = members
+                .iter()
+                .map(String::as_str)
+                .filter(|member| !handled.contains(member))
+                .collect();
+            if missing.is_empty() {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!("Switch over {} is missing cases: {}", name, missing.join(", ")),
+                    node_location(&node),
+                )
+                .with_suggestion("Add a case for each missing member, or a default case"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Enum names declared in the file, with their members.
+fn enum_declarations<'a>(root: &Node, source: &'a str) -> HashMap<&'a str, Vec<String>> {
+    let mut enums = HashMap::new();
+
+    walk_tree(*root, source, &mut |node: Node| {
+        if node.kind() != "enum_declaration" {
+            return;
+        }
+        if let Some(name) = node.child_by_field_name("name") {
+            enums.insert(node_text(&name, source), enum_members(&node, source));
+        }
+    });
+
+    enums
+}
+
+/// The enum a switch body's cases all belong to, and the members they
+/// handle. None if the switch has a `default` or any other kind of case.
+fn enum_cases<'a>(body: &Node, source: &'a str) -> Option<(&'a str, HashSet<&'a str>)> {
+    let mut enum_name = None;
+    let mut handled = HashSet::new();
+
+    let mut cursor = body.walk();
+    for case in body.named_children(&mut cursor) {
+        match case.kind() {
+            "switch_default" => return None,
+            "switch_case" => {}
+            _ => continue,
+        }
+        let value = case.child_by_field_name("value").filter(|value| value.kind() == "member_expression")?;
+        let object = node_text(&value.child_by_field_name("object")?, source);
+        if enum_name.is_some_and(|name| name != object) {
+            return None;
+        }
+        enum_name = Some(object);
+        handled.insert(node_text(&value.child_by_field_name("property")?, source));
+    }
+
+    enum_name.map(|name| (name, handled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    const ENUM: &str = "enum Direction {\n  Up,\n  Down = 2,\n  Left,\n}\n";
+
+    #[test]
+    fn test_missing_enum_member_is_flagged() {
+        let source = format!(
+            "{}function move(d: Direction) {{\n  switch (d) {{\n    case Direction.Up:\n      return -1;\n    case Direction.Down:\n      return 1;\n  }}\n}}\n",
+            ENUM
+        );
+
+        let findings = check_source(&NonExhaustiveSwitchRule, "direction.ts", &source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "non-exhaustive-switch");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].message, "Switch over Direction is missing cases: Left");
+        assert_eq!(findings[0].location.line, 7);
+    }
+
+    #[test]
+    fn test_default_or_unrelated_cases_are_ignored() {
+        let source = format!(
+            "{}switch (d) {{\n  case Direction.Up: break;\n  default: break;\n}}\nswitch (key) {{\n  case 'Up': break;\n}}\nswitch (d) {{\n  case Direction.Up: case Direction.Down: case Direction.Left: break;\n}}\n",
+            ENUM
+        );
+
+        assert!(check_source(&NonExhaustiveSwitchRule, "direction.ts", &source).is_empty());
+    }
+}
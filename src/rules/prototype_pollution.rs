@@ -0,0 +1,118 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags assignments into a built-in's prototype, such as
+/// `Array.prototype.last = ...` or `Object.prototype["key"] = ...`.
+pub struct PrototypePollutionRule;
+
+const BUILT_INS: &[&str] = &[
+    "Array", "Boolean", "Date", "Error", "Function", "Map", "Number", "Object", "Promise", "RegExp", "Set", "String",
+    "Symbol", "WeakMap", "WeakSet",
+];
+
+impl Rule for PrototypePollutionRule {
+    fn id(&self) -> &'static str {
+        "prototype-pollution"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Changing a built-in prototype alters every object of that type across the program, including in third-party code."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_security_rules.unwrap_or(true)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if !matches!(node.kind(), "assignment_expression" | "augmented_assignment_expression") {
+                return;
+            }
+            let Some(built_in) = node
+                .child_by_field_name("left")
+                .and_then(|target| modified_prototype(&target, ctx.source))
+            else {
+                return;
+            };
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::High,
+                    format!("Assignment modifies {}.prototype", built_in),
+                    node_location(&node),
+                )
+                .with_suggestion("Use a standalone helper function instead of extending the built-in"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// The built-in whose prototype `target` writes into: a property access
+/// somewhere below `<BuiltIn>.prototype` in the member chain.
+fn modified_prototype<'a>(target: &Node, source: &'a str) -> Option<&'a str> {
+    let mut object = match target.kind() {
+        "member_expression" | "subscript_expression" => target.child_by_field_name("object")?,
+        _ => return None,
+    };
+    loop {
+        if object.kind() == "member_expression" {
+            let property = object.child_by_field_name("property")?;
+            let owner = object.child_by_field_name("object")?;
+            if node_text(&property, source) == "prototype" && owner.kind() == "identifier" {
+                let name = node_text(&owner, source);
+                if BUILT_INS.contains(&name) {
+                    return Some(name);
+                }
+            }
+            object = owner;
+        } else if object.kind() == "subscript_expression" {
+            object = object.child_by_field_name("object")?;
+        } else {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_built_in_prototype_assignment_is_flagged() {
+        let source = "Array.prototype.last = function () {\n  return this[this.length - 1];\n};\nObject.prototype['isEmpty'] = () => true;\n";
+
+        let findings = check_source(&PrototypePollutionRule, "polyfills.js", source);
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "prototype-pollution");
+        assert!(matches!(findings[0].severity, Severity::High));
+        assert_eq!(findings[0].message, "Assignment modifies Array.prototype");
+        assert_eq!(findings[1].location.line, 4);
+    }
+
+    #[test]
+    fn test_own_classes_and_reads_are_ignored() {
+        let source = "Widget.prototype.render = function () {};\nconst slice = Array.prototype.slice;\nitems.prototype = null;\n";
+
+        assert!(check_source(&PrototypePollutionRule, "widget.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_disabled_with_security_rules() {
+        let config = RuleConfig {
+            enable_security_rules: Some(false),
+            ..Default::default()
+        };
+
+        assert!(!PrototypePollutionRule.is_enabled_by_config(&config));
+    }
+}
@@ -0,0 +1,154 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `reduce` callbacks that spread their accumulator into a new array
+/// or object, e.g. `arr.reduce((acc, x) => [...acc, x], [])`. Copying the
+/// accumulator on every step makes the reduction quadratic. Opt-in via
+/// `RuleConfig.detect_quadratic_spread`.
+pub struct QuadraticSpreadRule;
+
+const CALLBACK_KINDS: &[&str] = &["arrow_function", "function_expression", "function"];
+
+impl Rule for QuadraticSpreadRule {
+    fn id(&self) -> &'static str {
+        "quadratic-spread"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Spreading an accumulator on every iteration copies it each time, turning a linear loop into quadratic work."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_quadratic_spread.unwrap_or(false)
+    }
+
+    fn check<'a>(&self, ctx: &RuleContext<'a>) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node<'a>| {
+            let Some(callback) = reduce_callback(&node, ctx.source) else {
+                return;
+            };
+            let Some(accumulator) = first_parameter(&callback, ctx.source) else {
+                return;
+            };
+            let Some(body) = callback.child_by_field_name("body") else {
+                return;
+            };
+
+            let mut spreads = Vec::new();
+            walk_tree(body, ctx.source, &mut |inner: Node<'a>| {
+                let spreads_accumulator = inner.kind() == "spread_element"
+                    && inner
+                        .named_child(0)
+                        .is_some_and(|argument| node_text(&argument, ctx.source) == accumulator);
+                if spreads_accumulator {
+                    spreads.push(inner);
+                }
+            });
+
+            for spread in spreads {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!(
+                            "Spreading accumulator '{}' in a reduce callback copies it on every iteration",
+                            accumulator
+                        ),
+                        node_location(&spread),
+                    )
+                    .with_suggestion("Mutate the accumulator (push / property assignment) and return it instead"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// The callback of a `<expr>.reduce(callback, ...)` call.
+fn reduce_callback<'a>(node: &Node<'a>, source: &str) -> Option<Node<'a>> {
+    if node.kind() != "call_expression" {
+        return None;
+    }
+    let function = node.child_by_field_name("function")?;
+    if function.kind() != "member_expression" {
+        return None;
+    }
+    let property = function.child_by_field_name("property")?;
+    if node_text(&property, source) != "reduce" {
+        return None;
+    }
+
+    let callback = node.child_by_field_name("arguments")?.named_child(0)?;
+    CALLBACK_KINDS.contains(&callback.kind()).then_some(callback)
+}
+
+/// Name of the callback's first parameter, when it is a plain identifier.
+fn first_parameter<'a>(callback: &Node, source: &'a str) -> Option<&'a str> {
+    if let Some(parameter) = callback.child_by_field_name("parameter") {
+        return Some(node_text(&parameter, source));
+    }
+
+    let first = callback.child_by_field_name("parameters")?.named_child(0)?;
+    let identifier = match first.kind() {
+        "identifier" => first,
+        // TypeScript wraps parameters to carry type annotations
+        "required_parameter" => first.child_by_field_name("pattern")?,
+        _ => return None,
+    };
+    (identifier.kind() == "identifier").then(|| node_text(&identifier, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source_with_config;
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_quadratic_spread: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_spread_accumulator_in_reduce_is_flagged() {
+        let source = r#"
+            const all = items.reduce((acc, x) => [...acc, x], []);
+            const byId = items.reduce(function (map, item) {
+                return { ...map, [item.id]: item };
+            }, {});
+        "#;
+
+        let findings = check_source_with_config(&QuadraticSpreadRule, "app.js", source, &enabled());
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "quadratic-spread");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("'acc'"));
+        assert_eq!(findings[1].location.line, 4);
+    }
+
+    #[test]
+    fn test_typed_accumulator_is_flagged() {
+        let source = "const ids = rows.reduce((acc: string[], row: Row) => [...acc, row.id], []);\n";
+
+        assert_eq!(check_source_with_config(&QuadraticSpreadRule, "app.ts", source, &enabled()).len(), 1);
+    }
+
+    #[test]
+    fn test_other_spreads_and_opt_in() {
+        let source = r#"
+            const merged = items.reduce((acc, x) => { acc.push(...x.children); return acc; }, []);
+            const copies = items.map((acc) => [...acc]);
+        "#;
+
+        assert!(check_source_with_config(&QuadraticSpreadRule, "app.js", source, &enabled()).is_empty());
+        assert!(!QuadraticSpreadRule.is_enabled_by_config(&RuleConfig::default()));
+    }
+}
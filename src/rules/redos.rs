@@ -0,0 +1,235 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags regex literals with the classic catastrophic-backtracking shapes:
+/// an unbounded quantifier applied to a group that already repeats, as in
+/// `(a+)+`, or to an alternation whose branches overlap, as in `(a|ab)*`.
+/// This is a heuristic over the pattern source, not a full regex analysis.
+pub struct PotentialRedosRule;
+
+impl Rule for PotentialRedosRule {
+    fn id(&self) -> &'static str {
+        "potential-redos"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Regular expressions with nested or overlapping quantifiers can backtrack exponentially, letting crafted input hang the process."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_security_rules.unwrap_or(true)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "regex" {
+                return;
+            }
+            let Some(pattern) = node.child_by_field_name("pattern") else {
+                return;
+            };
+
+            if let Some(reason) = backtracking_hazard(node_text(&pattern, ctx.source)) {
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::High,
+                        format!("Regex may backtrack catastrophically: {}", reason),
+                        node_location(&node),
+                    )
+                    .with_suggestion("Remove the nested repetition or make the alternatives mutually exclusive"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// State for one open `(...)` group while scanning a pattern.
+struct Group {
+    /// Index of the opening `(`.
+    start: usize,
+    /// Whether anything inside the group is repeated without bound.
+    repeats: bool,
+    /// Source of each `|`-separated branch seen so far.
+    branches: Vec<String>,
+    /// Whether a nested group has overlapping branches.
+    ambiguous: bool,
+}
+
+impl Group {
+    fn open(start: usize) -> Self {
+        Self {
+            start,
+            repeats: false,
+            branches: vec![String::new()],
+            ambiguous: false,
+        }
+    }
+}
+
+/// Describes the first hazardous construct in `pattern`, if any.
+fn backtracking_hazard(pattern: &str) -> Option<&'static str> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut stack = vec![Group::open(0)];
+    let mut i = 0;
+
+    while i < chars.len() {
+        let start = i;
+        match chars[i] {
+            '\\' => i += 2,
+            '[' => {
+                i += 1;
+                while i < chars.len() && chars[i] != ']' {
+                    i += if chars[i] == '\\' { 2 } else { 1 };
+                }
+                i += 1;
+            }
+            '(' => {
+                stack.push(Group::open(i));
+                i += 1 + group_prefix_len(&chars[i + 1..]);
+                continue;
+            }
+            ')' if stack.len() > 1 => {
+                let group = stack.pop().unwrap();
+                i += 1;
+                let (unbounded, quantifier_len) = quantifier(&chars[i..]);
+                i += quantifier_len;
+
+                if unbounded && group.repeats {
+                    return Some("nested quantifier");
+                }
+                let ambiguous = group.ambiguous || branches_overlap(&group.branches);
+                if unbounded && ambiguous {
+                    return Some("overlapping alternation under a quantifier");
+                }
+
+                let parent = stack.last_mut().unwrap();
+                parent.repeats |= group.repeats || unbounded;
+                parent.ambiguous |= ambiguous;
+                parent.branches.last_mut().unwrap().extend(&chars[group.start..i]);
+                continue;
+            }
+            '|' => {
+                stack.last_mut().unwrap().branches.push(String::new());
+                i += 1;
+                continue;
+            }
+            _ => i += 1,
+        }
+
+        // An atom, optionally followed by a quantifier
+        i = i.min(chars.len());
+        let (unbounded, quantifier_len) = quantifier(&chars[i..]);
+        i += quantifier_len;
+        let group = stack.last_mut().unwrap();
+        group.repeats |= unbounded;
+        group.branches.last_mut().unwrap().extend(&chars[start..i]);
+    }
+
+    None
+}
+
+/// Length of a `?:`, `?=`, `?!`, `?<=`, `?<!` or `?<name>` group prefix at
+/// the start of `rest`, so it isn't mistaken for part of the first branch.
+fn group_prefix_len(rest: &[char]) -> usize {
+    match rest {
+        ['?', ':' | '=' | '!', ..] => 2,
+        ['?', '<', '=' | '!', ..] => 3,
+        ['?', '<', ..] => rest.iter().position(|&c| c == '>').map_or(0, |end| end + 1),
+        _ => 0,
+    }
+}
+
+/// Whether `rest` starts with an unbounded quantifier (`*`, `+`, `{n,}`),
+/// and how many characters any quantifier there spans, including a lazy `?`.
+fn quantifier(rest: &[char]) -> (bool, usize) {
+    let (unbounded, len) = match rest.first() {
+        Some('*') | Some('+') => (true, 1),
+        Some('?') => (false, 1),
+        Some('{') => match rest.iter().position(|&c| c == '}') {
+            Some(end) => {
+                let inner: String = rest[1..end].iter().collect();
+                let valid = !inner.is_empty() && inner.chars().all(|c| c.is_ascii_digit() || c == ',');
+                if !valid {
+                    return (false, 0);
+                }
+                (inner.ends_with(','), end + 1)
+            }
+            None => return (false, 0),
+        },
+        _ => return (false, 0),
+    };
+
+    let lazy = rest.get(len) == Some(&'?');
+    (unbounded, len + usize::from(lazy))
+}
+
+/// Whether two branches are identical or one is a prefix of the other, so
+/// the engine can match the same input more than one way.
+fn branches_overlap(branches: &[String]) -> bool {
+    branches.iter().enumerate().any(|(i, a)| {
+        branches[i + 1..]
+            .iter()
+            .any(|b| !a.is_empty() && !b.is_empty() && (a.starts_with(b.as_str()) || b.starts_with(a.as_str())))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_nested_quantifier_is_flagged() {
+        let source = "const email = /^([a-zA-Z0-9]+)*@example\\.com$/;\nconst ok = /^[a-z]+$/;\n";
+
+        let findings = check_source(&PotentialRedosRule, "validate.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "potential-redos");
+        assert!(matches!(findings[0].severity, Severity::High));
+        assert!(findings[0].message.contains("nested quantifier"));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (1, 15));
+    }
+
+    #[test]
+    fn test_overlapping_alternation_is_flagged() {
+        let findings = check_source(&PotentialRedosRule, "parse.ts", "const re = /(a|ab)+c/;\n");
+
+        assert_eq!(findings.len(), 1);
+        assert!(findings[0].message.contains("alternation"));
+    }
+
+    #[test]
+    fn test_safe_patterns_are_ignored() {
+        let source = r"
+            const a = /(ab)+/;
+            const b = /(a+)?b/;
+            const c = /(foo|bar)*/;
+            const d = /([+*])+x/;
+            const e = /(a{2,3})+/;
+            const f = /\(a+\)+/;
+        ";
+
+        assert!(check_source(&PotentialRedosRule, "ok.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_hazards_inside_outer_groups() {
+        assert!(backtracking_hazard("^(x(a+)+)$").is_some());
+        assert!(backtracking_hazard("((a|a))*").is_some());
+        assert!(backtracking_hazard("(?:\\d+){2,}").is_some());
+        assert!(backtracking_hazard("(?:\\d+){2}").is_none());
+    }
+}
@@ -0,0 +1,128 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags comparisons against boolean literals (`done === true`) and double
+/// negation (`!!flag`) used as a condition, where the value is coerced to a
+/// boolean anyway.
+pub struct RedundantBooleanRule;
+
+const EQUALITY_OPERATORS: &[&str] = &["==", "===", "!=", "!=="];
+
+const CONDITION_PARENTS: &[&str] = &["if_statement", "while_statement", "do_statement", "ternary_expression"];
+
+impl Rule for RedundantBooleanRule {
+    fn id(&self) -> &'static str {
+        "redundant-boolean"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Comparing a condition against `true`/`false` or double-negating it adds noise, since the condition is coerced to a boolean anyway."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let suggestion = match node.kind() {
+                "binary_expression" if compares_to_boolean_literal(&node) => {
+                    "Use the value directly, or negate it instead of comparing to false"
+                }
+                "unary_expression" if is_double_negation(&node) && is_condition(&node) => {
+                    "Drop the '!!'; conditions already coerce to boolean"
+                }
+                _ => return,
+            };
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Redundant boolean expression '{}'", node_text(&node, ctx.source)),
+                    node_location(&node),
+                )
+                .with_suggestion(suggestion),
+            );
+        });
+
+        findings
+    }
+}
+
+fn compares_to_boolean_literal(node: &Node) -> bool {
+    let is_equality = node
+        .child_by_field_name("operator")
+        .is_some_and(|operator| EQUALITY_OPERATORS.contains(&operator.kind()));
+
+    is_equality
+        && [node.child_by_field_name("left"), node.child_by_field_name("right")]
+            .iter()
+            .flatten()
+            .any(|operand| matches!(operand.kind(), "true" | "false"))
+}
+
+fn is_negation(node: &Node) -> bool {
+    node.kind() == "unary_expression"
+        && node
+            .child_by_field_name("operator")
+            .is_some_and(|operator| operator.kind() == "!")
+}
+
+/// `!!x`, reported once for the outer negation.
+fn is_double_negation(node: &Node) -> bool {
+    is_negation(node)
+        && node
+            .child_by_field_name("argument")
+            .is_some_and(|argument| is_negation(&argument))
+        && !node.parent().is_some_and(|parent| is_negation(&parent))
+}
+
+/// Whether `node` is the condition of an `if`, loop or ternary.
+fn is_condition(node: &Node) -> bool {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        if parent.kind() != "parenthesized_expression" {
+            return CONDITION_PARENTS.contains(&parent.kind())
+                && parent
+                    .child_by_field_name("condition")
+                    .is_some_and(|condition| condition.id() == current.id());
+        }
+        current = parent;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_comparison_with_true_is_flagged() {
+        let findings = check_source(&RedundantBooleanRule, "app.js", "if (done === true) { finish(); }\n");
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "redundant-boolean");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("done === true"));
+    }
+
+    #[test]
+    fn test_double_negation_in_condition_is_flagged() {
+        let source = "if (!!flag) { run(); }\nwhile (!!(queue.length)) { queue.pop(); }\nconst label = !!ready ? 'yes' : 'no';\n";
+
+        let findings = check_source(&RedundantBooleanRule, "app.js", source);
+
+        assert_eq!(findings.len(), 3);
+        assert!(findings[0].message.contains("!!flag"));
+    }
+
+    #[test]
+    fn test_value_contexts_are_ignored() {
+        let source = "const enabled = !!options.enabled;\nfunction f(value) { return !value; }\nif (count === 0) {}\nif (!ok) {}\n";
+
+        assert!(check_source(&RedundantBooleanRule, "app.js", source).is_empty());
+    }
+}
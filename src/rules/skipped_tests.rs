@@ -0,0 +1,131 @@
+use tree_sitter::Node;
+
+use super::{is_test_file, node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags tests and suites that were disabled with `.skip` or an `x` prefix
+/// and are easy to forget about.
+pub struct SkippedTestRule;
+
+const SKIPPED_CALLEES: &[&str] = &[
+    "describe.skip",
+    "it.skip",
+    "test.skip",
+    "xdescribe",
+    "xit",
+    "xtest",
+];
+
+impl Rule for SkippedTestRule {
+    fn id(&self) -> &'static str {
+        "skipped-test"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Skipped tests silently shrink what the suite checks and are easy to forget, letting regressions through."
+    }
+
+    fn is_enabled(&self, ctx: &RuleContext) -> bool {
+        is_test_file(ctx.file_name)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some(callee) = node.child_by_field_name("function") else {
+                return;
+            };
+            let callee = node_text(&callee, ctx.source);
+            if !SKIPPED_CALLEES.contains(&callee) {
+                return;
+            }
+
+            let description = test_description(&node, ctx.source).unwrap_or("<unnamed>");
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Skipped test '{}' ({})", description, callee),
+                    node_location(&node),
+                )
+                .with_suggestion("Re-enable the test or delete it if it is no longer relevant"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// The string literal passed as the first argument, without quotes.
+fn test_description<'a>(call: &Node, source: &'a str) -> Option<&'a str> {
+    let arguments = call.child_by_field_name("arguments")?;
+    let first = arguments.named_child(0)?;
+
+    match first.kind() {
+        "string" | "template_string" => {
+            let text = node_text(&first, source);
+            Some(text.get(1..text.len().saturating_sub(1)).unwrap_or(text))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_it_skip_is_flagged_with_description() {
+        let source = r#"
+            describe('cart', () => {
+                it.skip('applies discounts', () => {
+                    expect(total()).toBe(90);
+                });
+                it('adds items', () => {});
+            });
+        "#;
+
+        let findings = check_source(&SkippedTestRule, "src/cart.test.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "skipped-test");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("'applies discounts'"));
+        assert_eq!(findings[0].location.line, 3);
+    }
+
+    #[test]
+    fn test_x_prefixed_and_skip_variants_are_flagged() {
+        let source = r#"
+            xdescribe("legacy", () => {});
+            xit(`pending`, () => {});
+            test.skip("later", () => {});
+            describe.skip("suite", () => {});
+        "#;
+
+        assert_eq!(check_source(&SkippedTestRule, "tests/legacy.ts", source).len(), 4);
+    }
+
+    #[test]
+    fn test_non_test_files_are_ignored() {
+        let source = "it.skip('not a test file', () => {});";
+
+        assert!(check_source(&SkippedTestRule, "src/cart.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_is_test_file() {
+        assert!(is_test_file("src/cart.test.ts"));
+        assert!(is_test_file("cart.spec.js"));
+        assert!(is_test_file("src/__tests__/cart.js"));
+        assert!(is_test_file("tests/cart.js"));
+        assert!(!is_test_file("src/testing.js"));
+        assert!(!is_test_file("src/latest/cart.js"));
+    }
+}
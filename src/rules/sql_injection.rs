@@ -0,0 +1,139 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags template literals that build a SQL statement around `${...}`
+/// interpolations, such as `` `SELECT * FROM users WHERE id = ${id}` ``.
+/// Tagged templates (`` sql`...` ``) are left alone, since the tag usually
+/// turns interpolations into bound parameters.
+pub struct SqlInjectionRule;
+
+/// A statement keyword and the clause that must follow it.
+const STATEMENTS: &[(&str, &str)] = &[("select", "from"), ("insert", "into"), ("update", "set"), ("delete", "from")];
+
+impl Rule for SqlInjectionRule {
+    fn id(&self) -> &'static str {
+        "sql-injection-risk"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Values interpolated into SQL text can change the query itself when they contain quotes or SQL."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_security_rules.unwrap_or(true)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "template_string" || is_tagged(&node) || !looks_like_sql(&node, ctx.source) {
+                return;
+            }
+            let mut cursor = node.walk();
+            let interpolations = node
+                .named_children(&mut cursor)
+                .filter(|child| child.kind() == "template_substitution")
+                .count();
+            if interpolations == 0 {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::High,
+                    format!("SQL query built from a template literal with {} interpolation(s)", interpolations),
+                    node_location(&node),
+                )
+                .with_suggestion("Use a parameterized query and pass the values separately"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn is_tagged(template: &Node) -> bool {
+    template.parent().is_some_and(|parent| {
+        parent.kind() == "call_expression" && parent.child_by_field_name("arguments") == Some(*template)
+    })
+}
+
+/// Whether the literal text around the interpolations holds a statement
+/// keyword followed by its clause, or a `WHERE`. Keywords must be
+/// uppercase unless one opens the literal, so prose such as
+/// `` `Please select ${item} from the list` `` isn't taken for SQL.
+fn looks_like_sql(template: &Node, source: &str) -> bool {
+    let mut cursor = template.walk();
+    let fragments: Vec<&str> = template
+        .named_children(&mut cursor)
+        .filter(|child| child.kind() == "string_fragment")
+        .map(|fragment| node_text(&fragment, source))
+        .collect();
+    let words: Vec<&str> = fragments
+        .iter()
+        .flat_map(|fragment| fragment.split(|c: char| !c.is_ascii_alphanumeric() && c != '_'))
+        .filter(|word| !word.is_empty())
+        .collect();
+    let is_keyword = |index: usize, keyword: &str| {
+        words[index] == keyword.to_ascii_uppercase() || (index == 0 && words[index].eq_ignore_ascii_case(keyword))
+    };
+
+    (0..words.len()).any(|index| is_keyword(index, "where"))
+        || STATEMENTS.iter().any(|(keyword, clause)| {
+            (0..words.len())
+                .find(|index| is_keyword(*index, keyword))
+                .is_some_and(|start| words[start + 1..].iter().any(|word| word.eq_ignore_ascii_case(clause)))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    #[test]
+    fn test_interpolated_select_is_flagged() {
+        let source = "const rows = await db.query(`SELECT * FROM users WHERE id = ${id}`);\n";
+
+        let findings = check_source(&SqlInjectionRule, "users.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "sql-injection-risk");
+        assert!(matches!(findings[0].severity, Severity::High));
+        assert_eq!(findings[0].message, "SQL query built from a template literal with 1 interpolation(s)");
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_constant_tagged_and_prose_templates_are_ignored() {
+        let source = r#"
+const all = `SELECT * FROM users`;
+const tagged = sql`DELETE FROM users WHERE id = ${id}`;
+const label = `Please select ${item} from the list below`;
+const title = `Update ${name}`;
+"#;
+
+        assert!(check_source(&SqlInjectionRule, "users.js", source).is_empty());
+    }
+
+    #[test]
+    fn test_security_rules_toggle() {
+        let config = RuleConfig {
+            enable_security_rules: Some(false),
+            ..Default::default()
+        };
+
+        let source = "db.query(`update users set name = '${name}'`);\n";
+        assert_eq!(check_source(&SqlInjectionRule, "users.js", source).len(), 1);
+        assert!(check_source_with_config(&SqlInjectionRule, "users.js", source, &config).is_empty());
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `+=` string building inside loops, which copies the accumulated
+/// string on every iteration. Whether the target is a string is guessed from
+/// its initializer or the appended value, so the rule is opt-in.
+pub struct StringConcatInLoopRule;
+
+const LOOP_KINDS: &[&str] = &["for_statement", "for_in_statement", "while_statement", "do_statement"];
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+const STRING_KINDS: &[&str] = &["string", "template_string"];
+
+impl Rule for StringConcatInLoopRule {
+    fn id(&self) -> &'static str {
+        "string-concat-in-loop"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Building a string with `+=` in a loop copies the accumulated string on every iteration; collecting parts and joining avoids that."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_string_concat_in_loops.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let string_variables = string_initialized_variables(root, ctx.source);
+        let mut findings = Vec::new();
+
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if node.kind() != "augmented_assignment_expression" {
+                return;
+            }
+            let is_append = node
+                .child_by_field_name("operator")
+                .is_some_and(|operator| operator.kind() == "+=");
+            let (Some(left), Some(right)) = (node.child_by_field_name("left"), node.child_by_field_name("right")) else {
+                return;
+            };
+            if !is_append || left.kind() != "identifier" || !inside_loop(&node) {
+                return;
+            }
+
+            let name = node_text(&left, ctx.source);
+            if !string_variables.contains(name) && !is_string_valued(&right) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("String '{}' is built with += inside a loop", name),
+                    node_location(&node),
+                )
+                .with_suggestion("Collect the parts in an array and join them once after the loop"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Names declared with a string or template literal initializer.
+fn string_initialized_variables(root: Node, source: &str) -> HashSet<String> {
+    let mut names = HashSet::new();
+
+    walk_tree(root, source, &mut |node: Node| {
+        if node.kind() != "variable_declarator" {
+            return;
+        }
+        let (Some(name), Some(value)) = (node.child_by_field_name("name"), node.child_by_field_name("value")) else {
+            return;
+        };
+        if name.kind() == "identifier" && STRING_KINDS.contains(&value.kind()) {
+            names.insert(node_text(&name, source).to_string());
+        }
+    });
+
+    names
+}
+
+/// A string literal, or a `+` chain containing one.
+fn is_string_valued(node: &Node) -> bool {
+    match node.kind() {
+        kind if STRING_KINDS.contains(&kind) => true,
+        "binary_expression" => {
+            let is_plus = node
+                .child_by_field_name("operator")
+                .is_some_and(|operator| operator.kind() == "+");
+            is_plus
+                && [node.child_by_field_name("left"), node.child_by_field_name("right")]
+                    .iter()
+                    .flatten()
+                    .any(is_string_valued)
+        }
+        "parenthesized_expression" => node.named_child(0).is_some_and(|inner| is_string_valued(&inner)),
+        _ => false,
+    }
+}
+
+/// Whether `node` runs inside a loop body of its own enclosing function.
+fn inside_loop(node: &Node) -> bool {
+    let mut current = node.parent();
+    while let Some(ancestor) = current {
+        if FUNCTION_KINDS.contains(&ancestor.kind()) {
+            return false;
+        }
+        if LOOP_KINDS.contains(&ancestor.kind()) {
+            return true;
+        }
+        current = ancestor.parent();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source_with_config;
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_string_concat_in_loops: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_concatenation_in_for_loop_is_flagged() {
+        let source = r#"
+            function render(items) {
+                let html = '';
+                for (let i = 0; i < items.length; i++) {
+                    html += items[i].name;
+                }
+                return html;
+            }
+        "#;
+
+        let findings = check_source_with_config(&StringConcatInLoopRule, "render.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "string-concat-in-loop");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert!(findings[0].message.contains("'html'"));
+        assert_eq!(findings[0].location.line, 5);
+    }
+
+    #[test]
+    fn test_string_valued_rhs_is_flagged_without_initializer() {
+        let source = "let out;\nfor (const row of rows) { out += `<li>${row}</li>`; }\nwhile (more()) { out += '-' + next(); }\n";
+
+        let findings = check_source_with_config(&StringConcatInLoopRule, "list.ts", source, &enabled());
+
+        assert_eq!(findings.len(), 2);
+    }
+
+    #[test]
+    fn test_numeric_sums_and_code_outside_loops_are_ignored() {
+        let source = r#"
+            let total = 0;
+            let label = 'Total: ';
+            for (const n of numbers) { total += n; }
+            label += total;
+            for (const n of numbers) { items.forEach((item) => { label += item; }); }
+        "#;
+
+        assert!(check_source_with_config(&StringConcatInLoopRule, "sum.js", source, &enabled()).is_empty());
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        let rule = StringConcatInLoopRule;
+
+        assert!(!rule.is_enabled_by_config(&RuleConfig::default()));
+        assert!(rule.is_enabled_by_config(&enabled()));
+    }
+}
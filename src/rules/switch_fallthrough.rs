@@ -0,0 +1,201 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags non-empty switch cases that run into the next case without a
+/// `break`, `return`, `throw` or `continue`. A `// falls through` comment
+/// after the case body marks the fallthrough as intentional.
+pub struct SwitchFallthroughRule;
+
+const CASE_KINDS: &[&str] = &["switch_case", "switch_default"];
+
+const EXIT_KINDS: &[&str] = &["break_statement", "return_statement", "throw_statement", "continue_statement"];
+
+impl Rule for SwitchFallthroughRule {
+    fn id(&self) -> &'static str {
+        "switch-fallthrough"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A case without `break` runs into the next one, which is usually an accident rather than intent."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "switch_body" {
+                return;
+            }
+
+            let mut cursor = node.walk();
+            let members: Vec<Node> = node.named_children(&mut cursor).collect();
+
+            for (index, case) in members.iter().enumerate() {
+                if !CASE_KINDS.contains(&case.kind()) {
+                    continue;
+                }
+                // Comments between this case and the next one belong to it
+                let following = &members[index + 1..];
+                let next_case = following.iter().position(|member| CASE_KINDS.contains(&member.kind()));
+                let Some(next_case) = next_case else {
+                    continue;
+                };
+
+                let statements = case_statements(case);
+                let Some(last) = statements.last() else {
+                    // Empty cases deliberately share the next case's body
+                    continue;
+                };
+                if exits(last) {
+                    continue;
+                }
+
+                let marked = case_comments(case)
+                    .iter()
+                    .chain(&following[..next_case])
+                    .any(|comment| is_fallthrough_comment(node_text(comment, ctx.source)));
+                if marked {
+                    continue;
+                }
+
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Medium,
+                        "Case falls through to the next case".to_string(),
+                        node_location(case),
+                    )
+                    .with_suggestion("End the case with break, or add a '// falls through' comment if intended"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// The case's statements, without its `case` value or comments.
+fn case_statements<'a>(case: &Node<'a>) -> Vec<Node<'a>> {
+    let value = case.child_by_field_name("value").map(|value| value.id());
+    let mut cursor = case.walk();
+    case.named_children(&mut cursor)
+        .filter(|child| Some(child.id()) != value && child.kind() != "comment")
+        .collect()
+}
+
+fn case_comments<'a>(case: &Node<'a>) -> Vec<Node<'a>> {
+    let mut cursor = case.walk();
+    case.named_children(&mut cursor)
+        .filter(|child| child.kind() == "comment")
+        .collect()
+}
+
+/// Whether control can't continue past `statement`.
+fn exits(statement: &Node) -> bool {
+    match statement.kind() {
+        kind if EXIT_KINDS.contains(&kind) => true,
+        "statement_block" => {
+            let mut cursor = statement.walk();
+            let last = statement
+                .named_children(&mut cursor)
+                .filter(|child| child.kind() != "comment")
+                .last();
+            last.is_some_and(|last| exits(&last))
+        }
+        "if_statement" => {
+            let consequence = statement.child_by_field_name("consequence");
+            // The alternative is an `else_clause` wrapping the statement
+            let alternative = statement
+                .child_by_field_name("alternative")
+                .and_then(|clause| clause.named_child(0));
+            consequence.is_some_and(|c| exits(&c)) && alternative.is_some_and(|a| exits(&a))
+        }
+        _ => false,
+    }
+}
+
+fn is_fallthrough_comment(comment: &str) -> bool {
+    let normalized: String = comment
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect();
+    normalized.contains("fallthrough") || normalized.contains("fallsthrough")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_unintended_fallthrough_is_flagged() {
+        let source = r#"
+            switch (action) {
+                case 'save':
+                    save();
+                case 'close':
+                    close();
+                    break;
+                default:
+                    log();
+            }
+        "#;
+
+        let findings = check_source(&SwitchFallthroughRule, "app.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "switch-fallthrough");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].location.line, 3);
+    }
+
+    #[test]
+    fn test_commented_fallthrough_is_allowed() {
+        let source = r#"
+            switch (level) {
+                case 2:
+                    warn();
+                    // falls through
+                case 1:
+                    notice();
+                    /* fallthrough */
+                case 0:
+                    debug();
+            }
+        "#;
+
+        assert!(check_source(&SwitchFallthroughRule, "app.ts", source).is_empty());
+    }
+
+    #[test]
+    fn test_exiting_and_empty_cases_are_not_flagged() {
+        let source = r#"
+            function f(x) {
+                for (;;) {
+                    switch (x) {
+                        case 1:
+                        case 2: {
+                            return a();
+                        }
+                        case 3:
+                            if (y) { throw err; } else { continue; }
+                        case 4:
+                            if (y) { break; }
+                        case 5:
+                            b();
+                    }
+                }
+            }
+        "#;
+
+        let findings = check_source(&SwitchFallthroughRule, "app.js", source);
+
+        // Only case 4 can continue into case 5
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.line, 11);
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags `xhr.open(method, url, false)` where `xhr` holds a
+/// `new XMLHttpRequest()`. A literal `false` for the async argument makes
+/// the request synchronous, blocking the main thread until it completes.
+pub struct SyncXhrRule;
+
+impl Rule for SyncXhrRule {
+    fn id(&self) -> &'static str {
+        "sync-xhr"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Synchronous XMLHttpRequest freezes the page until the response arrives and is deprecated in browsers."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let root = ctx.parse_result.tree.root_node();
+        let requests = xhr_bindings(&root, ctx.source);
+        if requests.is_empty() {
+            return Vec::new();
+        }
+
+        let mut findings = Vec::new();
+        walk_tree(root, ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" || !is_sync_open(&node, &requests, ctx.source) {
+                return;
+            }
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    "Synchronous XMLHttpRequest blocks the main thread".to_string(),
+                    node_location(&node),
+                )
+                .with_suggestion("Pass true for the async argument, or use fetch()"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Names (`xhr`, `this.request`, ...) assigned a `new XMLHttpRequest()`.
+fn xhr_bindings<'a>(root: &Node, source: &'a str) -> HashSet<&'a str> {
+    let mut names = HashSet::new();
+
+    walk_tree(*root, source, &mut |node: Node| {
+        let (target, value) = match node.kind() {
+            "variable_declarator" => (node.child_by_field_name("name"), node.child_by_field_name("value")),
+            "assignment_expression" => (node.child_by_field_name("left"), node.child_by_field_name("right")),
+            _ => return,
+        };
+        let constructs_xhr = value.is_some_and(|value| {
+            value.kind() == "new_expression"
+                && value
+                    .child_by_field_name("constructor")
+                    .is_some_and(|constructor| node_text(&constructor, source) == "XMLHttpRequest")
+        });
+        if let Some(target) = target.filter(|_| constructs_xhr) {
+            names.insert(node_text(&target, source));
+        }
+    });
+
+    names
+}
+
+fn is_sync_open(call: &Node, requests: &HashSet<&str>, source: &str) -> bool {
+    let Some(callee) = call.child_by_field_name("function").filter(|callee| callee.kind() == "member_expression") else {
+        return false;
+    };
+    let is_open = callee
+        .child_by_field_name("property")
+        .is_some_and(|property| node_text(&property, source) == "open");
+    let on_request = callee
+        .child_by_field_name("object")
+        .is_some_and(|object| requests.contains(node_text(&object, source)));
+
+    let is_sync = call
+        .child_by_field_name("arguments")
+        .and_then(|arguments| arguments.named_child(2))
+        .is_some_and(|flag| flag.kind() == "false");
+
+    is_open && on_request && is_sync
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_synchronous_open_is_flagged() {
+        let source = "const xhr = new XMLHttpRequest();\nxhr.open('GET', '/api/user', false);\nxhr.send();\n";
+
+        let findings = check_source(&SyncXhrRule, "legacy.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "sync-xhr");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (2, 1));
+    }
+
+    #[test]
+    fn test_async_and_unrelated_open_calls_are_ignored() {
+        let source = r#"
+            class Client {
+                load() {
+                    this.request = new XMLHttpRequest();
+                    this.request.open("GET", "/a", true);
+                    this.request.open("GET", "/b");
+                    dialog.open("title", "body", false);
+                }
+            }
+        "#;
+
+        assert!(check_source(&SyncXhrRule, "client.js", source).is_empty());
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, RuleConfig, Severity};
+
+/// Flags groups of classes in one file that reference each other in a
+/// cycle, through field and parameter types or `new` expressions. Each
+/// strongly connected component of the reference graph is reported once,
+/// at its first class. Opt-in via `RuleConfig.detect_tight_coupling`.
+pub struct TightCouplingRule;
+
+impl Rule for TightCouplingRule {
+    fn id(&self) -> &'static str {
+        "tight-coupling"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Classes that depend on each other in a cycle can't be understood, tested or reused in isolation."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_tight_coupling.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let classes = &ctx.parse_result.classes;
+        let index: HashMap<&str, usize> = classes
+            .iter()
+            .enumerate()
+            .map(|(i, class)| (class.name.as_str(), i))
+            .collect();
+        let edges: Vec<Vec<usize>> = classes
+            .iter()
+            .map(|class| {
+                class
+                    .references
+                    .iter()
+                    .filter_map(|name| index.get(name.as_str()).copied())
+                    .collect()
+            })
+            .collect();
+
+        strongly_connected(&edges)
+            .into_iter()
+            .filter(|component| component.len() > 1)
+            .map(|mut component| {
+                component.sort_by_key(|&i| classes[i].line);
+                let names: Vec<&str> = component.iter().map(|&i| classes[i].name.as_str()).collect();
+
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Classes {} reference each other in a cycle", names.join(", ")),
+                    Location {
+                        line: classes[component[0]].line,
+                        column: 1,
+                        end_line: None,
+                        end_column: None,
+                    },
+                )
+                .with_suggestion("Break the cycle with an interface or by moving shared state into a separate class")
+            })
+            .collect()
+    }
+}
+
+/// Tarjan's algorithm over an adjacency list; returns every strongly
+/// connected component, including single nodes.
+fn strongly_connected(edges: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    struct State<'a> {
+        edges: &'a [Vec<usize>],
+        next_index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        stack: Vec<usize>,
+        on_stack: Vec<bool>,
+        components: Vec<Vec<usize>>,
+    }
+
+    fn connect(state: &mut State, node: usize) {
+        state.indices[node] = Some(state.next_index);
+        state.low_links[node] = state.next_index;
+        state.next_index += 1;
+        state.stack.push(node);
+        state.on_stack[node] = true;
+
+        for &next in &state.edges[node] {
+            match state.indices[next] {
+                None => {
+                    connect(state, next);
+                    state.low_links[node] = state.low_links[node].min(state.low_links[next]);
+                }
+                Some(index) if state.on_stack[next] => {
+                    state.low_links[node] = state.low_links[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(state.low_links[node]) == state.indices[node] {
+            let mut component = Vec::new();
+            while let Some(member) = state.stack.pop() {
+                state.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            state.components.push(component);
+        }
+    }
+
+    let mut state = State {
+        edges,
+        next_index: 0,
+        indices: vec![None; edges.len()],
+        low_links: vec![0; edges.len()],
+        stack: Vec::new(),
+        on_stack: vec![false; edges.len()],
+        components: Vec::new(),
+    };
+    for node in 0..edges.len() {
+        if state.indices[node].is_none() {
+            connect(&mut state, node);
+        }
+    }
+
+    state.components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source_with_config;
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_tight_coupling: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mutually_referencing_classes_are_flagged() {
+        let source = r#"
+class Order {
+    customer: Customer;
+}
+
+class Customer {
+    constructor(private orders: Order[]) {}
+}
+
+class Invoice {
+    order: Order;
+}
+"#;
+
+        let findings = check_source_with_config(&TightCouplingRule, "models.ts", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "tight-coupling");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Classes Order, Customer reference each other in a cycle");
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_new_expressions_count_as_references() {
+        let source = r#"
+            class Engine { start() { return new Car(); } }
+            class Car { constructor() { this.engine = new Engine(); } }
+            class Wheel { roll() { return new Car(); } }
+        "#;
+
+        assert_eq!(check_source_with_config(&TightCouplingRule, "cars.js", source, &enabled()).len(), 1);
+    }
+
+    #[test]
+    fn test_self_reference_and_off_by_default() {
+        let source = "class Node { next: Node; }\n";
+
+        assert!(check_source_with_config(&TightCouplingRule, "list.ts", source, &enabled()).is_empty());
+        assert!(!TightCouplingRule.is_enabled_by_config(&RuleConfig::default()));
+    }
+}
@@ -0,0 +1,152 @@
+use tree_sitter::Node;
+
+use super::{node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Location, Severity};
+
+const DEFAULT_MAX_TODO_DENSITY: f64 = 5.0;
+
+/// Files shorter than this aren't checked: a single TODO in a 15-line
+/// file is 6.7 per 100 lines, which says nothing about the file.
+const MIN_LINES: usize = 50;
+
+/// Comment markers that record known technical debt.
+const TECH_DEBT_MARKERS: &[&str] = &["TODO", "FIXME", "HACK", "XXX"];
+
+/// Flags files of at least `MIN_LINES` lines whose tech-debt marker density
+/// (markers per 100 lines) exceeds `RuleConfig.max_todo_density`.
+pub struct TodoDensityRule;
+
+impl Rule for TodoDensityRule {
+    fn id(&self) -> &'static str {
+        "high-todo-density"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Many TODO comments in one file signal unfinished work that is easy to forget."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        if ctx.source.lines().count() < MIN_LINES {
+            return Vec::new();
+        }
+        let max_density = ctx.config.max_todo_density.unwrap_or(DEFAULT_MAX_TODO_DENSITY);
+        let density = todo_density(ctx.parse_result.tree.root_node(), ctx.source);
+
+        if density <= max_density {
+            return Vec::new();
+        }
+
+        vec![Finding::new(
+            self.id(),
+            Severity::Low,
+            format!(
+                "File has {:.1} tech-debt markers per 100 lines (max: {:.1})",
+                density, max_density
+            ),
+            Location {
+                line: 1,
+                column: 1,
+                end_line: None,
+                end_column: None,
+            },
+        )
+        .with_suggestion("Resolve or ticket the outstanding TODO/FIXME comments")]
+    }
+}
+
+/// Tech-debt markers per 100 lines of `source`.
+pub fn todo_density(root: Node, source: &str) -> f64 {
+    let lines = source.lines().count();
+    if lines == 0 {
+        return 0.0;
+    }
+
+    count_markers(root, source) as f64 * 100.0 / lines as f64
+}
+
+/// Number of TODO/FIXME/HACK/XXX markers appearing in comments.
+pub(crate) fn count_markers(root: Node, source: &str) -> u32 {
+    let mut count = 0;
+
+    walk_tree(root, source, &mut |node: Node| {
+        if node.kind() != "comment" {
+            return;
+        }
+        count += node_text(&node, source)
+            .split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .filter(|word| TECH_DEBT_MARKERS.contains(word))
+            .count() as u32;
+    });
+
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    fn parse(source: &str) -> tree_sitter::Tree {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(tree_sitter_javascript::language()).unwrap();
+        parser.parse(source, None).unwrap()
+    }
+
+    fn source_with_markers(lines: usize, markers: usize) -> String {
+        (0..lines)
+            .map(|i| {
+                if i < markers {
+                    format!("// TODO: item {}\n", i)
+                } else {
+                    format!("const value{} = {};\n", i, i)
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_density_is_markers_per_hundred_lines() {
+        let source = source_with_markers(40, 3);
+        let tree = parse(&source);
+
+        assert_eq!(count_markers(tree.root_node(), &source), 3);
+        assert!((todo_density(tree.root_node(), &source) - 7.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_markers_outside_comments_and_partial_words_are_ignored() {
+        let source = "const TODO = 'FIXME';\n// TODOS are fine, XXXL too\n/* FIXME: real one */\n";
+        let tree = parse(source);
+
+        assert_eq!(count_markers(tree.root_node(), source), 1);
+    }
+
+    #[test]
+    fn test_high_density_file_is_flagged() {
+        let findings = check_source(&TodoDensityRule, "app.js", &source_with_markers(50, 4));
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "high-todo-density");
+        assert!(findings[0].message.contains("8.0"));
+    }
+
+    #[test]
+    fn test_short_file_with_one_todo_is_ignored() {
+        assert!(check_source(&TodoDensityRule, "app.js", &source_with_markers(15, 1)).is_empty());
+        assert!(check_source(&TodoDensityRule, "app.js", &source_with_markers(49, 4)).is_empty());
+    }
+
+    #[test]
+    fn test_threshold_is_configurable() {
+        let source = source_with_markers(50, 4);
+        let config = RuleConfig {
+            max_todo_density: Some(10.0),
+            ..Default::default()
+        };
+
+        assert!(check_source_with_config(&TodoDensityRule, "app.js", &source, &config).is_empty());
+        assert!(check_source(&TodoDensityRule, "app.js", &source_with_markers(100, 2)).is_empty());
+    }
+}
@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, Severity};
+
+/// Flags files importing more than `RuleConfig.max_imports` distinct
+/// modules. Several imports of one module count once, unlike the raw
+/// import count `high-coupling` holds to the same limit. Reported at the
+/// import that crosses the limit.
+pub struct TooManyImportsRule;
+
+const DEFAULT_MAX_IMPORTS: u32 = 25;
+
+impl Rule for TooManyImportsRule {
+    fn id(&self) -> &'static str {
+        "too-many-imports"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A file depending on many distinct modules is coupled to all of them and breaks whenever any changes."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max = ctx.config.max_imports.unwrap_or(DEFAULT_MAX_IMPORTS);
+
+        let mut modules = HashSet::new();
+        let mut first_over_limit = None;
+        for import in &ctx.parse_result.imports {
+            if modules.insert(import.module.as_str()) && modules.len() as u32 == max + 1 {
+                first_over_limit = Some(import.line);
+            }
+        }
+
+        let Some(line) = first_over_limit else {
+            return Vec::new();
+        };
+        vec![Finding::new(
+            self.id(),
+            Severity::Low,
+            format!("File imports {} distinct modules (max: {})", modules.len(), max),
+            Location {
+                line,
+                column: 1,
+                end_line: None,
+                end_column: None,
+            },
+        )
+        .with_suggestion("Group related dependencies behind a facade or barrel module")]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    #[test]
+    fn test_distinct_modules_over_limit_are_flagged() {
+        let source: String = (1..=30).map(|i| format!("import m{} from './module{}';\n", i, i)).collect();
+
+        let findings = check_source(&TooManyImportsRule, "app.js", &source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "too-many-imports");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "File imports 30 distinct modules (max: 25)");
+        assert_eq!(findings[0].location.line, 26);
+    }
+
+    #[test]
+    fn test_repeated_imports_of_one_module_count_once() {
+        let source: String = (1..=30).map(|i| format!("import {{ part{} }} from './shared';\n", i)).collect();
+        let config = RuleConfig {
+            max_imports: Some(1),
+            ..Default::default()
+        };
+
+        assert!(check_source_with_config(&TooManyImportsRule, "app.js", &source, &config).is_empty());
+    }
+}
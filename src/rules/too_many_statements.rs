@@ -0,0 +1,79 @@
+use super::{Rule, RuleContext};
+use crate::types::{Finding, Location, Severity};
+
+/// Flags functions with more statements than `RuleConfig.max_statements`.
+/// Unlike line counts, this ignores blank lines, comments and expressions
+/// wrapped across several lines.
+pub struct TooManyStatementsRule;
+
+const DEFAULT_MAX_STATEMENTS: u32 = 30;
+
+impl Rule for TooManyStatementsRule {
+    fn id(&self) -> &'static str {
+        "too-many-statements"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Long functions do too many things at once and are hard to name, test and change safely."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let max = ctx.config.max_statements.unwrap_or(DEFAULT_MAX_STATEMENTS);
+
+        ctx.parse_result
+            .functions
+            .iter()
+            .filter(|function| function.statement_count > max)
+            .map(|function| {
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!(
+                        "Function '{}' has {} statements (max: {})",
+                        function.name, function.statement_count, max
+                    ),
+                    Location {
+                        line: function.line,
+                        column: 1,
+                        end_line: None,
+                        end_column: None,
+                    },
+                )
+                .with_suggestion("Extract groups of related statements into helper functions")
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+    use crate::types::RuleConfig;
+
+    fn function_with_statements(count: usize) -> String {
+        let body: String = (0..count).map(|i| format!("  step{}();\n", i)).collect();
+        format!("function pipeline() {{\n{}}}\n", body)
+    }
+
+    #[test]
+    fn test_function_over_threshold_is_flagged() {
+        let config = RuleConfig {
+            max_statements: Some(5),
+            ..Default::default()
+        };
+
+        let findings = check_source_with_config(&TooManyStatementsRule, "app.js", &function_with_statements(6), &config);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "too-many-statements");
+        assert!(findings[0].message.contains("has 6 statements (max: 5)"));
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_default_threshold() {
+        assert!(check_source(&TooManyStatementsRule, "app.ts", &function_with_statements(30)).is_empty());
+        assert_eq!(check_source(&TooManyStatementsRule, "app.ts", &function_with_statements(31)).len(), 1);
+    }
+}
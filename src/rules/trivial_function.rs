@@ -0,0 +1,132 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags named functions whose whole body is a single `return` of a literal,
+/// identifier or property access. Such wrappers are often worth inlining,
+/// but plenty are intentional, so the rule is opt-in.
+pub struct TrivialFunctionRule;
+
+const FUNCTION_KINDS: &[&str] = &["function_declaration", "function_expression", "method_definition"];
+
+const TRIVIAL_VALUE_KINDS: &[&str] = &[
+    "identifier",
+    "member_expression",
+    "this",
+    "number",
+    "string",
+    "true",
+    "false",
+    "null",
+    "undefined",
+];
+
+impl Rule for TrivialFunctionRule {
+    fn id(&self) -> &'static str {
+        "trivial-function"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Functions that only return a literal, variable or property add indirection without adding meaning."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_trivial_functions.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if !FUNCTION_KINDS.contains(&node.kind()) {
+                return;
+            }
+            let (Some(name), Some(body)) = (node.child_by_field_name("name"), node.child_by_field_name("body")) else {
+                return;
+            };
+            if !is_trivial_body(&body) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("Function '{}' only returns a single value", node_text(&name, ctx.source)),
+                    node_location(&node),
+                )
+                .with_suggestion("Consider inlining the value or documenting why the wrapper exists"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn is_trivial_body(body: &Node) -> bool {
+    let mut cursor = body.walk();
+    let statements: Vec<Node> = body
+        .named_children(&mut cursor)
+        .filter(|statement| statement.kind() != "comment")
+        .collect();
+
+    let [statement] = statements.as_slice() else {
+        return false;
+    };
+    if statement.kind() != "return_statement" {
+        return false;
+    }
+
+    statement
+        .named_child(0)
+        .is_some_and(|value| TRIVIAL_VALUE_KINDS.contains(&value.kind()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_trivial_functions: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_single_return_functions_are_flagged() {
+        let source = r#"
+            function getName(user) { return user.name; }
+            class Config {
+                isEnabled() { return true; }
+            }
+            const answer = function answer() { return 42; };
+        "#;
+
+        let findings = check_source_with_config(&TrivialFunctionRule, "app.js", source, &enabled());
+
+        assert_eq!(findings.len(), 3);
+        assert_eq!(findings[0].rule_id, "trivial-function");
+        assert!(findings[0].message.contains("'getName'"));
+    }
+
+    #[test]
+    fn test_functions_with_real_work_are_ignored() {
+        let source = r#"
+            function add(a, b) { return a + b; }
+            function log(x) { console.log(x); return x; }
+            function noop() {}
+            const id = (x) => x;
+        "#;
+
+        assert!(check_source_with_config(&TrivialFunctionRule, "app.js", source, &enabled()).is_empty());
+    }
+
+    #[test]
+    fn test_rule_is_off_by_default() {
+        assert!(check_source(&TrivialFunctionRule, "app.js", "function f() { return 1; }").is_empty());
+    }
+}
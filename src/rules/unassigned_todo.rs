@@ -0,0 +1,136 @@
+use tree_sitter::Node;
+
+use super::{node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Location, RuleConfig, Severity};
+
+/// Markers that must name an owner or ticket, as in `TODO(alice)` or
+/// `FIXME(PROJ-123)`.
+const REFERENCED_MARKERS: &[&str] = &["TODO", "FIXME"];
+
+/// Flags `TODO`/`FIXME` comments without a parenthesized assignee or ticket
+/// reference. Opt-in via `RuleConfig.require_todo_reference`.
+pub struct UnassignedTodoRule;
+
+impl Rule for UnassignedTodoRule {
+    fn id(&self) -> &'static str {
+        "unassigned-todo"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "TODOs without an owner or issue reference tend never to be picked up."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.require_todo_reference.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "comment" {
+                return;
+            }
+            let text = node_text(&node, ctx.source);
+            let start = node.start_position();
+
+            for (offset, marker) in unreferenced_markers(text) {
+                // Markers inside block comments may sit on a later line
+                let preceding = &text[..offset];
+                let line_offset = preceding.matches('\n').count();
+                let column = match preceding.rfind('\n') {
+                    Some(newline) => offset - newline,
+                    None => start.column + offset + 1,
+                };
+
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("{} has no assignee or ticket reference", marker),
+                        Location {
+                            line: (start.row + line_offset) as u32 + 1,
+                            column: column as u32,
+                            end_line: None,
+                            end_column: None,
+                        },
+                    )
+                    .with_suggestion(&format!("Write it as {}(owner) or {}(TICKET-123)", marker, marker)),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Byte offsets and names of markers in `comment` that aren't followed by a
+/// non-empty `(...)` reference.
+fn unreferenced_markers(comment: &str) -> Vec<(usize, &'static str)> {
+    let mut markers = Vec::new();
+
+    for &marker in REFERENCED_MARKERS {
+        for (offset, _) in comment.match_indices(marker) {
+            let before = comment[..offset].chars().next_back();
+            let rest = &comment[offset + marker.len()..];
+            let is_word = !before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !rest.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_');
+            if is_word && !has_reference(rest) {
+                markers.push((offset, marker));
+            }
+        }
+    }
+
+    markers.sort_unstable();
+    markers
+}
+
+fn has_reference(after_marker: &str) -> bool {
+    after_marker
+        .strip_prefix('(')
+        .and_then(|rest| rest.split_once(')'))
+        .is_some_and(|(reference, _)| !reference.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source_with_config;
+
+    fn strict() -> RuleConfig {
+        RuleConfig {
+            require_todo_reference: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_bare_todo_is_flagged_and_referenced_one_is_not() {
+        let source = "// TODO(bob): x\nconst a = 1;\n// TODO: x\n/* FIXME(PROJ-42) and\n   FIXME later */\n";
+
+        let findings = check_source_with_config(&UnassignedTodoRule, "app.js", source, &strict());
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "unassigned-todo");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!((findings[0].location.line, findings[0].location.column), (3, 4));
+        assert!(findings[1].message.starts_with("FIXME"));
+        assert_eq!((findings[1].location.line, findings[1].location.column), (5, 4));
+    }
+
+    #[test]
+    fn test_empty_reference_and_other_markers() {
+        let source = "// TODO() fill in\n// HACK: allowed\n// TODOS and XFIXME are not markers\n";
+
+        let findings = check_source_with_config(&UnassignedTodoRule, "app.ts", source, &strict());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        assert!(!UnassignedTodoRule.is_enabled_by_config(&RuleConfig::default()));
+    }
+}
@@ -0,0 +1,145 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `process.env.X` / `process.env["X"]` reads used as if the variable
+/// were always set. A read is considered handled when it has a `||` / `??`
+/// fallback, is tested (`if`, ternary, comparison, `typeof`, `!`), or is
+/// passed straight to a validation helper such as `assert` or `required`.
+/// Opt-in via `RuleConfig.detect_unchecked_env_access`.
+pub struct UncheckedEnvAccessRule;
+
+/// Callees whose argument is taken to be validated.
+const VALIDATORS: &[&str] = &["assert", "invariant", "required", "requireEnv", "parse"];
+
+const CHECK_OPERATORS: &[&str] = &["||", "??", "===", "!==", "==", "!="];
+
+impl Rule for UncheckedEnvAccessRule {
+    fn id(&self) -> &'static str {
+        "unchecked-env-access"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Environment variables may be unset, so reading them without a fallback or check fails far from the misconfiguration."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_unchecked_env_access.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let is_env_read = matches!(node.kind(), "member_expression" | "subscript_expression")
+                && node
+                    .child_by_field_name("object")
+                    .is_some_and(|object| node_text(&object, ctx.source) == "process.env");
+            if !is_env_read || is_handled(&node, ctx.source) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("'{}' may be undefined", node_text(&node, ctx.source)),
+                    node_location(&node),
+                )
+                .with_suggestion("Provide a default with ?? or validate required variables at startup"),
+            );
+        });
+
+        findings
+    }
+}
+
+fn is_handled(read: &Node, source: &str) -> bool {
+    // Parentheses don't change how the value is used
+    let mut current = *read;
+    let mut parent = read.parent();
+    while let Some(p) = parent.filter(|p| p.kind() == "parenthesized_expression") {
+        current = p;
+        parent = p.parent();
+    }
+    let Some(parent) = parent else {
+        return false;
+    };
+    let is_field = |field: &str| parent.child_by_field_name(field).is_some_and(|child| child == current);
+
+    match parent.kind() {
+        "binary_expression" => parent
+            .child_by_field_name("operator")
+            .is_some_and(|operator| CHECK_OPERATORS.contains(&node_text(&operator, source))),
+        "unary_expression" => parent
+            .child_by_field_name("operator")
+            .is_some_and(|operator| matches!(node_text(&operator, source), "typeof" | "!")),
+        "if_statement" | "while_statement" | "ternary_expression" => is_field("condition"),
+        "assignment_expression" | "augmented_assignment_expression" => is_field("left"),
+        "arguments" => parent
+            .parent()
+            .and_then(|call| call.child_by_field_name("function"))
+            .is_some_and(|callee| {
+                let name = match callee.kind() {
+                    "member_expression" => callee.child_by_field_name("property"),
+                    _ => Some(callee),
+                };
+                name.is_some_and(|name| VALIDATORS.contains(&node_text(&name, source)))
+            }),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source_with_config;
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_unchecked_env_access: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_env_read_without_fallback_is_flagged() {
+        let source = "const port = process.env.PORT;\nconst fallback = process.env.PORT || 3000;\n";
+
+        let findings = check_source_with_config(&UncheckedEnvAccessRule, "server.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unchecked-env-access");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "'process.env.PORT' may be undefined");
+        assert_eq!(findings[0].location.line, 1);
+    }
+
+    #[test]
+    fn test_checked_reads_are_ignored() {
+        let source = r#"
+            const host = process.env["HOST"] ?? "localhost";
+            if (process.env.DEBUG) { enableDebug(); }
+            const mode = process.env.NODE_ENV === "production" ? "prod" : "dev";
+            const secret = required(process.env.SECRET);
+            process.env.TZ = "UTC";
+            const urls = (process.env.URLS || "").split(",");
+        "#;
+
+        assert!(check_source_with_config(&UncheckedEnvAccessRule, "config.ts", source, &enabled()).is_empty());
+    }
+
+    #[test]
+    fn test_chained_and_subscript_reads_are_flagged() {
+        let source = "const hosts = process.env.HOSTS.split(',');\nconnect(process.env['DB_URL']);\n";
+
+        assert_eq!(check_source_with_config(&UncheckedEnvAccessRule, "db.js", source, &enabled()).len(), 2);
+        assert!(!UncheckedEnvAccessRule.is_enabled_by_config(&RuleConfig::default()));
+    }
+}
@@ -0,0 +1,115 @@
+use tree_sitter::Node;
+
+use super::blocking_in_async::is_async;
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags `async` functions whose body never awaits, directly or through
+/// `for await`. Awaits inside nested functions belong to those functions
+/// and don't count.
+pub struct UnnecessaryAsyncRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+impl Rule for UnnecessaryAsyncRule {
+    fn id(&self) -> &'static str {
+        "unnecessary-async"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "An async function that never awaits wraps its result in a promise for no reason and misleads readers about what it does."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if !FUNCTION_KINDS.contains(&node.kind()) || !is_async(&node) {
+                return;
+            }
+            if node.child_by_field_name("body").is_none_or(|body| awaits(&body)) {
+                return;
+            }
+
+            let message = match function_name(&node, ctx.source) {
+                Some(name) => format!("Async function '{}' never awaits", name),
+                None => "Async function never awaits".to_string(),
+            };
+            findings.push(
+                Finding::new(self.id(), Severity::Low, message, node_location(&node))
+                    .with_suggestion("Drop the async keyword, or return a promise explicitly if callers need one"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Whether `node` contains an `await` outside any nested function.
+fn awaits(node: &Node) -> bool {
+    if node.kind() == "await_expression" || node.kind() == "await" {
+        return true;
+    }
+    let mut cursor = node.walk();
+    let found = node
+        .children(&mut cursor)
+        .any(|child| !FUNCTION_KINDS.contains(&child.kind()) && awaits(&child));
+    found
+}
+
+fn function_name<'a>(function: &Node, source: &'a str) -> Option<&'a str> {
+    let name = function.child_by_field_name("name").or_else(|| {
+        function
+            .parent()
+            .filter(|parent| parent.kind() == "variable_declarator")
+            .and_then(|declarator| declarator.child_by_field_name("name"))
+    })?;
+    Some(node_text(&name, source))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_async_function_returning_plain_value_is_flagged() {
+        let source = "async function getLimit() {\n  return 10;\n}\nconst load = async () => {\n  const run = async () => await fetch('/a');\n  return run;\n};\n";
+
+        let findings = check_source(&UnnecessaryAsyncRule, "limits.js", source);
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "unnecessary-async");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Async function 'getLimit' never awaits");
+        assert_eq!(findings[1].message, "Async function 'load' never awaits");
+        assert_eq!(findings[1].location.line, 4);
+    }
+
+    #[test]
+    fn test_awaiting_functions_are_ignored() {
+        let source = r#"
+            async function drain(stream) {
+                for await (const chunk of stream) {
+                    handle(chunk);
+                }
+            }
+            class Store {
+                async save(record) {
+                    return await db.put(record);
+                }
+            }
+            function plain() { return 1; }
+        "#;
+
+        assert!(check_source(&UnnecessaryAsyncRule, "store.js", source).is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Language, Severity};
+
+/// Flags TypeScript `as` assertions to `any`, `unknown` or `object`, which
+/// widen a value instead of narrowing it. `x as unknown as Foo` is reported
+/// once, as a double assertion, since it forces an unrelated type through.
+pub struct UnsafeCastRule;
+
+const WIDE_TYPES: &[&str] = &["any", "unknown", "object"];
+
+impl Rule for UnsafeCastRule {
+    fn id(&self) -> &'static str {
+        "unsafe-cast"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Assertions to `any` or through `unknown` switch off type checking, hiding the mistakes the types were meant to catch."
+    }
+
+    fn is_enabled(&self, ctx: &RuleContext) -> bool {
+        ctx.language == Language::TypeScript
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "as_expression" {
+                return;
+            }
+            let Some(target) = node.named_child(1) else {
+                return;
+            };
+            let target = node_text(&target, ctx.source);
+            if !WIDE_TYPES.contains(&target) {
+                return;
+            }
+
+            let (message, location) = match node.parent().filter(|parent| parent.kind() == "as_expression") {
+                Some(outer) => (
+                    format!("Double assertion through '{}' bypasses type checking", target),
+                    node_location(&outer),
+                ),
+                None => (format!("Assertion to '{}' widens the type", target), node_location(&node)),
+            };
+
+            findings.push(
+                Finding::new(self.id(), Severity::Low, message, location)
+                    .with_suggestion("Narrow with a type guard or fix the source type instead of asserting"),
+            );
+        });
+
+        findings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_double_assertion_is_flagged_once() {
+        let source = "const user = response as unknown as User;\n";
+
+        let findings = check_source(&UnsafeCastRule, "api.ts", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unsafe-cast");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Double assertion through 'unknown' bypasses type checking");
+        assert_eq!((findings[0].location.line, findings[0].location.column), (1, 14));
+    }
+
+    #[test]
+    fn test_widening_assertions_are_flagged() {
+        let source = "const a = (value as any).field;\nconst b = value as object;\nconst c = value as User;\nconst d = <any>value;\n";
+
+        let findings = check_source(&UnsafeCastRule, "cast.ts", source);
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].message, "Assertion to 'any' widens the type");
+        assert_eq!(findings[1].location.line, 2);
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, Severity};
+
+/// Flags class private fields (`#field`, or TypeScript `private` fields and
+/// parameter properties) that nothing in the class body reads or writes.
+/// Unlike `dead-code`, references are collected per class, since a private
+/// field can only be reached from inside it.
+pub struct UnusedPrivateFieldRule;
+
+impl Rule for UnusedPrivateFieldRule {
+    fn id(&self) -> &'static str {
+        "unused-private-field"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A private field nothing reads or writes is dead state that misleads readers about what the class tracks."
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "class_body" {
+                return;
+            }
+
+            let referenced = class_member_references(&node, ctx.source);
+            for field in private_fields(&node, ctx.source) {
+                let name = node_text(&field, ctx.source);
+                if referenced.contains(name) {
+                    continue;
+                }
+                findings.push(
+                    Finding::new(
+                        self.id(),
+                        Severity::Low,
+                        format!("Private field '{}' is never used", name),
+                        node_location(&field),
+                    )
+                    .with_suggestion("Remove the field or use it"),
+                );
+            }
+        });
+
+        findings
+    }
+}
+
+/// Name nodes of the private fields declared directly in `body`.
+fn private_fields<'a>(body: &Node<'a>, source: &str) -> Vec<Node<'a>> {
+    let mut fields = Vec::new();
+
+    for member in (0..body.named_child_count()).filter_map(|i| body.named_child(i)) {
+        match member.kind() {
+            "field_definition" | "public_field_definition" => {
+                let name = member
+                    .child_by_field_name("property")
+                    .or_else(|| member.child_by_field_name("name"));
+                if let Some(name) = name {
+                    if name.kind() == "private_property_identifier" || is_ts_private(&member) {
+                        fields.push(name);
+                    }
+                }
+            }
+            "method_definition" => {
+                // TS parameter properties: `constructor(private readonly svc: Service)`
+                let is_constructor = member
+                    .child_by_field_name("name")
+                    .is_some_and(|name| node_text(&name, source) == "constructor");
+                let Some(parameters) = member.child_by_field_name("parameters").filter(|_| is_constructor) else {
+                    continue;
+                };
+                for parameter in (0..parameters.named_child_count()).filter_map(|i| parameters.named_child(i)) {
+                    if let Some(pattern) = parameter.child_by_field_name("pattern") {
+                        if is_ts_private(&parameter) && pattern.kind() == "identifier" {
+                            fields.push(pattern);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fields
+}
+
+fn is_ts_private(node: &Node) -> bool {
+    (0..node.named_child_count())
+        .filter_map(|i| node.named_child(i))
+        .any(|child| child.kind() == "accessibility_modifier" && child.child(0).map(|m| m.kind()) == Some("private"))
+}
+
+/// Property names accessed anywhere in the class body, as `this.x`,
+/// `other.#x` or the `#x in obj` brand check.
+fn class_member_references<'a>(body: &Node, source: &'a str) -> HashSet<&'a str> {
+    let mut referenced = HashSet::new();
+
+    walk_tree(*body, source, &mut |node: Node| {
+        let name = match node.kind() {
+            "member_expression" => node.child_by_field_name("property"),
+            "binary_expression" => node.child_by_field_name("left").filter(|left| left.kind() == "private_property_identifier"),
+            _ => None,
+        };
+        if let Some(name) = name {
+            referenced.insert(node_text(&name, source));
+        }
+    });
+
+    referenced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_unused_hash_field_is_flagged() {
+        let source = "class Counter {\n  #count = 0;\n  #unused = [];\n  static #instances = 0;\n  increment() { this.#count++; return #instances in Counter; }\n}\n";
+
+        let findings = check_source(&UnusedPrivateFieldRule, "counter.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "unused-private-field");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "Private field '#unused' is never used");
+        assert_eq!((findings[0].location.line, findings[0].location.column), (3, 3));
+    }
+
+    #[test]
+    fn test_typescript_private_fields_and_parameter_properties() {
+        let source = "class Service {\n  private cache: Map<string, number> = new Map();\n  private retries = 3;\n  public label = '';\n  constructor(private readonly client: Client, private logger: Logger) {}\n  get(key: string) { return this.cache.get(key) ?? this.client.fetch(key); }\n}\n";
+
+        let findings = check_source(&UnusedPrivateFieldRule, "service.ts", source);
+
+        let messages: Vec<_> = findings.iter().map(|f| f.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["Private field 'retries' is never used", "Private field 'logger' is never used"]
+        );
+    }
+}
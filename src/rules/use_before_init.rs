@@ -0,0 +1,192 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `let x;` and `var x;` declarations whose first reference after
+/// the declaration, in source order within the same function, reads the
+/// variable instead of assigning it. References from nested functions are
+/// ignored, since when those run isn't known. Opt-in via
+/// `RuleConfig.detect_use_before_init`.
+pub struct UseBeforeInitRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "function",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+const REFERENCE_KINDS: &[&str] = &["identifier", "shorthand_property_identifier", "shorthand_property_identifier_pattern"];
+
+impl Rule for UseBeforeInitRule {
+    fn id(&self) -> &'static str {
+        "use-before-init"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Reading a variable declared without a value yields undefined, which usually means an assignment was missed."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.8
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_use_before_init.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "variable_declarator" || node.child_by_field_name("value").is_some() || !is_let_or_var(&node) {
+                return;
+            }
+            let Some(name) = node.child_by_field_name("name").filter(|name| name.kind() == "identifier") else {
+                return;
+            };
+            let text = node_text(&name, ctx.source);
+            let Some(reference) = first_reference(enclosing_scope(&node), text, name.end_byte(), ctx.source) else {
+                return;
+            };
+            if is_write(&reference) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Medium,
+                    format!("'{}' is read before it is assigned", text),
+                    node_location(&reference),
+                )
+                .with_suggestion("Initialize the variable where it is declared, or assign it before this read"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// Declared with `let` or `var`; `const` always has a value and ambient
+/// `declare let` has no runtime binding.
+fn is_let_or_var(declarator: &Node) -> bool {
+    let Some(declaration) = declarator.parent() else {
+        return false;
+    };
+    if declaration.parent().is_some_and(|parent| parent.kind() == "ambient_declaration") {
+        return false;
+    }
+    match declaration.kind() {
+        "variable_declaration" => true,
+        "lexical_declaration" => declaration.child(0).is_some_and(|keyword| keyword.kind() == "let"),
+        _ => false,
+    }
+}
+
+/// Body of the nearest enclosing function, or the whole program.
+fn enclosing_scope<'a>(node: &Node<'a>) -> Node<'a> {
+    let mut current = *node;
+    while let Some(parent) = current.parent() {
+        if FUNCTION_KINDS.contains(&parent.kind()) {
+            return parent.child_by_field_name("body").unwrap_or(parent);
+        }
+        current = parent;
+    }
+    current
+}
+
+/// First reference to `name` starting at or after byte `after`, outside
+/// nested functions.
+fn first_reference<'a>(node: Node<'a>, name: &str, after: usize, source: &str) -> Option<Node<'a>> {
+    if node.end_byte() <= after {
+        return None;
+    }
+    if node.start_byte() >= after && REFERENCE_KINDS.contains(&node.kind()) && node_text(&node, source) == name {
+        return Some(node);
+    }
+
+    let mut cursor = node.walk();
+    let children: Vec<Node<'a>> = node.named_children(&mut cursor).collect();
+    children
+        .into_iter()
+        .filter(|child| !FUNCTION_KINDS.contains(&child.kind()))
+        .find_map(|child| first_reference(child, name, after, source))
+}
+
+/// Whether `reference` is an assignment target, directly or inside a
+/// destructuring pattern, rather than a read.
+fn is_write(reference: &Node) -> bool {
+    let mut child = *reference;
+    while let Some(parent) = child.parent() {
+        let target = match parent.kind() {
+            "assignment_expression" | "for_in_statement" => return is_field(&parent, "left", &child),
+            "assignment_pattern" | "object_assignment_pattern" => is_field(&parent, "left", &child),
+            "pair_pattern" => is_field(&parent, "value", &child),
+            "array_pattern" | "object_pattern" | "rest_pattern" => true,
+            _ => false,
+        };
+        if !target {
+            return false;
+        }
+        child = parent;
+    }
+    false
+}
+
+fn is_field(parent: &Node, field: &str, child: &Node) -> bool {
+    parent.child_by_field_name(field).is_some_and(|node| node.id() == child.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_use_before_init: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_read_before_assignment_is_flagged() {
+        let source = "function total(items) {\n  let sum;\n  for (const item of items) {\n    sum += item.price;\n  }\n  return sum;\n}\n";
+
+        let findings = check_source_with_config(&UseBeforeInitRule, "total.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "use-before-init");
+        assert!(matches!(findings[0].severity, Severity::Medium));
+        assert_eq!(findings[0].message, "'sum' is read before it is assigned");
+        assert_eq!((findings[0].location.line, findings[0].location.column), (4, 5));
+    }
+
+    #[test]
+    fn test_assigned_initialized_and_closure_uses_are_ignored() {
+        let source = r#"
+            let label;
+            label = compute();
+            use(label);
+            var [first] = pair, count = 0;
+            let parsed;
+            ({ parsed } = decode(input));
+            let later;
+            const read = () => later;
+            later = 1;
+        "#;
+
+        assert!(check_source_with_config(&UseBeforeInitRule, "ok.js", source, &enabled()).is_empty());
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        assert!(check_source(&UseBeforeInitRule, "a.js", "let x;\nconsole.log(x);\n").is_empty());
+    }
+}
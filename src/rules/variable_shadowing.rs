@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags parameters and declarations that reuse the name of a binding in
+/// an enclosing function or the module scope. Scopes are tracked per
+/// function, so `let` redeclarations in nested blocks of one function
+/// aren't reported. Opt-in via `RuleConfig.detect_variable_shadowing`.
+pub struct VariableShadowingRule;
+
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",
+    "function_expression",
+    "function",
+    "arrow_function",
+    "method_definition",
+    "generator_function",
+    "generator_function_declaration",
+];
+
+impl Rule for VariableShadowingRule {
+    fn id(&self) -> &'static str {
+        "variable-shadowing"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A name that shadows an outer binding makes it easy to read or write the wrong variable."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_variable_shadowing.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+        let mut outer = Vec::new();
+        check_scope(ctx.parse_result.tree.root_node(), ctx.source, &mut outer, &mut |name, node, outer_line| {
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("'{}' shadows a variable declared on line {}", name, outer_line),
+                    node_location(&node),
+                )
+                .with_suggestion("Rename one of the variables"),
+            );
+        });
+
+        findings
+    }
+}
+
+type Scope<'a> = HashMap<&'a str, u32>;
+
+/// Checks the bindings of `scope` against the enclosing `outer` scopes,
+/// then recurses into the functions it contains.
+fn check_scope<'a>(
+    scope: Node<'a>,
+    source: &'a str,
+    outer: &mut Vec<Scope<'a>>,
+    report: &mut dyn FnMut(&'a str, Node<'a>, u32),
+) {
+    let mut names = Vec::new();
+    if let Some(parameters) = scope
+        .child_by_field_name("parameters")
+        .or_else(|| scope.child_by_field_name("parameter"))
+    {
+        pattern_names(parameters, &mut names);
+    }
+    let mut functions = Vec::new();
+    let body = if scope.kind() == "program" { Some(scope) } else { scope.child_by_field_name("body") };
+    if let Some(body) = body {
+        declarations(body, &mut names, &mut functions);
+    }
+
+    let mut bindings = Scope::new();
+    for name in names {
+        let text = node_text(&name, source);
+        let line = name.start_position().row as u32 + 1;
+        if let Some(outer_line) = outer.iter().rev().find_map(|scope| scope.get(text)) {
+            report(text, name, *outer_line);
+        }
+        bindings.entry(text).or_insert(line);
+    }
+
+    outer.push(bindings);
+    for function in functions {
+        check_scope(function, source, outer, report);
+    }
+    outer.pop();
+}
+
+/// Declared names directly in `node`'s scope, and the nested functions
+/// that open scopes of their own.
+fn declarations<'a>(node: Node<'a>, names: &mut Vec<Node<'a>>, functions: &mut Vec<Node<'a>>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if FUNCTION_KINDS.contains(&child.kind()) {
+            if child.kind() == "function_declaration" || child.kind() == "generator_function_declaration" {
+                names.extend(child.child_by_field_name("name"));
+            }
+            functions.push(child);
+            continue;
+        }
+        match child.kind() {
+            "variable_declarator" => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    pattern_names(name, names);
+                }
+            }
+            "class_declaration" => names.extend(child.child_by_field_name("name")),
+            _ => {}
+        }
+        declarations(child, names, functions);
+    }
+}
+
+/// Identifiers bound by a parameter list or destructuring pattern, without
+/// default values, renamed keys or type annotations.
+fn pattern_names<'a>(pattern: Node<'a>, names: &mut Vec<Node<'a>>) {
+    match pattern.kind() {
+        "identifier" | "shorthand_property_identifier_pattern" => names.push(pattern),
+        "assignment_pattern" | "object_assignment_pattern" => {
+            if let Some(left) = pattern.child_by_field_name("left") {
+                pattern_names(left, names);
+            }
+        }
+        "pair_pattern" => {
+            if let Some(value) = pattern.child_by_field_name("value") {
+                pattern_names(value, names);
+            }
+        }
+        "required_parameter" | "optional_parameter" => {
+            if let Some(inner) = pattern.child_by_field_name("pattern") {
+                pattern_names(inner, names);
+            }
+        }
+        "formal_parameters" | "object_pattern" | "array_pattern" | "rest_pattern" => {
+            let mut cursor = pattern.walk();
+            for child in pattern.named_children(&mut cursor) {
+                pattern_names(child, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_variable_shadowing: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_inner_parameter_shadowing_outer_variable_is_flagged() {
+        let source = "const user = load();\nfunction greet(user) {\n  return `Hi ${user.name}`;\n}\n";
+
+        let findings = check_source_with_config(&VariableShadowingRule, "greet.js", source, &enabled());
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "variable-shadowing");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "'user' shadows a variable declared on line 1");
+        assert_eq!((findings[0].location.line, findings[0].location.column), (2, 16));
+    }
+
+    #[test]
+    fn test_nested_declarations_and_patterns_are_checked() {
+        let source = r#"
+function outer(items, { limit = 10 }) {
+    const total = 0;
+    items.forEach((item) => {
+        const [total] = item.parts;
+        const helper = ({ limit }) => limit;
+    });
+}
+function sibling(item) { return item; }
+"#;
+
+        let findings = check_source_with_config(&VariableShadowingRule, "nested.ts", source, &enabled());
+        let messages: Vec<&str> = findings.iter().map(|finding| finding.message.as_str()).collect();
+
+        assert_eq!(
+            messages,
+            vec![
+                "'total' shadows a variable declared on line 3",
+                "'limit' shadows a variable declared on line 2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        let source = "const id = 1;\nconst read = (id) => id;\n";
+
+        assert!(check_source(&VariableShadowingRule, "ids.js", source).is_empty());
+    }
+}
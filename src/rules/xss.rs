@@ -0,0 +1,148 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags HTML sinks fed anything other than a literal: assignments to
+/// `innerHTML` / `outerHTML` and JSX `dangerouslySetInnerHTML={{ __html }}`.
+/// Literal markup is fine; computed markup may carry user input.
+pub struct XssRiskRule;
+
+const HTML_PROPERTIES: &[&str] = &["innerHTML", "outerHTML"];
+
+impl Rule for XssRiskRule {
+    fn id(&self) -> &'static str {
+        "xss-risk"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "Writing unescaped strings as HTML lets attacker-controlled data run scripts in the user's browser."
+    }
+
+    fn confidence(&self) -> f64 {
+        0.7
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.enable_security_rules.unwrap_or(true)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            let Some((sink, value)) = html_sink(&node, ctx.source) else {
+                return;
+            };
+            if is_literal(&value) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::High,
+                    format!("Non-literal value written to {}", sink),
+                    node_location(&node),
+                )
+                .with_suggestion("Use textContent, or sanitize the markup (e.g. DOMPurify) before inserting it"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// The sink name and the value written to it, for `el.innerHTML = value`,
+/// `el.innerHTML += value` and `dangerouslySetInnerHTML={{ __html: value }}`.
+fn html_sink<'a>(node: &Node<'a>, source: &str) -> Option<(&'static str, Node<'a>)> {
+    match node.kind() {
+        "assignment_expression" | "augmented_assignment_expression" => {
+            let left = node.child_by_field_name("left")?;
+            if left.kind() != "member_expression" {
+                return None;
+            }
+            let property = node_text(&left.child_by_field_name("property")?, source);
+            let sink = HTML_PROPERTIES.iter().find(|&&name| name == property)?;
+            Some((sink, node.child_by_field_name("right")?))
+        }
+        "jsx_attribute" => {
+            let name = node.named_child(0)?;
+            if node_text(&name, source) != "dangerouslySetInnerHTML" {
+                return None;
+            }
+            let object = node.named_child(1)?.named_child(0)?;
+            if object.kind() != "object" {
+                return None;
+            }
+
+            let mut cursor = object.walk();
+            let html = object.named_children(&mut cursor).find(|pair| {
+                pair.kind() == "pair"
+                    && pair
+                        .child_by_field_name("key")
+                        .is_some_and(|key| node_text(&key, source) == "__html")
+            })?;
+            Some(("dangerouslySetInnerHTML", html.child_by_field_name("value")?))
+        }
+        _ => None,
+    }
+}
+
+/// A string literal, or a template string without substitutions.
+fn is_literal(value: &Node) -> bool {
+    match value.kind() {
+        "string" => true,
+        "template_string" => {
+            let mut cursor = value.walk();
+            let has_substitution = value
+                .named_children(&mut cursor)
+                .any(|child| child.kind() == "template_substitution");
+            !has_substitution
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::check_source;
+
+    #[test]
+    fn test_inner_html_from_user_input_is_flagged() {
+        let source = "const el = document.getElementById('out');\nel.innerHTML = userInput;\n";
+
+        let findings = check_source(&XssRiskRule, "render.js", source);
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule_id, "xss-risk");
+        assert!(matches!(findings[0].severity, Severity::High));
+        assert!(findings[0].message.contains("innerHTML"));
+        assert_eq!(findings[0].location.line, 2);
+    }
+
+    #[test]
+    fn test_other_sinks_are_covered() {
+        let source = r#"
+            node.outerHTML = `<p>${name}</p>`;
+            list.innerHTML += item;
+            const view = <div dangerouslySetInnerHTML={{ __html: markup }} />;
+        "#;
+
+        assert_eq!(check_source(&XssRiskRule, "view.jsx", source).len(), 3);
+    }
+
+    #[test]
+    fn test_literal_markup_is_ignored() {
+        let source = r#"
+            el.innerHTML = '';
+            el.innerHTML = `<br>`;
+            el.textContent = userInput;
+            const view = <div dangerouslySetInnerHTML={{ __html: "<b>hi</b>" }} />;
+        "#;
+
+        assert!(check_source(&XssRiskRule, "view.tsx", source).is_empty());
+    }
+}
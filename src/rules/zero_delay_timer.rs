@@ -0,0 +1,110 @@
+use tree_sitter::Node;
+
+use super::{node_location, node_text, Rule, RuleContext};
+use crate::parser::visitor::walk_tree;
+use crate::types::{Finding, RuleConfig, Severity};
+
+/// Flags `setTimeout`/`setInterval` calls with a literal `0` delay or none
+/// at all. Deferring to "the next tick" usually papers over an ordering
+/// problem. Plenty of uses are deliberate, so the rule is opt-in via
+/// `RuleConfig.detect_zero_delay_timers`.
+pub struct ZeroDelayTimerRule;
+
+const TIMERS: &[&str] = &["setTimeout", "setInterval"];
+const GLOBAL_OBJECTS: &[&str] = &["window", "globalThis", "self", "global"];
+
+impl Rule for ZeroDelayTimerRule {
+    fn id(&self) -> &'static str {
+        "zero-delay-timer"
+    }
+
+    fn rationale(&self) -> &'static str {
+        "A zero-delay timer defers work to a later tick, which often hides an ordering bug instead of fixing it."
+    }
+
+    fn is_enabled_by_config(&self, config: &RuleConfig) -> bool {
+        config.detect_zero_delay_timers.unwrap_or(false)
+    }
+
+    fn check(&self, ctx: &RuleContext) -> Vec<Finding> {
+        let mut findings = Vec::new();
+
+        walk_tree(ctx.parse_result.tree.root_node(), ctx.source, &mut |node: Node| {
+            if node.kind() != "call_expression" {
+                return;
+            }
+            let Some(timer) = node.child_by_field_name("function").and_then(|callee| timer_name(&callee, ctx.source)) else {
+                return;
+            };
+            let delay = node.child_by_field_name("arguments").and_then(|arguments| arguments.named_child(1));
+            if !delay.is_none_or(|delay| is_zero(&delay, ctx.source)) {
+                return;
+            }
+
+            findings.push(
+                Finding::new(
+                    self.id(),
+                    Severity::Low,
+                    format!("{} with a zero delay", timer),
+                    node_location(&node),
+                )
+                .with_suggestion("Make the ordering explicit with a promise, event or callback instead"),
+            );
+        });
+
+        findings
+    }
+}
+
+/// `setTimeout`, or `window.setTimeout` and the like.
+fn timer_name<'a>(callee: &Node, source: &'a str) -> Option<&'a str> {
+    let name = match callee.kind() {
+        "identifier" => *callee,
+        "member_expression" => {
+            let object = callee.child_by_field_name("object")?;
+            if !GLOBAL_OBJECTS.contains(&node_text(&object, source)) {
+                return None;
+            }
+            callee.child_by_field_name("property")?
+        }
+        _ => return None,
+    };
+    let name = node_text(&name, source);
+    TIMERS.contains(&name).then_some(name)
+}
+
+fn is_zero(delay: &Node, source: &str) -> bool {
+    delay.kind() == "number" && node_text(delay, source).parse::<f64>() == Ok(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rules::{check_source, check_source_with_config};
+
+    fn enabled() -> RuleConfig {
+        RuleConfig {
+            detect_zero_delay_timers: Some(true),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_zero_and_missing_delays_are_flagged() {
+        let source = "setTimeout(cb, 0);\nwindow.setInterval(poll);\nsetTimeout(cb, 100);\nsetTimeout(cb, delay);\nscheduler.setTimeout(cb, 0);\n";
+
+        let findings = check_source_with_config(&ZeroDelayTimerRule, "timers.js", source, &enabled());
+
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[0].rule_id, "zero-delay-timer");
+        assert!(matches!(findings[0].severity, Severity::Low));
+        assert_eq!(findings[0].message, "setTimeout with a zero delay");
+        assert_eq!(findings[1].message, "setInterval with a zero delay");
+        assert_eq!(findings[1].location.line, 2);
+    }
+
+    #[test]
+    fn test_rule_is_opt_in() {
+        assert!(check_source(&ZeroDelayTimerRule, "timers.js", "setTimeout(cb, 0);\n").is_empty());
+    }
+}
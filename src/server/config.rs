@@ -0,0 +1,143 @@
+use serde::Serialize;
+use std::env;
+use tracing::warn;
+
+/// Runtime settings for the HTTP server, resolved from the environment.
+///
+/// The whole struct is reported by `GET /config`, so anything secret added
+/// here must be marked `#[serde(skip_serializing)]`.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerConfig {
+    pub bind_addr: String,
+    /// Origins allowed to make cross-origin requests. `None` keeps the
+    /// permissive CORS policy used for local development.
+    pub cors_allowed_origins: Option<Vec<String>>,
+    /// Maximum number of files accepted in one analysis request, and
+    /// retained by one session.
+    pub max_files: usize,
+    /// Maximum size of a single file's content, in bytes.
+    pub max_file_size_bytes: usize,
+    /// Serialized size above which buffered JSON analysis responses
+    /// (`/analyze` and async callbacks) drop trailing file results and are
+    /// marked `truncated`. `/analyze/stream` is never truncated.
+    pub max_response_bytes: usize,
+    /// Key for the `X-Signature-256` HMAC on `/analyze/async` callbacks.
+    /// Callbacks are unsigned when unset.
+    #[serde(skip_serializing)]
+    pub callback_secret: Option<String>,
+    /// Delivery attempts per callback before giving up.
+    pub callback_max_attempts: u32,
+    /// Hosts `/analyze/async` callbacks may target, including ones on the
+    /// internal network. `None` accepts any host that resolves only to
+    /// public addresses.
+    pub callback_allowed_hosts: Option<Vec<String>>,
+    /// `/analyze/async` and `/cache/preload` jobs running at once; requests
+    /// over the limit get a 429.
+    pub max_async_jobs: usize,
+    /// Worker threads for the parallel parts of cross-file passes. `0`
+    /// uses one per CPU core.
+    pub analysis_threads: usize,
+    /// Seconds a `POST /session` session may sit idle before it is dropped.
+    pub session_ttl_secs: u64,
+    /// Sessions open at once; opening another closes the least recently
+    /// used.
+    pub max_sessions: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind_addr: "0.0.0.0:8080".to_string(),
+            cors_allowed_origins: None,
+            max_files: 100,
+            max_file_size_bytes: 1024 * 1024,
+            max_response_bytes: 64 * 1024 * 1024,
+            callback_secret: None,
+            callback_max_attempts: 3,
+            callback_allowed_hosts: None,
+            max_async_jobs: 16,
+            analysis_threads: 0,
+            session_ttl_secs: 30 * 60,
+            max_sessions: 100,
+        }
+    }
+}
+
+/// `GET /config` body: the settings plus which protections are active.
+#[derive(Debug, Serialize)]
+pub struct ConfigReport {
+    #[serde(flatten)]
+    pub settings: ServerConfig,
+    /// Whether requests must authenticate. The server has no
+    /// authentication of its own, so this is false; put it behind a proxy
+    /// that authenticates when exposing it.
+    pub auth_enabled: bool,
+    /// Whether requests are rate limited. Like authentication, this is left
+    /// to a proxy, so it is false.
+    pub rate_limiting_enabled: bool,
+    /// Whether `/analyze/async` callbacks are signed, i.e. a callback
+    /// secret is set. The secret itself is never reported.
+    pub callback_signing_enabled: bool,
+}
+
+impl From<&ServerConfig> for ConfigReport {
+    fn from(config: &ServerConfig) -> Self {
+        Self {
+            settings: config.clone(),
+            auth_enabled: false,
+            rate_limiting_enabled: false,
+            callback_signing_enabled: config.callback_secret.is_some(),
+        }
+    }
+}
+
+impl ServerConfig {
+    /// Builds a config from `RAE_*` environment variables, falling back to
+    /// the defaults for anything unset.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        Self {
+            bind_addr: env::var("RAE_BIND_ADDR").unwrap_or(defaults.bind_addr),
+            cors_allowed_origins: env::var("RAE_CORS_ALLOWED_ORIGINS")
+                .ok()
+                .map(|value| parse_list(&value)),
+            max_files: parse_count("RAE_MAX_FILES").unwrap_or(defaults.max_files),
+            max_file_size_bytes: parse_count("RAE_MAX_FILE_SIZE").unwrap_or(defaults.max_file_size_bytes),
+            max_response_bytes: parse_count("RAE_MAX_RESPONSE_BYTES").unwrap_or(defaults.max_response_bytes),
+            callback_secret: env::var("RAE_CALLBACK_SECRET").ok().filter(|secret| !secret.is_empty()),
+            callback_max_attempts: parse_count("RAE_CALLBACK_MAX_ATTEMPTS")
+                .map(|attempts| attempts as u32)
+                .unwrap_or(defaults.callback_max_attempts),
+            callback_allowed_hosts: env::var("RAE_CALLBACK_ALLOWED_HOSTS")
+                .ok()
+                .map(|value| parse_list(&value)),
+            max_async_jobs: parse_count("RAE_MAX_ASYNC_JOBS").unwrap_or(defaults.max_async_jobs),
+            analysis_threads: parse_count("RAE_ANALYSIS_THREADS").unwrap_or(defaults.analysis_threads),
+            session_ttl_secs: parse_count("RAE_SESSION_TTL_SECS")
+                .map(|secs| secs as u64)
+                .unwrap_or(defaults.session_ttl_secs),
+            max_sessions: parse_count("RAE_MAX_SESSIONS").unwrap_or(defaults.max_sessions),
+        }
+    }
+}
+
+fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_count(name: &str) -> Option<usize> {
+    let value = env::var(name).ok()?;
+    match value.trim().parse() {
+        Ok(count) => Some(count),
+        Err(_) => {
+            warn!("Ignoring invalid {}: {}", name, value);
+            None
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Prometheus text exposition for `GET /metrics`.
+
+use std::fmt::Write;
+
+use crate::analysis::AnalysisEngine;
+
+pub const CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+pub fn render(engine: &AnalysisEngine) -> String {
+    let mut out = String::new();
+
+    out.push_str("# HELP parses_total Files parsed, by language.\n");
+    out.push_str("# TYPE parses_total counter\n");
+    for (language, count) in engine.parse_counts() {
+        let _ = writeln!(out, "parses_total{{language=\"{:?}\"}} {}", language, count);
+    }
+
+    if let Some(cache) = engine.cache() {
+        out.push_str("# HELP cache_hits_total Files answered from the disk cache.\n");
+        out.push_str("# TYPE cache_hits_total counter\n");
+        let _ = writeln!(out, "cache_hits_total {}", cache.hits());
+        out.push_str("# HELP cache_misses_total Disk cache lookups without a usable entry.\n");
+        out.push_str("# TYPE cache_misses_total counter\n");
+        let _ = writeln!(out, "cache_misses_total {}", cache.misses());
+    }
+
+    out
+}
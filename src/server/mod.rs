@@ -1,42 +1,104 @@
 use axum::{
-    extract::State,
-    http::StatusCode,
-    response::Json,
-    routing::{get, post},
+    body::Body,
+    extract::{Path, Query, State},
+    http::{header, HeaderValue, Method, StatusCode},
+    middleware,
+    response::{IntoResponse, Json, Response},
+    routing::{delete, get, post},
     Router,
 };
+use serde::Deserialize;
 use serde_json::json;
 use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tower::ServiceBuilder;
-use tower_http::{cors::CorsLayer, trace::TraceLayer};
-use tracing::{info, instrument};
+use tower_http::{
+    cors::{AllowOrigin, CorsLayer},
+    trace::TraceLayer,
+};
+use tracing::{info, instrument, warn, Instrument};
 
 use crate::{
-    analysis::AnalysisEngine,
+    analysis::{AnalysisEngine, SessionLimits},
     error::{AnalysisError, AnalysisResult},
-    types::{AnalysisRequest, AnalysisResponse},
+    github,
+    types::{
+        AnalysisRequest, AnalysisResponse, ApiSurfaceRequest, ApiSurfaceResponse, AsyncAnalysisRequest, AsyncAnalysisResponse, CachePreloadRequest, CachePreloadResponse, ConfigValidation, MinimalAnalysisResponse, MultiConfigRequest, MultiConfigResponse, ParseCheckRequest,
+        ParseCheckResponse, RuleConfig, RuleDescriptor, SessionResponse, SourceFile,
+    },
 };
 
+pub mod config;
+pub mod metrics;
+pub mod options;
+pub mod request_context;
+pub mod stream;
+pub mod truncate;
+pub mod webhook;
+
+pub use config::{ConfigReport, ServerConfig};
+use options::{OutputFormat, ResponseOptions};
+
+const CALLBACK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
 pub struct Server {
     engine: Arc<AnalysisEngine>,
+    config: ServerConfig,
+}
+
+/// Shared state handed to every handler.
+#[derive(Clone)]
+struct AppState {
+    engine: Arc<AnalysisEngine>,
+    config: Arc<ServerConfig>,
+    callback_policy: webhook::CallbackPolicy,
+    /// One permit per `/analyze/async` or `/cache/preload` job in flight.
+    async_jobs: Arc<Semaphore>,
 }
 
 impl Server {
     pub async fn new() -> AnalysisResult<Self> {
-        let engine = Arc::new(AnalysisEngine::new().await?);
-        Ok(Self { engine })
+        Self::with_config(ServerConfig::from_env()).await
+    }
+
+    pub async fn with_config(config: ServerConfig) -> AnalysisResult<Self> {
+        Ok(Self::with_engine(AnalysisEngine::new().await?, config))
+    }
+
+    /// A server around an already configured engine, e.g. one with a
+    /// specific disk cache.
+    pub fn with_engine(mut engine: AnalysisEngine, config: ServerConfig) -> Self {
+        engine.set_session_limits(SessionLimits {
+            ttl: std::time::Duration::from_secs(config.session_ttl_secs),
+            max_sessions: config.max_sessions,
+            max_files: config.max_files,
+        });
+        Self {
+            engine: Arc::new(engine),
+            config,
+        }
     }
 
     pub async fn run(self) -> AnalysisResult<()> {
+        let bind_addr = self.config.bind_addr.clone();
+        if self.config.analysis_threads > 0 {
+            // Only the first pool configured in a process takes effect
+            if let Err(e) = rayon::ThreadPoolBuilder::new()
+                .num_threads(self.config.analysis_threads)
+                .build_global()
+            {
+                warn!("Ignoring RAE_ANALYSIS_THREADS: {}", e);
+            }
+        }
         let app = self.create_router();
         
-        let listener = tokio::net::TcpListener::bind("0.0.0.0:8080")
+        let listener = tokio::net::TcpListener::bind(&bind_addr)
             .await
             .map_err(|e| AnalysisError::InternalError {
-                message: format!("Failed to bind to port 8080: {}", e),
+                message: format!("Failed to bind to {}: {}", bind_addr, e),
             })?;
 
-        info!("Server starting on http://0.0.0.0:8080");
+        info!("Server starting on http://{}", bind_addr);
         
         axum::serve(listener, app)
             .await
@@ -47,20 +109,62 @@ impl Server {
         Ok(())
     }
 
-    fn create_router(self) -> Router {
+    pub fn create_router(self) -> Router {
         Router::new()
+            .route("/analyze", post(analyze_handler))
+            .route("/analyze/stream", post(analyze_stream_handler))
+            .route("/analyze/multi-config", post(analyze_multi_config_handler))
+            .route("/analyze/async", post(analyze_async_handler))
+            .route("/session", post(session_handler))
+            .route("/session/:id", delete(close_session_handler))
+            .route("/parse/check", post(parse_check_handler))
+            .route("/api-surface", post(api_surface_handler))
+            .route("/rules/validate", post(validate_rules_handler))
+            .route("/cache/preload", post(cache_preload_handler))
+            .route_layer(middleware::from_fn(request_context::request_context))
             .route("/", get(health_check))
             .route("/health", get(health_check))
-            .route("/analyze", post(analyze_handler))
+            .route("/config", get(config_handler))
+            .route("/schema", get(schema_handler))
+            .route("/rules", get(rules_handler))
+            .route("/metrics", get(metrics_handler))
             .layer(
                 ServiceBuilder::new()
                     .layer(TraceLayer::new_for_http())
-                    .layer(CorsLayer::permissive()),
+                    .layer(cors_layer(&self.config)),
             )
-            .with_state(self.engine)
+            .with_state(AppState {
+                engine: self.engine,
+                callback_policy: webhook::CallbackPolicy::new(self.config.callback_allowed_hosts.clone()),
+                async_jobs: Arc::new(Semaphore::new(self.config.max_async_jobs)),
+                config: Arc::new(self.config),
+            })
     }
 }
 
+fn cors_layer(config: &ServerConfig) -> CorsLayer {
+    let Some(origins) = &config.cors_allowed_origins else {
+        return CorsLayer::permissive();
+    };
+
+    let origins: Vec<HeaderValue> = origins
+        .iter()
+        .filter_map(|origin| match origin.parse() {
+            Ok(value) => Some(value),
+            Err(_) => {
+                warn!("Ignoring invalid CORS origin: {}", origin);
+                None
+            }
+        })
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods([Method::GET, Method::POST, Method::DELETE])
+        .allow_headers([header::CONTENT_TYPE, request_context::REQUEST_ID])
+        .expose_headers([request_context::REQUEST_ID, request_context::SERVER_TIMING])
+}
+
 #[instrument]
 async fn health_check() -> Json<serde_json::Value> {
     Json(json!({
@@ -70,57 +174,384 @@ async fn health_check() -> Json<serde_json::Value> {
     }))
 }
 
-#[instrument(skip(engine, request))]
+/// The effective server configuration, for debugging deployments.
+#[instrument(skip(state))]
+async fn config_handler(State(state): State<AppState>) -> Json<ConfigReport> {
+    Json(ConfigReport::from(state.config.as_ref()))
+}
+
+/// Every rule the engine runs, with its rationale.
+#[instrument(skip(state))]
+async fn rules_handler(State(state): State<AppState>) -> Json<Vec<RuleDescriptor>> {
+    Json(state.engine.rules())
+}
+
+/// JSON Schema for the `/analyze` request and response bodies, derived from
+/// the serde types so generated client bindings stay in sync.
+async fn schema_handler() -> Json<serde_json::Value> {
+    Json(json!({
+        "request": schemars::schema_for!(AnalysisRequest),
+        "response": schemars::schema_for!(AnalysisResponse),
+    }))
+}
+
+#[instrument(skip(state))]
+async fn metrics_handler(State(state): State<AppState>) -> Response {
+    ([(header::CONTENT_TYPE, metrics::CONTENT_TYPE)], metrics::render(&state.engine)).into_response()
+}
+
+/// Query parameters accepted by `/analyze`, besides `ResponseOptions`.
+#[derive(Debug, Deserialize)]
+struct AnalyzeParams {
+    /// Token from `POST /session`; the request's files update that session.
+    session: Option<String>,
+}
+
+#[instrument(skip(state, request))]
 async fn analyze_handler(
-    State(engine): State<Arc<AnalysisEngine>>,
-    Json(request): Json<AnalysisRequest>,
-) -> AnalysisResult<Json<AnalysisResponse>> {
+    State(state): State<AppState>,
+    Query(params): Query<AnalyzeParams>,
+    Query(options): Query<ResponseOptions>,
+    Json(mut request): Json<AnalysisRequest>,
+) -> AnalysisResult<Response> {
+    let format = options.format()?;
+    options.apply(&mut request.rules)?;
+
     // Validate request
-    validate_request(&request)?;
+    validate_request(&request, &state)?;
+    let minimal = is_minimal(&request);
+    validate_format(format, minimal)?;
     
     // Perform analysis
-    let response = engine.analyze(request).await?;
+    let mut response = match &params.session {
+        Some(token) => state.engine.analyze_session(token, request).await?,
+        None => state.engine.analyze(request).await?,
+    };
     
+    if format == OutputFormat::Github {
+        return Ok(([(header::CONTENT_TYPE, github::CONTENT_TYPE)], github::render(&response)).into_response());
+    }
+    if minimal {
+        return Ok(Json(MinimalAnalysisResponse::from(response)).into_response());
+    }
+
+    truncate::fit_to_budget(&mut response, state.config.max_response_bytes);
+
+    Ok(Json(response).into_response())
+}
+
+/// Opens a session whose files persist across `/analyze?session=` calls, so
+/// an editor can send only what changed.
+#[instrument(skip(state))]
+async fn session_handler(State(state): State<AppState>) -> Json<SessionResponse> {
+    let session = request_context::generate_id();
+    state.engine.open_session(&session);
+    info!("Opened session {}", session);
+
+    Json(SessionResponse { session })
+}
+
+/// Closes a session, freeing the files it retains.
+#[instrument(skip(state))]
+async fn close_session_handler(State(state): State<AppState>, Path(session): Path<String>) -> AnalysisResult<StatusCode> {
+    if !state.engine.close_session(&session) {
+        return Err(AnalysisError::NotFound {
+            resource: format!("session {}", session),
+        });
+    }
+    info!("Closed session {}", session);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Same as `/analyze`, but the JSON body is written incrementally with
+/// chunked transfer encoding instead of being buffered in full. Measuring
+/// the body would mean serializing all of it up front, so
+/// `max_response_bytes` doesn't apply here.
+#[instrument(skip(state, request))]
+async fn analyze_stream_handler(
+    State(state): State<AppState>,
+    Query(options): Query<ResponseOptions>,
+    Json(mut request): Json<AnalysisRequest>,
+) -> AnalysisResult<Response> {
+    let format = options.format()?;
+    options.apply(&mut request.rules)?;
+    validate_request(&request, &state)?;
+    let minimal = is_minimal(&request);
+    validate_format(format, minimal)?;
+
+    let response = state.engine.analyze(request).await?;
+    if format == OutputFormat::Github {
+        return Ok(([(header::CONTENT_TYPE, github::CONTENT_TYPE)], github::render(&response)).into_response());
+    }
+    if minimal {
+        return Ok(Json(MinimalAnalysisResponse::from(response)).into_response());
+    }
+    let body = Body::from_stream(futures::stream::iter(stream::response_chunks(response)));
+
+    Ok(([(header::CONTENT_TYPE, "application/json")], body).into_response())
+}
+
+/// Accepts an analysis, returns a job id straight away, and POSTs the
+/// result to the request's callback URL when it is done. The URL must pass
+/// the server's `CallbackPolicy`, and at most `max_async_jobs` jobs run at
+/// once.
+#[instrument(skip(state, request))]
+async fn analyze_async_handler(
+    State(state): State<AppState>,
+    Json(request): Json<AsyncAnalysisRequest>,
+) -> AnalysisResult<(StatusCode, Json<AsyncAnalysisResponse>)> {
+    let AsyncAnalysisRequest {
+        files,
+        rules,
+        callback_url,
+    } = request;
+    let request = AnalysisRequest { files, rules };
+    validate_request(&request, &state)?;
+    let target = state
+        .callback_policy
+        .check(&callback_url)
+        .await
+        .map_err(|message| AnalysisError::ValidationError { message }.at_field("callback_url"))?;
+    let permit = acquire_job_permit(&state)?;
+
+    let job_id = request_context::generate_id();
+    let span = tracing::info_span!("analyze_async", job_id = %job_id);
+    let response_job_id = job_id.clone();
+    tokio::spawn(
+        async move {
+            let mut response = match state.engine.analyze(request).await {
+                Ok(response) => response,
+                Err(e) => {
+                    warn!("Async analysis failed: {}", e);
+                    return;
+                }
+            };
+            truncate::fit_to_budget(&mut response, state.config.max_response_bytes);
+            let body = match serde_json::to_vec(&response) {
+                Ok(body) => body,
+                Err(e) => {
+                    warn!("Failed to serialize async analysis result: {}", e);
+                    return;
+                }
+            };
+
+            let delivery = webhook::Delivery {
+                url: &callback_url,
+                job_id: &job_id,
+                body,
+                secret: state.config.callback_secret.as_deref(),
+                max_attempts: state.config.callback_max_attempts,
+            };
+            webhook::deliver(&target.client(CALLBACK_TIMEOUT), delivery).await;
+            drop(permit);
+        }
+        .instrument(span),
+    );
+
+    Ok((StatusCode::ACCEPTED, Json(AsyncAnalysisResponse { job_id: response_job_id })))
+}
+
+/// Runs the same files under every config in the request and returns one
+/// summary per config.
+#[instrument(skip(state, request))]
+async fn analyze_multi_config_handler(
+    State(state): State<AppState>,
+    Json(request): Json<MultiConfigRequest>,
+) -> AnalysisResult<Json<MultiConfigResponse>> {
+    const MAX_CONFIGS: usize = 10;
+
+    validate_files(&request.files, &state)?;
+    if request.configs.is_empty() || request.configs.len() > MAX_CONFIGS {
+        return Err(AnalysisError::ValidationError {
+            message: format!("Between 1 and {} configs must be provided, got {}", MAX_CONFIGS, request.configs.len()),
+        }
+        .at_field("configs"));
+    }
+    for (index, config) in request.configs.iter().enumerate() {
+        validate_config(config, &state).map_err(|e| e.at_field(format!("configs[{}]", index)))?;
+    }
+
+    let response = state.engine.analyze_multi_config(request).await?;
+
+    Ok(Json(response))
+}
+
+/// API inventory: the exported functions and classes of each file, with
+/// signatures, instead of findings.
+#[instrument(skip(state, request))]
+async fn api_surface_handler(
+    State(state): State<AppState>,
+    Json(request): Json<ApiSurfaceRequest>,
+) -> AnalysisResult<Json<ApiSurfaceResponse>> {
+    validate_files(&request.files, &state)?;
+
+    let response = state.engine.api_surface(request).await?;
+
     Ok(Json(response))
 }
 
-fn validate_request(request: &AnalysisRequest) -> AnalysisResult<()> {
-    const MAX_FILE_SIZE: usize = 1024 * 1024; // 1MB per file
-    const MAX_FILES: usize = 100;
+/// Syntax-only check: reports whether each file parses, without computing
+/// metrics or running rules.
+#[instrument(skip(state, request))]
+async fn parse_check_handler(
+    State(state): State<AppState>,
+    Json(request): Json<ParseCheckRequest>,
+) -> AnalysisResult<Json<ParseCheckResponse>> {
+    validate_files(&request.files, &state)?;
 
-    if request.files.is_empty() {
+    let response = state.engine.check_parse(request).await?;
+
+    Ok(Json(response))
+}
+
+/// Analyzes the files in the background to fill the disk cache and returns
+/// straight away with a job id. Preloads count towards `max_async_jobs`.
+#[instrument(skip(state, request))]
+async fn cache_preload_handler(
+    State(state): State<AppState>,
+    Json(request): Json<CachePreloadRequest>,
+) -> AnalysisResult<(StatusCode, Json<CachePreloadResponse>)> {
+    validate_files(&request.files, &state)?;
+    if let Some(config) = &request.rules {
+        validate_config(config, &state).map_err(|e| e.at_field("rules"))?;
+    }
+    if state.engine.cache().is_none() {
         return Err(AnalysisError::ValidationError {
-            message: "At least one file must be provided".to_string(),
+            message: "Preloading requires the disk cache; set RAE_CACHE_DIR".to_string(),
         });
     }
 
-    if request.files.len() > MAX_FILES {
+    let permit = acquire_job_permit(&state)?;
+
+    let job_id = request_context::generate_id();
+    let files = request.files.len();
+    let engine = state.engine.clone();
+    let span = tracing::info_span!("cache_preload", job_id = %job_id);
+    tokio::spawn(
+        async move {
+            let request = AnalysisRequest {
+                files: request.files,
+                rules: request.rules,
+            };
+            match engine.analyze(request).await {
+                Ok(_) => info!("Preloaded {} files", files),
+                Err(e) => warn!("Cache preload failed: {}", e),
+            }
+            drop(permit);
+        }
+        .instrument(span),
+    );
+
+    Ok((StatusCode::ACCEPTED, Json(CachePreloadResponse { job_id, files })))
+}
+
+/// Reports every problem with a rule config without analyzing anything.
+#[instrument(skip(state, config))]
+async fn validate_rules_handler(
+    State(state): State<AppState>,
+    Json(config): Json<RuleConfig>,
+) -> Json<ConfigValidation> {
+    Json(state.engine.validate_config(&config))
+}
+
+/// A slot for one background job (`/analyze/async` or `/cache/preload`),
+/// held until the job finishes.
+fn acquire_job_permit(state: &AppState) -> AnalysisResult<OwnedSemaphorePermit> {
+    state
+        .async_jobs
+        .clone()
+        .try_acquire_owned()
+        .map_err(|_| AnalysisError::TooManyRequests {
+            resource: "background jobs".to_string(),
+        })
+}
+
+/// Whether the client asked for the summary alone.
+fn is_minimal(request: &AnalysisRequest) -> bool {
+    request
+        .rules
+        .as_ref()
+        .and_then(|rules| rules.minimal_response)
+        .unwrap_or(false)
+}
+
+/// A minimal response drops every finding, leaving GitHub annotations
+/// nothing to render.
+fn validate_format(format: OutputFormat, minimal: bool) -> AnalysisResult<()> {
+    if format == OutputFormat::Github && minimal {
+        return Err(AnalysisError::ValidationError {
+            message: "minimal_response can't be combined with format=github".to_string(),
+        }
+        .at_field("rules.minimal_response"));
+    }
+    Ok(())
+}
+
+fn validate_request(request: &AnalysisRequest, state: &AppState) -> AnalysisResult<()> {
+    validate_files(&request.files, state)?;
+    match &request.rules {
+        Some(config) => validate_config(config, state).map_err(|e| e.at_field("rules")),
+        None => Ok(()),
+    }
+}
+
+fn validate_config(config: &RuleConfig, state: &AppState) -> AnalysisResult<()> {
+    let validation = state.engine.validate_config(config);
+    if validation.valid {
+        return Ok(());
+    }
+
+    Err(AnalysisError::ValidationError {
+        message: format!("Invalid rule config: {}", validation.errors.join("; ")),
+    })
+}
+
+fn validate_files(files: &[SourceFile], state: &AppState) -> AnalysisResult<()> {
+    let config = &state.config;
+    if files.is_empty() {
         return Err(AnalysisError::ValidationError {
-            message: format!("Too many files: {} (max: {})", request.files.len(), MAX_FILES),
-        });
+            message: "At least one file must be provided".to_string(),
+        }
+        .at_field("files"));
+    }
+
+    if files.len() > config.max_files {
+        return Err(AnalysisError::ValidationError {
+            message: format!("Too many files: {} (max: {})", files.len(), config.max_files),
+        }
+        .at_field("files"));
     }
 
-    for file in &request.files {
+    for (index, file) in files.iter().enumerate() {
         if file.name.is_empty() {
             return Err(AnalysisError::ValidationError {
                 message: "File name cannot be empty".to_string(),
-            });
+            }
+            .at_field(format!("files[{}].name", index)));
         }
 
-        if file.content.len() > MAX_FILE_SIZE {
+        if file.content.len() > config.max_file_size_bytes {
             return Err(AnalysisError::FileTooLarge {
                 size_bytes: file.content.len(),
-                limit_bytes: MAX_FILE_SIZE,
-            });
+                limit_bytes: config.max_file_size_bytes,
+            }
+            .at_field(format!("files[{}].content", index)));
         }
 
         // Detect language if not provided
-        if file.language.is_none() {
-            if crate::types::Language::from_filename(&file.name).is_none() {
-                return Err(AnalysisError::UnsupportedLanguage {
-                    language: file.name.split('.').last().unwrap_or("unknown").to_string(),
-                });
+        if file.language.is_none() && crate::types::Language::from_filename(&file.name).is_none() {
+            return Err(AnalysisError::UnsupportedLanguage {
+                language: file.name.split('.').next_back().unwrap_or("unknown").to_string(),
+            }
+            .at_field(format!("files[{}].name", index)));
+        }
+
+        if let Some(parser) = file.parser_override.as_ref().filter(|parser| !state.engine.has_parser(parser)) {
+            return Err(AnalysisError::UnsupportedLanguage {
+                language: format!("{:?}", parser),
             }
+            .at_field(format!("files[{}].parser_override", index)));
         }
     }
 
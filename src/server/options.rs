@@ -0,0 +1,115 @@
+//! Output options shared by the analysis endpoints' query strings, as in
+//! `/analyze?format=github&min_severity=high&sort=true&flatten=true`.
+//!
+//! Filters and ordering map onto the matching `RuleConfig` fields, taking
+//! precedence over the request body, so every output format sees the same
+//! findings.
+
+use serde::Deserialize;
+
+use crate::error::{AnalysisError, AnalysisResult};
+use crate::types::{RuleConfig, Severity};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ResponseOptions {
+    /// `json` (the default) or `github` for GitHub Actions workflow commands.
+    pub format: Option<String>,
+    /// `low`, `medium`, `high` or `critical`; overrides `RuleConfig.min_severity`.
+    pub min_severity: Option<String>,
+    /// Overrides `RuleConfig.sort_findings`.
+    pub sort: Option<bool>,
+    /// Overrides `RuleConfig.flatten_findings`.
+    pub flatten: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Json,
+    Github,
+}
+
+impl ResponseOptions {
+    pub fn format(&self) -> AnalysisResult<OutputFormat> {
+        match self.format.as_deref().unwrap_or("json") {
+            "json" => Ok(OutputFormat::Json),
+            "github" => Ok(OutputFormat::Github),
+            other => Err(AnalysisError::ValidationError {
+                message: format!("Unsupported format: {} (expected json or github)", other),
+            }),
+        }
+    }
+
+    /// Writes the options into `rules`, creating a default config if the
+    /// request had none.
+    pub fn apply(&self, rules: &mut Option<RuleConfig>) -> AnalysisResult<()> {
+        let min_severity = self.min_severity.as_deref().map(parse_severity).transpose()?;
+        if min_severity.is_none() && self.sort.is_none() && self.flatten.is_none() {
+            return Ok(());
+        }
+
+        let config = rules.get_or_insert_with(RuleConfig::default);
+        if min_severity.is_some() {
+            config.min_severity = min_severity;
+        }
+        if self.sort.is_some() {
+            config.sort_findings = self.sort;
+        }
+        if self.flatten.is_some() {
+            config.flatten_findings = self.flatten;
+        }
+        Ok(())
+    }
+}
+
+fn parse_severity(name: &str) -> AnalysisResult<Severity> {
+    match name.to_ascii_lowercase().as_str() {
+        "low" => Ok(Severity::Low),
+        "medium" => Ok(Severity::Medium),
+        "high" => Ok(Severity::High),
+        "critical" => Ok(Severity::Critical),
+        _ => Err(AnalysisError::ValidationError {
+            message: format!("Unsupported min_severity: {} (expected low, medium, high or critical)", name),
+        }
+        .at_field("min_severity")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_override_request_config() {
+        let options = ResponseOptions {
+            min_severity: Some("HIGH".to_string()),
+            flatten: Some(true),
+            ..Default::default()
+        };
+        let mut rules = Some(RuleConfig {
+            min_severity: Some(Severity::Low),
+            flatten_findings: Some(false),
+            sort_findings: Some(true),
+            ..Default::default()
+        });
+
+        options.apply(&mut rules).unwrap();
+
+        let rules = rules.unwrap();
+        assert_eq!(rules.min_severity, Some(Severity::High));
+        assert_eq!(rules.flatten_findings, Some(true));
+        assert_eq!(rules.sort_findings, Some(true));
+    }
+
+    #[test]
+    fn test_invalid_options_are_rejected() {
+        let options = ResponseOptions {
+            format: Some("sarif".to_string()),
+            min_severity: Some("severe".to_string()),
+            ..Default::default()
+        };
+
+        assert!(options.format().is_err());
+        assert!(options.apply(&mut None).is_err());
+        assert!(ResponseOptions::default().apply(&mut None).is_ok());
+    }
+}
@@ -0,0 +1,65 @@
+//! Per-request tracing context for the analysis routes.
+//!
+//! Accepts an incoming `X-Request-Id` (or generates one), records it on the
+//! request's tracing span, and echoes it back together with a
+//! `Server-Timing: analyze;dur=<ms>` header.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use tracing::{info_span, Instrument};
+
+pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+pub const SERVER_TIMING: HeaderName = HeaderName::from_static("server-timing");
+
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+pub async fn request_context(request: Request, next: Next) -> Response {
+    let request_id = request
+        .headers()
+        .get(&REQUEST_ID)
+        .filter(|value| !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN)
+        .cloned()
+        .unwrap_or_else(generate_request_id);
+
+    let span = info_span!(
+        "request",
+        request_id = request_id.to_str().unwrap_or_default(),
+        method = %request.method(),
+        uri = %request.uri(),
+    );
+
+    let start = Instant::now();
+    let mut response = next.run(request).instrument(span).await;
+    let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let headers = response.headers_mut();
+    headers.insert(REQUEST_ID, request_id);
+    if let Ok(timing) = HeaderValue::from_str(&format!("analyze;dur={:.1}", elapsed_ms)) {
+        headers.insert(SERVER_TIMING, timing);
+    }
+
+    response
+}
+
+fn generate_request_id() -> HeaderValue {
+    HeaderValue::from_str(&generate_id()).expect("hex request id is a valid header value")
+}
+
+/// Time-based id with a process-wide counter so ids stay unique under load.
+pub(crate) fn generate_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or_default();
+    let sequence = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("{:016x}-{:08x}", nanos, sequence as u32)
+}
@@ -0,0 +1,178 @@
+//! Incremental JSON serialization of an `AnalysisResponse`.
+//!
+//! The response is written as a single well-formed JSON document, but each
+//! finding is serialized into its own chunk so large result sets never need
+//! a fully materialized body buffer.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::iter;
+
+use crate::types::{AnalysisResponse, FileAnalysisResult};
+
+type Chunk = Result<Vec<u8>, serde_json::Error>;
+
+/// Splits `response` into JSON chunks whose concatenation is equivalent to
+/// serializing the whole response at once.
+pub fn response_chunks(mut response: AnalysisResponse) -> impl Iterator<Item = Chunk> + Send {
+    let results = std::mem::take(&mut response.results);
+    let header = open_object(&response, "results");
+
+    iter::once(header)
+        .chain(
+            results
+                .into_iter()
+                .enumerate()
+                .flat_map(|(index, result)| file_chunks(index > 0, result)),
+        )
+        .chain(iter::once(Ok(b"]}".to_vec())))
+}
+
+fn file_chunks(leading_comma: bool, mut result: FileAnalysisResult) -> impl Iterator<Item = Chunk> {
+    let findings = std::mem::take(&mut result.findings);
+    let header = open_object(&result, "findings").map(|chunk| {
+        if leading_comma {
+            [b",".as_slice(), &chunk].concat()
+        } else {
+            chunk
+        }
+    });
+
+    iter::once(header)
+        .chain(findings.into_iter().enumerate().map(|(index, finding)| {
+            let mut chunk = if index > 0 { b",".to_vec() } else { Vec::new() };
+            serde_json::to_writer(&mut chunk, &finding)?;
+            Ok(chunk)
+        }))
+        .chain(iter::once(Ok(b"]}".to_vec())))
+}
+
+/// Writes `{` followed by every field of `value` except `array_key`, then
+/// opens `array_key` as an array for the caller to fill.
+fn open_object(value: &impl Serialize, array_key: &str) -> Chunk {
+    let mut chunk = b"{".to_vec();
+
+    if let Value::Object(fields) = serde_json::to_value(value)? {
+        for (key, field) in fields.iter().filter(|(key, _)| key.as_str() != array_key) {
+            serde_json::to_writer(&mut chunk, key)?;
+            chunk.push(b':');
+            serde_json::to_writer(&mut chunk, field)?;
+            chunk.push(b',');
+        }
+    }
+
+    serde_json::to_writer(&mut chunk, array_key)?;
+    chunk.extend_from_slice(b":[");
+    Ok(chunk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        AnalysisSummary, FileMetrics, Finding, Language, Location, Severity,
+    };
+    use std::collections::HashMap;
+
+    fn finding(line: u32) -> Finding {
+        Finding::new(
+            "test-rule",
+            Severity::Low,
+            format!("finding on line {}", line),
+            Location {
+                line,
+                column: 1,
+                end_line: None,
+                end_column: None,
+            },
+        )
+    }
+
+    fn file_result(name: &str, findings: Vec<Finding>) -> FileAnalysisResult {
+        FileAnalysisResult {
+            file_name: name.to_string(),
+            language: Language::JavaScript,
+            findings,
+            metrics: FileMetrics {
+                lines_of_code: 10,
+                functions_count: 2,
+                classes_count: 0,
+                complexity_score: 1.0,
+                todo_density: 0.0,
+                iife_count: 0,
+                longest_regex: 0,
+                class_cohesion: Vec::new(),
+                halstead: None,
+            },
+            rules_executed: vec!["test-rule".to_string()],
+            rules_skipped: Vec::new(),
+            coupling: Default::default(),
+            timing_breakdown: None,
+        }
+    }
+
+    fn response(results: Vec<FileAnalysisResult>) -> AnalysisResponse {
+        AnalysisResponse {
+            results,
+            summary: AnalysisSummary {
+                total_files: 2,
+                total_findings: 3,
+                findings_by_severity: HashMap::from([("Low".to_string(), 3)]),
+                total_lines_analyzed: 20,
+                budget_exceeded: false,
+                files_skipped: 0,
+                unsupported_files: Vec::new(),
+                truncated: false,
+                truncation_reason: None,
+                findings_by_directory: None,
+                sampled: false,
+                sample_size: None,
+                languages_analyzed: HashMap::new(),
+                lines_by_language: HashMap::new(),
+            },
+            execution_time_ms: 7,
+            all_findings: None,
+            deduped_findings: None,
+        }
+    }
+
+    fn stream_to_value(response: AnalysisResponse) -> Value {
+        let body: Vec<u8> = response_chunks(response)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+            .concat();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn test_streamed_body_matches_buffered_serialization() {
+        let build = || {
+            response(vec![
+                file_result("a.js", vec![finding(1), finding(2)]),
+                file_result("b.js", vec![finding(3)]),
+            ])
+        };
+
+        let buffered = serde_json::to_value(build()).unwrap();
+        let streamed = stream_to_value(build());
+
+        assert_eq!(streamed, buffered);
+    }
+
+    #[test]
+    fn test_streams_one_chunk_per_finding() {
+        let findings = (1..=100).map(finding).collect();
+        let chunks: Vec<_> = response_chunks(response(vec![file_result("big.js", findings)])).collect();
+
+        // header + file header + 100 findings + file close + response close
+        assert_eq!(chunks.len(), 104);
+    }
+
+    #[test]
+    fn test_streams_empty_results() {
+        let streamed = stream_to_value(response(Vec::new()));
+
+        assert_eq!(streamed["results"], serde_json::json!([]));
+        assert_eq!(streamed["execution_time_ms"], 7);
+    }
+}
@@ -0,0 +1,284 @@
+//! Keeps buffered JSON analysis responses under
+//! `ServerConfig.max_response_bytes`. Streamed responses aren't measured.
+//!
+//! Whole file results are dropped from the end of `results` until the
+//! serialized response fits, so the body stays valid JSON and every result
+//! that is kept is complete. `all_findings` and `deduped_findings`, when
+//! present, are cut down to the kept files. The summary still describes
+//! the full analysis.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::types::{AnalysisResponse, DedupedFinding, FileAnalysisResult, FindingWithFile};
+
+/// Drops trailing results until `response` serializes to at most
+/// `max_bytes`, flagging the summary when anything was dropped.
+pub fn fit_to_budget(response: &mut AnalysisResponse, max_bytes: usize) {
+    if serialized_len(&*response) <= max_bytes {
+        return;
+    }
+
+    let sizes = result_sizes(response);
+
+    // Size the envelope with the longest reason this call can produce
+    let results = std::mem::take(&mut response.results);
+    let flattened = response.all_findings.is_some();
+    if flattened {
+        response.all_findings = Some(Vec::new());
+    }
+    let deduped = response.deduped_findings.take();
+    if deduped.is_some() {
+        response.deduped_findings = Some(Vec::new());
+    }
+    response.summary.truncated = true;
+    response.summary.truncation_reason = Some(reason(max_bytes, results.len(), results.len()));
+    let mut total = serialized_len(&*response);
+
+    let mut kept = 0;
+    for size in &sizes {
+        // Every result after the first is preceded by a comma
+        let size = size + usize::from(kept > 0);
+        if total + size > max_bytes {
+            break;
+        }
+        total += size;
+        kept += 1;
+    }
+
+    let count = results.len();
+    response.results = results;
+    loop {
+        response.results.truncate(kept);
+        if flattened {
+            response.all_findings = Some(FindingWithFile::collect(&response.results));
+        }
+        if let Some(deduped) = &deduped {
+            response.deduped_findings = Some(deduped_in(deduped, &response.results));
+        }
+        response.summary.truncation_reason = Some(reason(max_bytes, count - kept, count));
+
+        // The sizes are estimates (a moved deduped finding can change
+        // length), so confirm the fit and drop more if needed
+        if kept == 0 || serialized_len(&*response) <= max_bytes {
+            break;
+        }
+        kept -= 1;
+    }
+}
+
+/// What keeping each result costs: the result itself plus its entries in
+/// `all_findings` and `deduped_findings`. A deduped finding is charged to
+/// the first result it occurs in, and each occurrence to its own result.
+fn result_sizes(response: &AnalysisResponse) -> Vec<usize> {
+    let flattened = response.all_findings.is_some();
+    let mut sizes: Vec<usize> = response
+        .results
+        .iter()
+        .map(|result| {
+            let flat: usize = if flattened {
+                FindingWithFile::collect(std::slice::from_ref(result))
+                    .iter()
+                    .map(|finding| serialized_len(finding) + 1)
+                    .sum()
+            } else {
+                0
+            };
+            serialized_len(result) + flat
+        })
+        .collect();
+
+    let Some(deduped) = &response.deduped_findings else {
+        return sizes;
+    };
+    let mut indices: HashMap<&str, usize> = HashMap::new();
+    for (index, result) in response.results.iter().enumerate() {
+        indices.entry(result.file_name.as_str()).or_insert(index);
+    }
+    for entry in deduped {
+        let occurring: Vec<(usize, usize)> = entry
+            .occurrences
+            .iter()
+            .filter_map(|occurrence| {
+                let index = *indices.get(occurrence.file_name.as_str())?;
+                Some((index, serialized_len(occurrence) + 1))
+            })
+            .collect();
+        let Some(&(first, _)) = occurring.iter().min() else {
+            continue;
+        };
+        let bare = DedupedFinding {
+            occurrences: Vec::new(),
+            ..entry.clone()
+        };
+        sizes[first] += serialized_len(&bare) + 1;
+        for (index, size) in occurring {
+            sizes[index] += size;
+        }
+    }
+
+    sizes
+}
+
+/// `deduped` restricted to occurrences in `results`. A finding whose first
+/// occurrence was dropped moves to its first remaining one.
+fn deduped_in(deduped: &[DedupedFinding], results: &[FileAnalysisResult]) -> Vec<DedupedFinding> {
+    let kept: HashSet<&str> = results.iter().map(|result| result.file_name.as_str()).collect();
+
+    deduped
+        .iter()
+        .filter_map(|entry| {
+            let occurrences: Vec<_> = entry
+                .occurrences
+                .iter()
+                .filter(|occurrence| kept.contains(occurrence.file_name.as_str()))
+                .cloned()
+                .collect();
+            let first = occurrences.first()?;
+            let mut finding = entry.finding.clone();
+            if entry.occurrences.first() != Some(first) {
+                finding.location.line = first.line;
+                finding.location.end_line = None;
+                finding.location.end_column = None;
+            }
+            Some(DedupedFinding {
+                fingerprint: entry.fingerprint.clone(),
+                finding,
+                occurrences,
+            })
+        })
+        .collect()
+}
+
+fn reason(max_bytes: usize, omitted: usize, total: usize) -> String {
+    format!(
+        "Response exceeded {} bytes; {} of {} file results omitted",
+        max_bytes, omitted, total
+    )
+}
+
+fn serialized_len(value: &impl serde::Serialize) -> usize {
+    serde_json::to_vec(value).map_or(0, |bytes| bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AnalysisSummary, FileAnalysisResult, FileMetrics, Language};
+    use std::collections::HashMap;
+
+    fn response(files: usize) -> AnalysisResponse {
+        let result = |index: usize| FileAnalysisResult {
+            file_name: format!("file{}.js", index),
+            language: Language::JavaScript,
+            findings: Vec::new(),
+            metrics: FileMetrics {
+                lines_of_code: 1,
+                functions_count: 0,
+                classes_count: 0,
+                complexity_score: 1.0,
+                todo_density: 0.0,
+                iife_count: 0,
+                longest_regex: 0,
+                class_cohesion: Vec::new(),
+                halstead: None,
+            },
+            rules_executed: Vec::new(),
+            rules_skipped: Vec::new(),
+            coupling: Default::default(),
+            timing_breakdown: None,
+        };
+
+        AnalysisResponse {
+            results: (0..files).map(result).collect(),
+            summary: AnalysisSummary {
+                total_files: files as u32,
+                total_findings: 0,
+                findings_by_severity: HashMap::new(),
+                total_lines_analyzed: files as u32,
+                budget_exceeded: false,
+                files_skipped: 0,
+                unsupported_files: Vec::new(),
+                truncated: false,
+                truncation_reason: None,
+                findings_by_directory: None,
+                sampled: false,
+                sample_size: None,
+                languages_analyzed: HashMap::new(),
+                lines_by_language: HashMap::new(),
+            },
+            execution_time_ms: 1,
+            all_findings: None,
+            deduped_findings: None,
+        }
+    }
+
+    #[test]
+    fn test_response_within_budget_is_untouched() {
+        let mut fitted = response(3);
+        fit_to_budget(&mut fitted, usize::MAX);
+
+        assert_eq!(fitted.results.len(), 3);
+        assert!(!fitted.summary.truncated);
+        assert!(fitted.summary.truncation_reason.is_none());
+    }
+
+    #[test]
+    fn test_trailing_results_are_dropped_to_fit() {
+        let full = serialized_len(&response(10));
+        let mut fitted = response(10);
+        fit_to_budget(&mut fitted, full / 2);
+
+        let body = serde_json::to_vec(&fitted).unwrap();
+        assert!(body.len() <= full / 2);
+        assert!(fitted.summary.truncated);
+        assert!(!fitted.results.is_empty() && fitted.results.len() < 10);
+        assert_eq!(fitted.results[0].file_name, "file0.js");
+        assert_eq!(fitted.summary.total_files, 10);
+        let omitted = 10 - fitted.results.len();
+        assert!(fitted
+            .summary
+            .truncation_reason
+            .unwrap()
+            .contains(&format!("{} of 10 file results omitted", omitted)));
+    }
+
+    #[test]
+    fn test_deduped_findings_are_cut_to_kept_files() {
+        let mut full = response(10);
+        for result in &mut full.results {
+            result.findings.push(crate::types::Finding::new(
+                "float-equality",
+                crate::types::Severity::Medium,
+                "Avoid comparing floats with ===".to_string(),
+                crate::types::Location {
+                    line: 2,
+                    column: 1,
+                    end_line: None,
+                    end_column: None,
+                },
+            ));
+        }
+        full.deduped_findings = Some(DedupedFinding::collect(&mut full.results));
+        let budget = serialized_len(&full) / 2;
+
+        let mut fitted = full;
+        fit_to_budget(&mut fitted, budget);
+
+        assert!(serde_json::to_vec(&fitted).unwrap().len() <= budget);
+        let kept: Vec<_> = fitted.results.iter().map(|result| result.file_name.clone()).collect();
+        assert!(!kept.is_empty() && kept.len() < 10);
+        let deduped = fitted.deduped_findings.unwrap();
+        assert_eq!(deduped.len(), 1);
+        let occurring: Vec<_> = deduped[0].occurrences.iter().map(|o| o.file_name.clone()).collect();
+        assert_eq!(occurring, kept);
+    }
+
+    #[test]
+    fn test_tiny_budget_keeps_valid_envelope() {
+        let mut fitted = response(2);
+        fit_to_budget(&mut fitted, 10);
+
+        assert!(fitted.results.is_empty());
+        assert!(fitted.summary.truncated);
+    }
+}
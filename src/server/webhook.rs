@@ -0,0 +1,297 @@
+//! Delivery of `/analyze/async` results to the client's callback URL.
+//!
+//! The body is the serialized `AnalysisResponse`. When a callback secret is
+//! configured it is signed with HMAC-SHA256 and the hex digest sent as
+//! `X-Signature-256: sha256=<digest>`, so receivers can check the payload
+//! came from this server. Failed deliveries (network errors and non-2xx
+//! responses) are retried with exponential backoff, a bounded number of
+//! times.
+//!
+//! Callback URLs come from clients, so they must not become a way to reach
+//! the server's own network. A [`CallbackPolicy`] check resolves the host
+//! when the job is accepted, and the delivery client is pinned to the
+//! addresses that passed, so the name can't re-resolve somewhere else
+//! later. The client follows no redirects, so a receiver can't bounce a
+//! delivery somewhere the policy forbids either.
+
+use axum::http::HeaderName;
+use hmac::{Hmac, Mac};
+use reqwest::Url;
+use sha2::Sha256;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+pub const SIGNATURE: HeaderName = HeaderName::from_static("x-signature-256");
+pub const JOB_ID: HeaderName = HeaderName::from_static("x-job-id");
+
+/// Delay before the first retry; doubled after each failed attempt.
+const INITIAL_BACKOFF: Duration = Duration::from_millis(200);
+
+/// Which hosts callbacks may be delivered to.
+///
+/// Without an allowlist, any host is accepted as long as every address it
+/// resolves to is public. With one, only the listed hosts are accepted, and
+/// they may resolve anywhere, e.g. to a receiver on the internal network.
+#[derive(Debug, Clone, Default)]
+pub struct CallbackPolicy {
+    allowed_hosts: Option<Arc<Vec<String>>>,
+}
+
+impl CallbackPolicy {
+    pub fn new(allowed_hosts: Option<Vec<String>>) -> Self {
+        Self {
+            allowed_hosts: allowed_hosts.map(Arc::new),
+        }
+    }
+
+    /// Checks that `url` is an http(s) URL whose host the policy allows,
+    /// resolving it if needed.
+    pub async fn check(&self, url: &str) -> Result<CallbackTarget, String> {
+        let url = Url::parse(url)
+            .ok()
+            .filter(|url| matches!(url.scheme(), "http" | "https"))
+            .ok_or_else(|| format!("callback_url must be an http or https URL, got '{}'", url))?;
+        // IPv6 hosts come bracketed, as in `http://[::1]/`
+        let host = url
+            .host_str()
+            .map(|host| host.trim_start_matches('[').trim_end_matches(']').to_string())
+            .ok_or_else(|| "callback_url has no host".to_string())?;
+
+        match &self.allowed_hosts {
+            Some(_) if self.is_listed(&host) => return Ok(CallbackTarget { host, addrs: Vec::new() }),
+            Some(_) => return Err(format!("callback_url host '{}' is not in the allowed hosts", host)),
+            None => {}
+        }
+
+        let port = url.port_or_known_default().unwrap_or(80);
+        let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), port))
+            .await
+            .map_err(|_| format!("callback_url host '{}' could not be resolved", host))?
+            .collect();
+        check_public(&host, &addrs)?;
+
+        Ok(CallbackTarget { host, addrs })
+    }
+
+    fn is_listed(&self, host: &str) -> bool {
+        self.allowed_hosts
+            .as_ref()
+            .is_some_and(|hosts| hosts.iter().any(|allowed| allowed.eq_ignore_ascii_case(host)))
+    }
+}
+
+/// A callback host that passed the policy, with the addresses it was
+/// checked at.
+#[derive(Debug)]
+pub struct CallbackTarget {
+    host: String,
+    addrs: Vec<SocketAddr>,
+}
+
+impl CallbackTarget {
+    /// Client for delivering to this target. It connects only to the
+    /// checked addresses and doesn't follow redirects.
+    pub fn client(&self, timeout: Duration) -> reqwest::Client {
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none());
+        if !self.addrs.is_empty() {
+            builder = builder.resolve_to_addrs(&self.host, &self.addrs);
+        }
+        builder.build().unwrap_or_default()
+    }
+}
+
+fn check_public(host: &str, addrs: &[SocketAddr]) -> Result<(), String> {
+    match addrs.iter().find(|addr| !is_public(addr.ip())) {
+        Some(addr) => Err(format!(
+            "callback_url host '{}' resolves to non-public address {}",
+            host,
+            addr.ip()
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Whether `ip` is routable on the public internet: not loopback, private
+/// (RFC 1918, IPv6 unique local), link-local (which includes cloud metadata
+/// endpoints at 169.254.169.254), shared (RFC 6598), multicast, reserved or
+/// unspecified. IPv6 addresses that embed an IPv4 one (mapped, compatible,
+/// NAT64 and 6to4) are judged by the embedded address.
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => {
+            let [first, second, ..] = ip.octets();
+            !(ip.is_loopback()
+                || ip.is_private()
+                || ip.is_link_local()
+                || ip.is_unspecified()
+                || ip.is_broadcast()
+                || ip.is_multicast()
+                || first == 0
+                || first >= 240
+                || (first == 100 && (64..128).contains(&second)))
+        }
+        IpAddr::V6(ip) => {
+            if ip.is_loopback() || ip.is_unspecified() {
+                return false;
+            }
+            if let Some(embedded) = embedded_ipv4(&ip) {
+                return is_public(IpAddr::V4(embedded));
+            }
+            let segments = ip.segments();
+            !(ip.is_multicast()
+                || (segments[0] & 0xfe00) == 0xfc00
+                || (segments[0] & 0xffc0) == 0xfe80
+                // Local-use NAT64 (RFC 8215), which translates into
+                // whatever network the operator chose
+                || (segments[0] == 0x64 && segments[1] == 0xff9b && segments[2] == 1))
+        }
+    }
+}
+
+/// The IPv4 address carried by an IPv4-mapped (`::ffff:0:0/96`),
+/// IPv4-compatible (`::/96`), well-known NAT64 (`64:ff9b::/96`) or 6to4
+/// (`2002::/16`) address.
+fn embedded_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    let segments = ip.segments();
+    let from_segments = |high: u16, low: u16| Ipv4Addr::from((u32::from(high) << 16) | u32::from(low));
+
+    if let Some(mapped) = ip.to_ipv4_mapped() {
+        return Some(mapped);
+    }
+    match segments {
+        [0, 0, 0, 0, 0, 0, high, low] => Some(from_segments(high, low)),
+        [0x64, 0xff9b, 0, 0, 0, 0, high, low] => Some(from_segments(high, low)),
+        [0x2002, high, low, ..] => Some(from_segments(high, low)),
+        _ => None,
+    }
+}
+
+/// A finished job's result, ready to be posted.
+pub struct Delivery<'a> {
+    pub url: &'a str,
+    pub job_id: &'a str,
+    pub body: Vec<u8>,
+    pub secret: Option<&'a str>,
+    pub max_attempts: u32,
+}
+
+/// Posts the result, retrying failures. Returns whether any attempt got a
+/// 2xx response.
+pub async fn deliver(client: &reqwest::Client, delivery: Delivery<'_>) -> bool {
+    let signature = delivery
+        .secret
+        .map(|secret| format!("sha256={}", sign(secret.as_bytes(), &delivery.body)));
+    let mut backoff = INITIAL_BACKOFF;
+
+    for attempt in 1..=delivery.max_attempts.max(1) {
+        let mut request = client
+            .post(delivery.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(JOB_ID.as_str(), delivery.job_id)
+            .body(delivery.body.clone());
+        if let Some(signature) = &signature {
+            request = request.header(SIGNATURE.as_str(), signature);
+        }
+
+        match request.send().await {
+            Ok(response) if response.status().is_success() => {
+                info!("Delivered job {} to callback on attempt {}", delivery.job_id, attempt);
+                return true;
+            }
+            Ok(response) => warn!(
+                "Callback for job {} answered {} (attempt {})",
+                delivery.job_id,
+                response.status(),
+                attempt
+            ),
+            Err(e) => warn!("Callback for job {} failed: {} (attempt {})", delivery.job_id, e, attempt),
+        }
+
+        if attempt < delivery.max_attempts {
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+    }
+
+    warn!("Giving up on callback for job {}", delivery.job_id);
+    false
+}
+
+/// Hex HMAC-SHA256 of `body` keyed with `secret`.
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+
+    format!("{:x}", mac.finalize().into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_matches_rfc_4231_vectors() {
+        // Test case 2
+        assert_eq!(
+            sign(b"Jefe", b"what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+        // Test case 6: a key longer than the block size is hashed first
+        assert_eq!(
+            sign(&[0xaa; 131], b"Test Using Larger Than Block-Size Key - Hash Key First"),
+            "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54"
+        );
+    }
+
+    #[test]
+    fn test_only_public_addresses_are_public() {
+        let internal = [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:10.0.0.1",
+            "::ffff:169.254.169.254",
+            "224.0.0.1",
+            "239.255.255.250",
+            "240.0.0.1",
+            "ff02::1",
+            "ff0e::1",
+            "64:ff9b::a9fe:a9fe",
+            "64:ff9b::7f00:1",
+            "64:ff9b:1::808:808",
+            "2002:c0a8:101::1",
+            "::10.0.0.1",
+        ];
+        for ip in internal {
+            assert!(!is_public(ip.parse().unwrap()), "{} should not be public", ip);
+        }
+        for ip in ["93.184.216.34", "8.8.8.8", "2606:4700::1111", "64:ff9b::808:808", "2002:808:808::1"] {
+            assert!(is_public(ip.parse().unwrap()), "{} should be public", ip);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_policy_rejects_internal_and_unlisted_hosts() {
+        let open = CallbackPolicy::default();
+        assert!(open.check("http://127.0.0.1:9000/hook").await.is_err());
+        assert!(open.check("http://169.254.169.254/latest/meta-data").await.is_err());
+        assert!(open.check("http://[::1]/hook").await.is_err());
+        assert!(open.check("http://localhost/hook").await.is_err());
+        assert!(open.check("ftp://example.com/hook").await.is_err());
+
+        let listed = CallbackPolicy::new(Some(vec!["127.0.0.1".to_string()]));
+        assert!(listed.check("http://127.0.0.1:9000/hook").await.is_ok());
+        assert!(listed.check("http://93.184.216.34/hook").await.is_err());
+    }
+}
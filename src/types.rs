@@ -1,25 +1,27 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 pub enum Language {
     JavaScript,
     TypeScript,
     Python,
     Go,
     Rust,
+    Json,
 }
 
 impl Language {
     pub fn from_filename(filename: &str) -> Option<Self> {
-        let extension = filename.split('.').last()?;
+        let extension = filename.split('.').next_back()?;
         match extension {
             "js" | "jsx" | "mjs" => Some(Language::JavaScript),
             "ts" | "tsx" => Some(Language::TypeScript),
             "py" | "pyi" => Some(Language::Python),
             "go" => Some(Language::Go),
             "rs" => Some(Language::Rust),
+            "json" => Some(Language::Json),
             _ => None,
         }
     }
@@ -31,29 +33,295 @@ impl Language {
             Language::Python => &["py", "pyi"],
             Language::Go => &["go"],
             Language::Rust => &["rs"],
+            Language::Json => &["json"],
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct SourceFile {
     pub name: String,
     pub content: String,
     pub language: Option<Language>,
+    /// Inclusive 1-based `(start, end)` line range to report findings for.
+    /// The whole file is still parsed; only findings outside are dropped.
+    #[serde(default)]
+    pub analyze_range: Option<(u32, u32)>,
+    /// Parse with this language's parser instead, e.g. the JavaScript parser
+    /// for a `.ts` file. Results still report `language`.
+    #[serde(default)]
+    pub parser_override: Option<Language>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct AnalysisRequest {
     pub files: Vec<SourceFile>,
     pub rules: Option<RuleConfig>,
 }
 
+/// The same files evaluated under several rule configs, for comparing
+/// thresholds before rolling them out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiConfigRequest {
+    pub files: Vec<SourceFile>,
+    pub configs: Vec<RuleConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MultiConfigResponse {
+    /// One summary per entry of `configs`, in the same order.
+    pub summaries: Vec<AnalysisSummary>,
+    pub execution_time_ms: u64,
+}
+
+/// An analysis whose result is POSTed to `callback_url` once it finishes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AsyncAnalysisRequest {
+    pub files: Vec<SourceFile>,
+    pub rules: Option<RuleConfig>,
+    /// `http` or `https` URL that receives the `AnalysisResponse`.
+    pub callback_url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AsyncAnalysisResponse {
+    /// Sent back with the result in the `X-Job-Id` header.
+    pub job_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SessionResponse {
+    /// Pass as `/analyze?session=<token>` to analyze against the session.
+    pub session: String,
+}
+
+/// Files to analyze in the background so a later `/analyze` of the same
+/// content under the same rules is served from the disk cache.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachePreloadRequest {
+    pub files: Vec<SourceFile>,
+    pub rules: Option<RuleConfig>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CachePreloadResponse {
+    /// Identifies the background job in the server logs.
+    pub job_id: String,
+    pub files: usize,
+}
+
+/// Files whose exported API to list, without metrics or rules.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiSurfaceRequest {
+    pub files: Vec<SourceFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ApiSurfaceResponse {
+    pub results: Vec<FileApiSurface>,
+    pub execution_time_ms: u64,
+}
+
+/// The exported functions and classes (and TypeScript interfaces) of a file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileApiSurface {
+    pub file_name: String,
+    pub language: Language,
+    pub functions: Vec<ExportedFunction>,
+    pub classes: Vec<ExportedClass>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedFunction {
+    pub name: String,
+    pub line: u32,
+    /// Parameters and return type as written, e.g. `(id: string): Promise<User>`.
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportedClass {
+    pub name: String,
+    pub line: u32,
+}
+
+/// Files to check for syntax errors only, without metrics or rules.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParseCheckRequest {
+    pub files: Vec<SourceFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ParseCheckResponse {
+    pub results: Vec<ParseCheckResult>,
+    pub execution_time_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParseCheckResult {
+    pub file_name: String,
+    pub parses: bool,
+    pub error_count: u32,
+    /// 1-based line of the first syntax error.
+    pub first_error_line: Option<u32>,
+}
+
+/// Outcome of checking a `RuleConfig` with `/rules/validate`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigValidation {
+    pub valid: bool,
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RuleConfig {
     pub complexity_threshold: Option<u32>,
     pub max_function_length: Option<u32>,
     pub enable_security_rules: Option<bool>,
     pub enable_dead_code_detection: Option<bool>,
+    /// Re-export count above which a pure re-export file is flagged as a barrel.
+    pub max_reexports: Option<u32>,
+    /// Total syntax nodes a request may analyze before the remaining files
+    /// are skipped. Unlimited when unset.
+    pub node_budget: Option<u64>,
+    /// Rule ids that should not run for this request.
+    pub disabled_rules: Option<Vec<String>>,
+    /// Tech-debt markers per 100 lines above which a file of 50 or more
+    /// lines is flagged.
+    pub max_todo_density: Option<f64>,
+    /// Canonicalize relative import specifiers (strip extensions, collapse
+    /// `/index`) before rules see them.
+    pub normalize_import_paths: Option<bool>,
+    /// Importing files above which a module is flagged as `high-coupling`.
+    pub max_fan_in: Option<u32>,
+    /// Imports above which a file is flagged: `high-coupling` counts every
+    /// import statement and `require`, `too-many-imports` counts distinct
+    /// modules.
+    pub max_imports: Option<u32>,
+    /// Opt in to the heuristic `string-concat-in-loop` rule.
+    pub detect_string_concat_in_loops: Option<bool>,
+    /// Drop findings that repeat the same rule, position and message.
+    pub dedupe_findings: Option<bool>,
+    /// Opt in to the `trivial-function` rule.
+    pub detect_trivial_functions: Option<bool>,
+    /// Callback nesting depth above which `callback-hell` fires.
+    pub max_callback_depth: Option<u32>,
+    /// Leave leading guard clauses out of function complexity.
+    pub discount_guard_clauses: Option<bool>,
+    /// Report per-phase timings in `FileAnalysisResult.timing_breakdown`.
+    pub include_timing: Option<bool>,
+    /// Opt in to the `asi-hazard` rule.
+    pub detect_asi_hazards: Option<bool>,
+    /// Extra function names or glob patterns (`handle*`) the dead-code rule
+    /// never flags, on top of common framework entry points.
+    pub dead_code_exemptions: Option<Vec<String>>,
+    /// Extra glob patterns (`*_dsn`, `stripe*`) for variable and key names
+    /// whose string values `hardcoded-secret` checks, matched case-insensitively.
+    pub secret_name_patterns: Option<Vec<String>>,
+    /// Statements above which a function is flagged as `too-many-statements`.
+    pub max_statements: Option<u32>,
+    /// Opt in to `unassigned-todo`, which requires `TODO(owner)` style
+    /// references on TODO/FIXME comments.
+    pub require_todo_reference: Option<bool>,
+    /// Files with at most this many lines skip optional metrics such as
+    /// Halstead, which mean little at that size. `0` disables the fast path.
+    pub small_file_max_lines: Option<u32>,
+    /// Opt in to the `quadratic-spread` rule.
+    pub detect_quadratic_spread: Option<bool>,
+    /// Replacement suggestion text per rule id, e.g. a link to internal
+    /// docs. `{rule}` is replaced with the rule id.
+    pub suggestion_overrides: Option<HashMap<String, String>>,
+    /// Base URL of the rule docs; each finding links to `<base>/<rule id>`.
+    pub rule_doc_base_url: Option<String>,
+    /// Direct elements above which an array or object literal is flagged as
+    /// `large-inline-literal`.
+    pub max_literal_elements: Option<u32>,
+    /// Report `AnalysisSummary.findings_by_directory`.
+    pub group_by_directory: Option<bool>,
+    /// Opt in to the `tight-coupling` rule for reference cycles between
+    /// classes in the same file.
+    pub detect_tight_coupling: Option<bool>,
+    /// Also return every finding in one `AnalysisResponse.all_findings`
+    /// list, each tagged with its file name.
+    pub flatten_findings: Option<bool>,
+    /// Opt in to `unchecked-env-access`, which flags `process.env` reads
+    /// without a fallback or check.
+    pub detect_unchecked_env_access: Option<bool>,
+    /// Compute metrics only: no rule runs and `findings` stays empty.
+    pub metrics_only: Option<bool>,
+    /// Rule ids to run before all others, in this order. Repeated ids run
+    /// once, at their first position.
+    pub rule_order: Option<Vec<String>>,
+    /// Stop running rules on a file once one reports a `Critical` finding.
+    pub stop_on_critical: Option<bool>,
+    /// Opt in to `await-in-loop`, which flags `await` in loop bodies.
+    pub detect_await_in_loop: Option<bool>,
+    /// Properties above which a returned object literal is flagged as
+    /// `large-return-object`.
+    pub max_return_object_properties: Option<u32>,
+    /// Attach each rule's rationale to its findings.
+    pub explain: Option<bool>,
+    /// Opt in to `zero-delay-timer`, which flags `setTimeout(fn, 0)`.
+    pub detect_zero_delay_timers: Option<bool>,
+    /// Opt in to `legacy-iife`, which flags immediately-invoked function
+    /// expressions.
+    pub detect_legacy_iife: Option<bool>,
+    /// Report each distinct finding once, in `deduped_findings`, instead of
+    /// under every file it occurs in.
+    pub dedupe_across_files: Option<bool>,
+    /// Fraction of files to analyze, from 0.0 to 1.0. Files are picked by a
+    /// hash of their name, so repeated runs analyze the same sample.
+    pub sample_rate: Option<f64>,
+    /// Functions and methods `deprecated-api` flags calls to. A bare name
+    /// (`fetchLegacy`) matches plain and method calls of that name; a dotted
+    /// one (`api.fetchLegacy`) matches that exact callee.
+    pub deprecated_symbols: Option<Vec<String>>,
+    /// Class cohesion (0.0 to 1.0) below which `low-cohesion` flags a
+    /// class. Unset leaves the rule off; the metric is reported regardless.
+    pub min_class_cohesion: Option<f64>,
+    /// Name each finding's innermost enclosing function, skipping anonymous
+    /// callbacks, in `Finding.enclosing_function`.
+    pub include_enclosing_function: Option<bool>,
+    /// Opt in to `variable-shadowing`, which flags names that reuse a
+    /// binding from an enclosing function or the module scope.
+    pub detect_variable_shadowing: Option<bool>,
+    /// Pattern length above which `complex-regex` flags a regex literal.
+    pub max_regex_length: Option<u32>,
+    /// Group count above which `complex-regex` flags a regex literal.
+    pub max_regex_groups: Option<u32>,
+    /// Answer `/analyze` with only the summary and execution time, for
+    /// clients such as CI gates that need nothing per file.
+    pub minimal_response: Option<bool>,
+    /// Opt in to `use-before-init`, which flags `let x;` variables read
+    /// before anything is assigned to them.
+    pub detect_use_before_init: Option<bool>,
+    /// Glob patterns (`/opt/app/*`) for absolute paths `hardcoded-path`
+    /// accepts.
+    pub path_allowlist: Option<Vec<String>>,
+    /// Drop findings below this severity, before the summary is tallied.
+    pub min_severity: Option<Severity>,
+    /// Order each file's findings by severity, most severe first, then by
+    /// position.
+    pub sort_findings: Option<bool>,
+    /// Opt in to `multiple-responsibilities`, which flags classes whose
+    /// methods split into groups sharing no fields.
+    pub detect_multiple_responsibilities: Option<bool>,
+    /// Drop findings from rules less confident than this (0.0 to 1.0),
+    /// before the summary is tallied.
+    pub min_confidence: Option<f64>,
+    /// Extra functions (`db.query`, or a bare `request` to match it as a
+    /// method too) that `floating-promise` treats as returning a promise,
+    /// on top of `fetch` and `axios`.
+    pub thenable_functions: Option<Vec<String>>,
+    /// Fail the whole request with `UnsupportedLanguage` at the first file
+    /// no parser handles, instead of analyzing the rest without it.
+    pub fail_on_unsupported: Option<bool>,
+    /// Object and array patterns a parameter may nest before
+    /// `deep-destructuring` flags it.
+    pub max_destructure_depth: Option<u32>,
+    /// Attach a snippet to each finding: its lines plus this many lines
+    /// before and after.
+    pub snippet_context_lines: Option<u32>,
 }
 
 impl Default for RuleConfig {
@@ -63,35 +331,316 @@ impl Default for RuleConfig {
             max_function_length: Some(50),
             enable_security_rules: Some(true),
             enable_dead_code_detection: Some(true),
+            max_reexports: Some(20),
+            node_budget: None,
+            disabled_rules: None,
+            max_todo_density: Some(5.0),
+            normalize_import_paths: Some(false),
+            max_fan_in: Some(15),
+            max_imports: Some(25),
+            detect_string_concat_in_loops: Some(false),
+            dedupe_findings: Some(true),
+            detect_trivial_functions: Some(false),
+            max_callback_depth: Some(2),
+            discount_guard_clauses: Some(false),
+            include_timing: Some(false),
+            detect_asi_hazards: Some(false),
+            dead_code_exemptions: None,
+            secret_name_patterns: None,
+            max_statements: Some(30),
+            require_todo_reference: Some(false),
+            small_file_max_lines: Some(3),
+            detect_quadratic_spread: Some(false),
+            suggestion_overrides: None,
+            rule_doc_base_url: None,
+            max_literal_elements: Some(100),
+            group_by_directory: Some(false),
+            detect_tight_coupling: Some(false),
+            flatten_findings: Some(false),
+            detect_unchecked_env_access: Some(false),
+            metrics_only: Some(false),
+            rule_order: None,
+            stop_on_critical: Some(false),
+            detect_await_in_loop: Some(false),
+            max_return_object_properties: Some(15),
+            explain: Some(false),
+            detect_zero_delay_timers: Some(false),
+            detect_legacy_iife: Some(false),
+            dedupe_across_files: Some(false),
+            sample_rate: None,
+            deprecated_symbols: None,
+            min_class_cohesion: None,
+            include_enclosing_function: Some(false),
+            detect_variable_shadowing: Some(false),
+            max_regex_length: Some(80),
+            max_regex_groups: Some(6),
+            minimal_response: Some(false),
+            detect_use_before_init: Some(false),
+            path_allowlist: None,
+            min_severity: None,
+            sort_findings: Some(false),
+            detect_multiple_responsibilities: Some(false),
+            min_confidence: None,
+            thenable_functions: None,
+            fail_on_unsupported: Some(false),
+            max_destructure_depth: Some(2),
+            snippet_context_lines: None,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AnalysisResponse {
     pub results: Vec<FileAnalysisResult>,
     pub summary: AnalysisSummary,
     pub execution_time_ms: u64,
+    /// Every finding in `results`, in file order, when
+    /// `RuleConfig.flatten_findings` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub all_findings: Option<Vec<FindingWithFile>>,
+    /// Every finding once per fingerprint, with where it occurs, when
+    /// `RuleConfig.dedupe_across_files` is set. `results` then carry no
+    /// findings of their own.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deduped_findings: Option<Vec<DedupedFinding>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl AnalysisResponse {
+    /// Every reported finding with its file, for renderers that print one
+    /// line per finding. Under `dedupe_across_files` these come from
+    /// `deduped_findings`, one per occurrence: the first occurrence's
+    /// finding moved to that occurrence's line.
+    pub fn reported_findings(&self) -> Vec<FindingWithFile> {
+        let Some(deduped) = &self.deduped_findings else {
+            return FindingWithFile::collect(&self.results);
+        };
+
+        deduped
+            .iter()
+            .flat_map(|deduped| {
+                deduped.occurrences.iter().map(|occurrence| {
+                    let mut finding = deduped.finding.clone();
+                    if finding.location.line != occurrence.line {
+                        finding.location.line = occurrence.line;
+                        finding.location.end_line = None;
+                        finding.location.end_column = None;
+                    }
+                    FindingWithFile {
+                        file_name: occurrence.file_name.clone(),
+                        finding,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// `/analyze` response under `RuleConfig.minimal_response`.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct MinimalAnalysisResponse {
+    pub summary: AnalysisSummary,
+    pub execution_time_ms: u64,
+}
+
+impl From<AnalysisResponse> for MinimalAnalysisResponse {
+    fn from(response: AnalysisResponse) -> Self {
+        Self {
+            summary: response.summary,
+            execution_time_ms: response.execution_time_ms,
+        }
+    }
+}
+
+/// A finding together with the file it was reported in.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FindingWithFile {
+    pub file_name: String,
+    #[serde(flatten)]
+    pub finding: Finding,
+}
+
+impl FindingWithFile {
+    /// Flattens the findings of `results`, in order.
+    pub fn collect(results: &[FileAnalysisResult]) -> Vec<Self> {
+        results
+            .iter()
+            .flat_map(|result| {
+                result.findings.iter().map(|finding| FindingWithFile {
+                    file_name: result.file_name.clone(),
+                    finding: finding.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A finding reported identically in one or more places.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DedupedFinding {
+    pub fingerprint: String,
+    /// The first occurrence.
+    #[serde(flatten)]
+    pub finding: Finding,
+    pub occurrences: Vec<FindingOccurrence>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FindingOccurrence {
+    pub file_name: String,
+    pub line: u32,
+}
+
+impl DedupedFinding {
+    /// Moves the findings out of `results`, grouped by fingerprint in the
+    /// order they first occur.
+    pub fn collect(results: &mut [FileAnalysisResult]) -> Vec<Self> {
+        let mut deduped: Vec<Self> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+
+        for result in results {
+            for finding in std::mem::take(&mut result.findings) {
+                let occurrence = FindingOccurrence {
+                    file_name: result.file_name.clone(),
+                    line: finding.location.line,
+                };
+                let fingerprint = finding.fingerprint();
+                match index.get(&fingerprint) {
+                    Some(&position) => deduped[position].occurrences.push(occurrence),
+                    None => {
+                        index.insert(fingerprint.clone(), deduped.len());
+                        deduped.push(DedupedFinding {
+                            fingerprint,
+                            finding,
+                            occurrences: vec![occurrence],
+                        });
+                    }
+                }
+            }
+        }
+
+        deduped
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileAnalysisResult {
     pub file_name: String,
     pub language: Language,
     pub findings: Vec<Finding>,
     pub metrics: FileMetrics,
+    /// Ids of the rules that were run against this file.
+    #[serde(default)]
+    pub rules_executed: Vec<String>,
+    /// Rules that were considered but not run, with the reason why.
+    #[serde(default)]
+    pub rules_skipped: Vec<SkippedRule>,
+    /// Cross-file coupling within the analyzed batch.
+    #[serde(default)]
+    pub coupling: CouplingMetrics,
+    /// Microseconds spent in `parse`, `metrics` and each `rule:<id>`, when
+    /// `RuleConfig.include_timing` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timing_breakdown: Option<HashMap<String, u64>>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CouplingMetrics {
+    /// Imports and `require` calls in this file.
+    pub import_count: u32,
+    /// Other files in the batch that import this one.
+    pub fan_in: u32,
+}
+
+/// Catalog entry for a rule, as listed by `GET /rules`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleDescriptor {
+    pub id: String,
+    /// Why the rule exists and the risk it guards against.
+    pub rationale: String,
+    /// Whether the rule runs without opting in through `RuleConfig`.
+    pub enabled_by_default: bool,
+    /// See `Rule::confidence`.
+    pub confidence: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SkippedRule {
+    pub rule_id: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Finding {
     pub rule_id: String,
     pub severity: Severity,
     pub message: String,
     pub location: Location,
     pub suggestion: Option<String>,
+    /// Link to the rule's documentation, when `RuleConfig.rule_doc_base_url`
+    /// is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc_url: Option<String>,
+    /// Why the rule exists, when `RuleConfig.explain` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rationale: Option<String>,
+    /// Innermost named function containing the finding, when
+    /// `RuleConfig.include_enclosing_function` is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enclosing_function: Option<String>,
+    /// How likely the finding is a real problem, from 0.0 to 1.0: the
+    /// reporting rule's `Rule::confidence`.
+    #[serde(default = "full_confidence")]
+    pub confidence: f64,
+    /// The finding's lines with `RuleConfig.snippet_context_lines` lines of
+    /// context either side, when that is set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<Vec<SnippetLine>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// One line of a finding's snippet.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SnippetLine {
+    pub line: u32,
+    pub text: String,
+    /// Part of the finding's own span rather than context.
+    pub highlighted: bool,
+}
+
+fn full_confidence() -> f64 {
+    1.0
+}
+
+impl Finding {
+    pub fn new(rule_id: &str, severity: Severity, message: String, location: Location) -> Self {
+        Self {
+            rule_id: rule_id.to_string(),
+            severity,
+            message,
+            location,
+            suggestion: None,
+            doc_url: None,
+            rationale: None,
+            enclosing_function: None,
+            confidence: 1.0,
+            snippet: None,
+        }
+    }
+
+    /// Identifies the same issue wherever it is reported: the rule and the
+    /// message, but not the file or position, so copy-pasted code shares it.
+    pub fn fingerprint(&self) -> String {
+        let hash = ContentHash::from_content(&format!("{}\0{}", self.rule_id, self.message));
+        hash.0[..16].to_string()
+    }
+
+    pub fn with_suggestion(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_string());
+        self
+    }
+}
+
+/// Ordered from least to most severe.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
 pub enum Severity {
     Low,
     Medium,
@@ -99,7 +648,7 @@ pub enum Severity {
     Critical,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Location {
     pub line: u32,
     pub column: u32,
@@ -107,20 +656,86 @@ pub struct Location {
     pub end_column: Option<u32>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct FileMetrics {
     pub lines_of_code: u32,
     pub functions_count: u32,
     pub classes_count: u32,
     pub complexity_score: f64,
+    /// TODO/FIXME/HACK/XXX comment markers per 100 lines.
+    #[serde(default)]
+    pub todo_density: f64,
+    /// Immediately-invoked function expressions, a pre-module pattern.
+    #[serde(default)]
+    pub iife_count: u32,
+    /// Pattern length of the longest regex literal.
+    #[serde(default)]
+    pub longest_regex: u32,
+    /// Cohesion of each class with at least two instance methods.
+    #[serde(default)]
+    pub class_cohesion: Vec<ClassCohesion>,
+    /// Left unset for files at or under `RuleConfig.small_file_max_lines`.
+    #[serde(default)]
+    pub halstead: Option<HalsteadMetrics>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ClassCohesion {
+    pub class_name: String,
+    pub line: u32,
+    /// Share of method pairs using a common field, from 0.0 to 1.0.
+    pub cohesion: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HalsteadMetrics {
+    /// Distinct operators plus distinct operands.
+    pub vocabulary: u32,
+    /// Total operators plus total operands.
+    pub length: u32,
+    pub volume: f64,
+    pub difficulty: f64,
+    pub effort: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AnalysisSummary {
     pub total_files: u32,
     pub total_findings: u32,
     pub findings_by_severity: HashMap<String, u32>,
     pub total_lines_analyzed: u32,
+    /// Set when `RuleConfig.node_budget` ran out and the results are partial.
+    #[serde(default)]
+    pub budget_exceeded: bool,
+    /// Files left unanalyzed because the budget ran out.
+    #[serde(default)]
+    pub files_skipped: u32,
+    /// Files left out because no parser handles their language. With
+    /// `RuleConfig.fail_on_unsupported` the request fails instead.
+    #[serde(default)]
+    pub unsupported_files: Vec<String>,
+    /// Set when trailing `results` were dropped to keep the response under
+    /// the server's size limit. The totals above still cover every file.
+    #[serde(default)]
+    pub truncated: bool,
+    #[serde(default)]
+    pub truncation_reason: Option<String>,
+    /// Findings per top-level directory of `file_name` (`.` for files at
+    /// the root), when `RuleConfig.group_by_directory` is set.
+    #[serde(default)]
+    pub findings_by_directory: Option<HashMap<String, u32>>,
+    /// Set when `RuleConfig.sample_rate` limited the analysis to a sample.
+    #[serde(default)]
+    pub sampled: bool,
+    /// Files in the sample, out of all those in the request.
+    #[serde(default)]
+    pub sample_size: Option<u32>,
+    /// Files analyzed per language.
+    #[serde(default)]
+    pub languages_analyzed: HashMap<Language, u32>,
+    /// Lines analyzed per language.
+    #[serde(default)]
+    pub lines_by_language: HashMap<Language, u32>,
 }
 
 // Content hash for caching
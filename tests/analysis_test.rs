@@ -0,0 +1,901 @@
+use rust_analysis_engine::{
+    analysis::{cache::DiskCache, AnalysisEngine},
+    error::AnalysisError,
+    types::{AnalysisRequest, AnalysisResponse, Language, ParseCheckRequest, RuleConfig, Severity, SourceFile},
+};
+
+fn js_file(name: &str, content: &str) -> SourceFile {
+    SourceFile {
+        name: name.to_string(),
+        content: content.to_string(),
+        language: None,
+        analyze_range: None,
+        parser_override: None,
+    }
+}
+
+#[tokio::test]
+async fn test_node_budget_returns_partial_results() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let files = (0..5)
+        .map(|i| js_file(&format!("file{}.js", i), "function add(a, b) { return a + b; }"))
+        .collect();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files,
+            rules: Some(RuleConfig {
+                node_budget: Some(10),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+
+    // The first file alone spends the whole budget
+    assert_eq!(response.results.len(), 1);
+    assert_eq!(response.summary.total_files, 1);
+    assert!(response.summary.budget_exceeded);
+    assert_eq!(response.summary.files_skipped, 4);
+}
+
+#[tokio::test]
+async fn test_no_budget_analyzes_every_file() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let files = (0..5)
+        .map(|i| js_file(&format!("file{}.js", i), "function add(a, b) { return a + b; }"))
+        .collect();
+
+    let response = engine
+        .analyze(AnalysisRequest { files, rules: None })
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 5);
+    assert!(!response.summary.budget_exceeded);
+    assert_eq!(response.summary.files_skipped, 0);
+}
+
+#[tokio::test]
+async fn test_disabled_rule_is_reported_as_skipped() {
+    let engine = AnalysisEngine::new().await.unwrap();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("app.js", "counter = 0;\n")],
+            rules: Some(RuleConfig {
+                disabled_rules: Some(vec!["global-pollution".to_string()]),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+
+    let result = &response.results[0];
+    assert!(result.findings.iter().all(|f| f.rule_id != "global-pollution"));
+    assert!(!result.rules_executed.contains(&"global-pollution".to_string()));
+    assert!(result.rules_executed.contains(&"barrel-file".to_string()));
+
+    let skipped = result
+        .rules_skipped
+        .iter()
+        .find(|rule| rule.rule_id == "global-pollution")
+        .unwrap();
+    assert_eq!(skipped.reason, "disabled by config");
+
+    // The hooks rule only applies to JSX/TSX files
+    let hooks = result
+        .rules_skipped
+        .iter()
+        .find(|rule| rule.rule_id == "hooks-rule-violation")
+        .unwrap();
+    assert_eq!(hooks.reason, "not applicable to this file");
+}
+
+#[tokio::test]
+async fn test_crlf_line_endings_match_editor_lines() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "// header\r\nlet total = 0;\r\n\r\n  leaked = total;\r\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("app.js", source)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    let result = &response.results[0];
+    assert_eq!(result.metrics.lines_of_code, 4);
+
+    let finding = result
+        .findings
+        .iter()
+        .find(|f| f.rule_id == "global-pollution")
+        .unwrap();
+    assert_eq!(finding.location.line, 4);
+    assert_eq!(finding.location.column, 3);
+    assert_eq!(finding.location.end_line, Some(4));
+    assert!(finding.message.contains("'leaked'"));
+}
+
+#[tokio::test]
+async fn test_widely_imported_module_is_high_coupling() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let mut files = vec![js_file("src/utils/index.js", "export const id = (x) => x;\n")];
+    for i in 0..4 {
+        let import = if i % 2 == 0 { "./utils" } else { "./utils/index.js" };
+        files.push(js_file(
+            &format!("src/feature{}.js", i),
+            &format!("import {{ id }} from '{}';\nimport React from 'react';\n", import),
+        ));
+    }
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files,
+            rules: Some(RuleConfig {
+                max_fan_in: Some(3),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+
+    let utils = &response.results[0];
+    assert_eq!(utils.coupling.fan_in, 4);
+    assert_eq!(utils.coupling.import_count, 0);
+    let coupling: Vec<_> = utils
+        .findings
+        .iter()
+        .filter(|f| f.rule_id == "high-coupling")
+        .collect();
+    assert_eq!(coupling.len(), 1);
+    assert!(coupling[0].message.contains("imported by 4 other files"));
+
+    let feature = &response.results[1];
+    assert_eq!(feature.coupling.import_count, 2);
+    assert_eq!(feature.coupling.fan_in, 0);
+    assert!(feature.findings.iter().all(|f| f.rule_id != "high-coupling"));
+    assert!(response.summary.total_findings >= 1);
+}
+
+#[tokio::test]
+async fn test_import_heavy_file_without_fan_in_is_high_coupling() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    // Thirty imports of one module: over max_imports, but one distinct module
+    let source: String = (0..30).map(|i| format!("import {{ part{} }} from 'shared';\n", i)).collect();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("src/app.js", &source)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    let result = &response.results[0];
+    assert_eq!(result.coupling.fan_in, 0);
+    assert_eq!(result.coupling.import_count, 30);
+    let coupling: Vec<_> = result.findings.iter().filter(|f| f.rule_id == "high-coupling").collect();
+    assert_eq!(coupling.len(), 1);
+    assert_eq!(coupling[0].message, "File imports 30 modules (max: 25)");
+    assert!(result.findings.iter().all(|f| f.rule_id != "too-many-imports"));
+}
+
+#[tokio::test]
+async fn test_high_coupling_is_anchored_at_first_import() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let imports: String = (0..30).map(|i| format!("import {{ part{} }} from 'shared';\n", i)).collect();
+    let source = format!("// Application entry point\n\n{}", imports);
+    let file = |range| SourceFile {
+        analyze_range: range,
+        ..js_file("src/app.js", &source)
+    };
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![file(None), file(Some((10, 20)))],
+            rules: Some(RuleConfig {
+                snippet_context_lines: Some(1),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+
+    let coupling = |index: usize| -> Vec<_> {
+        response.results[index]
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == "high-coupling")
+            .collect()
+    };
+
+    let anchored = coupling(0);
+    assert_eq!(anchored.len(), 1);
+    assert_eq!((anchored[0].location.line, anchored[0].location.column), (3, 1));
+    let snippet = anchored[0].snippet.as_ref().unwrap();
+    assert_eq!(snippet.iter().map(|line| line.line).collect::<Vec<_>>(), vec![2, 3, 4]);
+    assert!(snippet[1].highlighted && snippet[1].text.starts_with("import { part0 }"));
+
+    // The first import lies outside the range, so there is nothing to report
+    assert!(coupling(1).is_empty());
+    assert_eq!(response.results[1].coupling.import_count, 30);
+    assert!(response.results[1].rules_executed.iter().any(|id| id == "high-coupling"));
+}
+
+#[tokio::test]
+async fn test_coupling_over_large_batch_is_deterministic() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    // Every module imports the hub and its predecessor
+    let request = || AnalysisRequest {
+        files: std::iter::once(js_file("src/hub.js", "export const hub = 1;\n"))
+            .chain((1..500).map(|i| {
+                let previous = if i == 1 { "./hub".to_string() } else { format!("./m{}", i - 1) };
+                js_file(
+                    &format!("src/m{}.js", i),
+                    &format!("import {{ hub }} from './hub';\nimport * as prev from '{}';\nexport const m{} = hub;\n", previous, i),
+                )
+            }))
+            .collect(),
+        rules: None,
+    };
+    let coupling = |response: &AnalysisResponse| -> Vec<(u32, u32)> {
+        response
+            .results
+            .iter()
+            .map(|result| (result.coupling.fan_in, result.coupling.import_count))
+            .collect()
+    };
+
+    let first = engine.analyze(request()).await.unwrap();
+    let second = engine.analyze(request()).await.unwrap();
+
+    assert_eq!(first.results[0].coupling.fan_in, 499);
+    assert_eq!(first.results[1].coupling.fan_in, 1);
+    assert_eq!(first.results[499].coupling.fan_in, 0);
+    assert_eq!(coupling(&first), coupling(&second));
+}
+
+#[tokio::test]
+async fn test_identical_findings_are_deduplicated() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    // Declaring the same name twice in one statement reports it twice
+    let source = "var shared = 1, shared = 2;\n";
+
+    let analyze = |dedupe| {
+        engine.analyze(AnalysisRequest {
+            files: vec![js_file("legacy.js", source)],
+            rules: Some(RuleConfig {
+                dedupe_findings: Some(dedupe),
+                ..Default::default()
+            }),
+        })
+    };
+
+    let count = |response: &rust_analysis_engine::types::AnalysisResponse| {
+        response.results[0]
+            .findings
+            .iter()
+            .filter(|f| f.rule_id == "global-pollution")
+            .count()
+    };
+
+    let raw = analyze(false).await.unwrap();
+    let deduped = analyze(true).await.unwrap();
+
+    assert_eq!(count(&raw), 2);
+    assert_eq!(count(&deduped), 1);
+    assert_eq!(deduped.summary.total_findings, raw.summary.total_findings - 1);
+}
+
+#[tokio::test]
+async fn test_short_functions_have_no_findings_by_default() {
+    let engine = AnalysisEngine::new().await.unwrap();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("tiny.js", "export function one() { return 1; }\n")],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(response.results[0].findings.is_empty());
+    assert_eq!(response.summary.total_findings, 0);
+}
+
+#[tokio::test]
+async fn test_summary_breaks_down_files_and_lines_by_language() {
+    let engine = AnalysisEngine::new().await.unwrap();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![
+                js_file("app.js", "export const a = 1;\nexport const b = 2;\n"),
+                js_file("util.js", "export const c = 3;\n"),
+                js_file("types.ts", "export type Id = string;\nexport type Name = string;\nexport const d = 4;\n"),
+            ],
+            rules: None,
+        })
+        .await
+        .unwrap();
+    let summary = &response.summary;
+
+    assert_eq!(summary.languages_analyzed.len(), 2);
+    assert_eq!(summary.languages_analyzed[&Language::JavaScript], 2);
+    assert_eq!(summary.languages_analyzed[&Language::TypeScript], 1);
+    assert_eq!(summary.lines_by_language[&Language::JavaScript], 3);
+    assert_eq!(summary.lines_by_language[&Language::TypeScript], 3);
+
+    let json = serde_json::to_value(summary).unwrap();
+    assert_eq!(json["languages_analyzed"]["TypeScript"], 1);
+}
+
+#[tokio::test]
+async fn test_identical_files_are_reported_under_each_name() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "leaked = 1;\nfunction f() { return leaked; }\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![
+                js_file("packages/a/shared.js", source),
+                js_file("packages/b/shared.js", source),
+            ],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(response.results.len(), 2);
+    assert_eq!(response.results[0].file_name, "packages/a/shared.js");
+    assert_eq!(response.results[1].file_name, "packages/b/shared.js");
+    assert_eq!(response.results[0].findings.len(), response.results[1].findings.len());
+    assert_eq!(response.summary.total_files, 2);
+    assert_eq!(
+        response.summary.total_findings,
+        2 * response.results[0].findings.len() as u32
+    );
+    assert_eq!(
+        response.summary.total_lines_analyzed,
+        2 * response.results[0].metrics.lines_of_code
+    );
+}
+
+#[tokio::test]
+async fn test_analyze_range_limits_reported_findings() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "let a;\nfirst = 1;\nlet b;\nsecond = 2;\n";
+    let file = |range| SourceFile {
+        analyze_range: range,
+        ..js_file("app.js", source)
+    };
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![file(None), file(Some((3, 4))), file(Some((40, 50)))],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    let lines = |index: usize| -> Vec<u32> {
+        response.results[index]
+            .findings
+            .iter()
+            .map(|f| f.location.line)
+            .collect()
+    };
+
+    assert_eq!(lines(0), vec![2, 4]);
+    assert_eq!(lines(1), vec![4]);
+    assert!(lines(2).is_empty());
+    assert_eq!(response.results[1].metrics.lines_of_code, 4);
+}
+
+#[tokio::test]
+async fn test_timing_breakdown_is_opt_in() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let analyze = |include_timing| {
+        engine.analyze(AnalysisRequest {
+            files: vec![js_file("app.js", "function add(a, b) { return a + b; }\n")],
+            rules: Some(RuleConfig {
+                include_timing: Some(include_timing),
+                ..Default::default()
+            }),
+        })
+    };
+
+    let timed = analyze(true).await.unwrap();
+    let timings = timed.results[0].timing_breakdown.as_ref().unwrap();
+    assert!(timings.contains_key("parse"));
+    assert!(timings.contains_key("metrics"));
+    assert!(timings.contains_key("rule:global-pollution"));
+
+    let untimed = analyze(false).await.unwrap();
+    assert!(untimed.results[0].timing_breakdown.is_none());
+}
+
+#[tokio::test]
+async fn test_json_config_secrets_are_reported() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let config = "{\n  \"service\": \"billing\",\n  \"apiKey\": \"a8f5f167f44f4964e6c998dee827110c\"\n}\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("config/production.json", config)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    let result = &response.results[0];
+    assert_eq!(result.language, rust_analysis_engine::types::Language::Json);
+    assert_eq!(result.findings.len(), 1);
+    assert_eq!(result.findings[0].rule_id, "hardcoded-secret");
+    assert_eq!(result.findings[0].location.line, 3);
+}
+
+#[tokio::test]
+async fn test_tiny_files_skip_halstead_metrics() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let larger = "export function sum(items) {\n  let total = 0;\n  for (const item of items) {\n    total += item;\n  }\n  return total;\n}\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("tiny.js", "export const x = 1;\n"), js_file("sum.js", larger)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+
+    assert!(response.results[0].metrics.halstead.is_none());
+    let halstead = response.results[1].metrics.halstead.as_ref().unwrap();
+    assert!(halstead.volume > 0.0);
+}
+
+#[tokio::test]
+async fn test_class_cohesion_is_reported_with_rule_off() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "export class Report {\n  load() { this.rows = fetchRows(); }\n  print() { console.log(this.title); }\n}\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("report.js", source)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+    let result = &response.results[0];
+
+    assert_eq!(result.metrics.class_cohesion.len(), 1);
+    assert_eq!(result.metrics.class_cohesion[0].class_name, "Report");
+    assert_eq!(result.metrics.class_cohesion[0].cohesion, 0.0);
+    assert!(result.findings.iter().all(|finding| finding.rule_id != "low-cohesion"));
+}
+
+#[tokio::test]
+async fn test_disk_cache_survives_engine_restart() {
+    let dir = std::env::temp_dir().join(format!("rae-engine-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let request = || AnalysisRequest {
+        files: vec![js_file("app.js", "leaked = 1;\nif (total === 0.1) {}\n")],
+        rules: None,
+    };
+
+    let first = AnalysisEngine::with_cache(Some(DiskCache::new(&dir, 1024 * 1024))).await.unwrap();
+    let fresh = first.analyze(request()).await.unwrap();
+    assert_eq!(first.parse_counts().len(), 1);
+    assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+
+    // A new engine, as after a runner restart, answers from disk without parsing
+    let second = AnalysisEngine::with_cache(Some(DiskCache::new(&dir, 1024 * 1024))).await.unwrap();
+    let cached = second.analyze(request()).await.unwrap();
+
+    assert!(second.parse_counts().is_empty());
+    assert_eq!(
+        serde_json::to_value(&cached.results).unwrap(),
+        serde_json::to_value(&fresh.results).unwrap()
+    );
+    assert_eq!(cached.summary.total_findings, fresh.summary.total_findings);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn test_suggestion_overrides_replace_built_in_text() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "export function route(a, b) {\n  if (a) { return 1; }\n  if (b) { return 2; }\n  return 3;\n}\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("route.js", source)],
+            rules: Some(RuleConfig {
+                complexity_threshold: Some(2),
+                suggestion_overrides: Some(std::collections::HashMap::from([(
+                    "high-complexity".to_string(),
+                    "See https://wiki.example.com/lint/{rule}".to_string(),
+                )])),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+
+    let finding = response.results[0]
+        .findings
+        .iter()
+        .find(|f| f.rule_id == "high-complexity")
+        .unwrap();
+    assert_eq!(
+        finding.suggestion.as_deref(),
+        Some("See https://wiki.example.com/lint/high-complexity")
+    );
+}
+
+#[tokio::test]
+async fn test_findings_link_to_rule_docs() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "export function route(a, b) {\n  if (a) { return 1; }\n  if (b) { return 2; }\n  return 3;\n}\n";
+    let analyze = |rule_doc_base_url: Option<&str>| {
+        engine.analyze(AnalysisRequest {
+            files: vec![js_file("route.js", source)],
+            rules: Some(RuleConfig {
+                complexity_threshold: Some(2),
+                rule_doc_base_url: rule_doc_base_url.map(str::to_string),
+                ..Default::default()
+            }),
+        })
+    };
+
+    let linked = analyze(Some("https://docs.example.com/rules/")).await.unwrap();
+    let finding = linked.results[0]
+        .findings
+        .iter()
+        .find(|f| f.rule_id == "high-complexity")
+        .unwrap();
+    assert_eq!(
+        finding.doc_url.as_deref(),
+        Some("https://docs.example.com/rules/high-complexity")
+    );
+
+    let unlinked = analyze(None).await.unwrap();
+    assert!(unlinked.results[0].findings.iter().all(|f| f.doc_url.is_none()));
+}
+
+#[tokio::test]
+async fn test_explain_mode_attaches_rule_rationale() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "const password = \"hunter2-prod-123\";\n";
+    let analyze = |explain| {
+        engine.analyze(AnalysisRequest {
+            files: vec![js_file("config.js", source)],
+            rules: Some(RuleConfig {
+                explain: Some(explain),
+                ..Default::default()
+            }),
+        })
+    };
+
+    let explained = analyze(true).await.unwrap();
+    let finding = explained.results[0]
+        .findings
+        .iter()
+        .find(|f| f.rule_id == "hardcoded-secret")
+        .unwrap();
+    let catalog = engine.rules();
+    let descriptor = catalog.iter().find(|rule| rule.id == "hardcoded-secret").unwrap();
+    assert_eq!(finding.rationale.as_deref(), Some(descriptor.rationale.as_str()));
+
+    let plain = analyze(false).await.unwrap();
+    assert!(plain.results[0].findings.iter().all(|f| f.rationale.is_none()));
+}
+
+#[tokio::test]
+async fn test_findings_name_their_enclosing_function() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "export function applyDiscount(order) {\n  return order.items.map((item) => {\n    if (item.ratio === 0.1) {\n      return item.price * 0.9;\n    }\n    return item.price;\n  });\n}\nconst top = 0.3 === total;\n";
+    let request = |include| AnalysisRequest {
+        files: vec![js_file("discount.js", source)],
+        rules: Some(RuleConfig {
+            include_enclosing_function: Some(include),
+            ..Default::default()
+        }),
+    };
+
+    let response = engine.analyze(request(true)).await.unwrap();
+    let float_findings: Vec<_> = response.results[0]
+        .findings
+        .iter()
+        .filter(|finding| finding.rule_id == "float-equality")
+        .collect();
+
+    assert_eq!(float_findings.len(), 2);
+    assert_eq!(float_findings[0].enclosing_function.as_deref(), Some("applyDiscount"));
+    assert_eq!(float_findings[1].enclosing_function, None);
+
+    let response = engine.analyze(request(false)).await.unwrap();
+    assert!(response.results[0].findings.iter().all(|finding| finding.enclosing_function.is_none()));
+}
+
+#[tokio::test]
+async fn test_min_severity_and_sorting_apply_before_the_summary() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "if (ratio === 0.1) { run(); }\nconst token = Math.random();\nArray.prototype.last = function () {};\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("app.js", source)],
+            rules: Some(RuleConfig {
+                min_severity: Some(Severity::Medium),
+                sort_findings: Some(true),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+    let findings = &response.results[0].findings;
+
+    assert!(findings.iter().all(|finding| finding.severity >= Severity::Medium));
+    assert_eq!(findings[0].rule_id, "prototype-pollution");
+    assert!(findings.windows(2).all(|pair| pair[0].severity >= pair[1].severity));
+    assert_eq!(response.summary.total_findings, findings.len() as u32);
+    assert!(!response.summary.findings_by_severity.contains_key("Low"));
+}
+
+#[tokio::test]
+async fn test_min_confidence_drops_heuristic_findings() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "if (ratio === 0.1) { run(); }\nconst token = Math.random();\n";
+    let analyze = |min_confidence| {
+        engine.analyze(AnalysisRequest {
+            files: vec![js_file("app.js", source)],
+            rules: Some(RuleConfig {
+                min_confidence,
+                ..Default::default()
+            }),
+        })
+    };
+
+    let all = analyze(None).await.unwrap();
+    let confident = analyze(Some(0.9)).await.unwrap();
+
+    let random = all.results[0]
+        .findings
+        .iter()
+        .find(|finding| finding.rule_id == "insecure-random")
+        .unwrap();
+    assert!(random.confidence < 0.9);
+    assert!(confident.results[0].findings.iter().all(|finding| finding.confidence >= 0.9));
+    assert!(confident.results[0].findings.iter().any(|finding| finding.rule_id == "float-equality"));
+    assert_eq!(confident.summary.total_findings, all.summary.total_findings - 1);
+}
+
+#[tokio::test]
+async fn test_unsupported_files_are_skipped_unless_failing_fast() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let files = vec![
+        js_file("app.js", "export const answer = 42;\n"),
+        js_file("tool.py", "print('hi')\n"),
+        js_file("lib.js", "export const other = 1;\n"),
+    ];
+    let analyze = |fail_on_unsupported| {
+        engine.analyze(AnalysisRequest {
+            files: files.clone(),
+            rules: Some(RuleConfig {
+                fail_on_unsupported: Some(fail_on_unsupported),
+                ..Default::default()
+            }),
+        })
+    };
+
+    let partial = analyze(false).await.unwrap();
+    assert_eq!(partial.summary.total_files, 2);
+    assert_eq!(partial.summary.unsupported_files, vec!["tool.py"]);
+    assert_eq!(partial.summary.files_skipped, 0);
+
+    let strict = analyze(true).await;
+    assert!(matches!(strict, Err(AnalysisError::UnsupportedLanguage { .. })));
+}
+
+#[tokio::test]
+async fn test_findings_grouped_by_top_level_directory() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let float_compare = "export function close(a) { return a === 0.1; }\n";
+    let files = vec![
+        js_file("src/math/close.js", float_compare),
+        js_file("src/app.js", float_compare),
+        js_file("tests/close.test.js", "export const clean = 1;\n"),
+        js_file("index.js", float_compare),
+    ];
+
+    let grouped = engine
+        .analyze(AnalysisRequest {
+            files: files.clone(),
+            rules: Some(RuleConfig {
+                group_by_directory: Some(true),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+    let by_directory = grouped.summary.findings_by_directory.unwrap();
+
+    assert_eq!(by_directory["src"], 2);
+    assert_eq!(by_directory["tests"], 0);
+    assert_eq!(by_directory["."], 1);
+    assert_eq!(by_directory.values().sum::<u32>(), grouped.summary.total_findings);
+
+    let default = engine.analyze(AnalysisRequest { files, rules: None }).await.unwrap();
+    assert!(default.summary.findings_by_directory.is_none());
+}
+
+#[tokio::test]
+async fn test_sample_rate_analyzes_a_stable_fraction() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let request = || AnalysisRequest {
+        files: (0..200)
+            .map(|i| js_file(&format!("src/module{}.js", i), &format!("export const value{} = {};\n", i, i)))
+            .collect(),
+        rules: Some(RuleConfig {
+            sample_rate: Some(0.25),
+            ..Default::default()
+        }),
+    };
+
+    let first = engine.analyze(request()).await.unwrap();
+    let second = engine.analyze(request()).await.unwrap();
+    let names = |response: &AnalysisResponse| -> Vec<String> {
+        response.results.iter().map(|result| result.file_name.clone()).collect()
+    };
+
+    assert!(first.summary.sampled);
+    assert_eq!(first.summary.sample_size, Some(first.summary.total_files));
+    assert!((30..=70).contains(&first.summary.total_files), "sampled {}", first.summary.total_files);
+    assert_eq!(names(&first), names(&second));
+
+    // Sessions sample the same files
+    engine.open_session("sampled");
+    let session = engine.analyze_session("sampled", request()).await.unwrap();
+    assert_eq!(names(&session), names(&first));
+    assert_eq!(session.summary.sample_size, first.summary.sample_size);
+}
+
+#[tokio::test]
+async fn test_identical_findings_collapse_across_files() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    // Same code at a different line in each file, so the files aren't duplicates
+    let files = (0..3)
+        .map(|i| {
+            let source = format!("{}export const isTenth = (ratio) => ratio === 0.1;\n", "\n".repeat(i));
+            js_file(&format!("pkg{}/check.js", i), &source)
+        })
+        .collect();
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files,
+            rules: Some(RuleConfig {
+                dedupe_across_files: Some(true),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+    let deduped = response.deduped_findings.unwrap();
+
+    assert_eq!(deduped.len(), 1);
+    assert_eq!(deduped[0].finding.rule_id, "float-equality");
+    let occurrences: Vec<_> = deduped[0]
+        .occurrences
+        .iter()
+        .map(|occurrence| (occurrence.file_name.as_str(), occurrence.line))
+        .collect();
+    assert_eq!(occurrences, vec![("pkg0/check.js", 1), ("pkg1/check.js", 2), ("pkg2/check.js", 3)]);
+    assert!(response.results.iter().all(|result| result.findings.is_empty()));
+    assert_eq!(response.summary.total_findings, 3);
+}
+
+#[tokio::test]
+async fn test_flattened_findings_cover_every_file() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let files = vec![
+        js_file("a.js", "if (x === 0.1) { leaked = 1; }\n"),
+        js_file("b.js", "export const clean = 1;\n"),
+        js_file("c.js", "if (y == 0.2) {}\n"),
+    ];
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files,
+            rules: Some(RuleConfig {
+                flatten_findings: Some(true),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+    let all_findings = response.all_findings.unwrap();
+
+    let per_file: usize = response.results.iter().map(|result| result.findings.len()).sum();
+    assert_eq!(all_findings.len(), per_file);
+    assert!(per_file >= 2);
+    assert_eq!(all_findings[0].file_name, "a.js");
+    assert_eq!(all_findings.last().unwrap().file_name, "c.js");
+    assert_eq!(all_findings[0].finding.rule_id, response.results[0].findings[0].rule_id);
+}
+
+#[tokio::test]
+async fn test_metrics_only_skips_every_rule() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    let source = "function check(total) {\n  if (total === 0.1) { leaked = 1; }\n  return total;\n}\n";
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("check.js", source)],
+            rules: Some(RuleConfig {
+                metrics_only: Some(true),
+                ..Default::default()
+            }),
+        })
+        .await
+        .unwrap();
+    let result = &response.results[0];
+
+    assert!(result.findings.is_empty());
+    assert_eq!(response.summary.total_findings, 0);
+    assert!(result.rules_executed.is_empty());
+    assert!(result.rules_skipped.iter().all(|skipped| skipped.reason == "metrics-only mode"));
+    assert!(result.rules_skipped.iter().any(|skipped| skipped.rule_id == "high-coupling"));
+    assert_eq!(result.metrics.lines_of_code, 4);
+    assert_eq!(result.metrics.functions_count, 1);
+    assert!(result.metrics.halstead.is_some());
+
+    let normal = engine
+        .analyze(AnalysisRequest {
+            files: vec![js_file("check.js", source)],
+            rules: None,
+        })
+        .await
+        .unwrap();
+    assert!(!normal.results[0].findings.is_empty());
+}
+
+#[tokio::test]
+async fn test_parser_override_selects_parser_but_keeps_language() {
+    let engine = AnalysisEngine::new().await.unwrap();
+    // JSX in a `.ts` file only parses with the JavaScript parser
+    let source = "const greet = (name) => `hi ${name}`;\nexport const view = <Greeting name=\"a\" />;\n";
+    let file = |parser_override| SourceFile {
+        parser_override,
+        ..js_file("legacy.ts", source)
+    };
+
+    let checked = engine
+        .check_parse(ParseCheckRequest {
+            files: vec![file(None), file(Some(Language::JavaScript))],
+        })
+        .await
+        .unwrap();
+    assert!(!checked.results[0].parses);
+    assert!(checked.results[1].parses);
+
+    let response = engine
+        .analyze(AnalysisRequest {
+            files: vec![file(Some(Language::JavaScript))],
+            rules: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(response.results[0].language, Language::TypeScript);
+    assert_eq!(response.results[0].metrics.functions_count, 1);
+
+    let unsupported = engine
+        .analyze(AnalysisRequest {
+            files: vec![file(Some(Language::Python))],
+            rules: None,
+        })
+        .await;
+    assert!(unsupported.is_err());
+}
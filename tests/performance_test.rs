@@ -23,7 +23,7 @@ async fn test_parsing_performance() {
     assert!(duration.as_millis() < 100, "Parsing took too long: {}ms", duration.as_millis());
     
     // Verify we found the expected structures
-    assert!(result.functions.len() > 0);
+    assert!(!result.functions.is_empty());
 }
 
 #[tokio::test]
@@ -36,16 +36,22 @@ async fn test_analysis_engine_performance() {
             name: "file1.js".to_string(),
             content: generate_test_javascript(500),
             language: None,
+            analyze_range: None,
+            parser_override: None,
         },
         SourceFile {
             name: "file2.js".to_string(),
             content: generate_test_javascript(500),
             language: None,
+            analyze_range: None,
+            parser_override: None,
         },
         SourceFile {
             name: "file3.js".to_string(),
             content: generate_test_javascript(500),
             language: None,
+            analyze_range: None,
+            parser_override: None,
         },
     ];
     
@@ -102,7 +108,7 @@ fn generate_test_javascript(target_lines: usize) -> String {
     content.push_str("import React from 'react';\n");
     content.push_str("import { useState, useEffect } from 'react';\n");
     content.push_str("const fs = require('fs');\n");
-    content.push_str("\n");
+    content.push('\n');
     
     let mut current_lines = 4;
     let mut function_count = 0;
@@ -178,7 +184,7 @@ const arrow{} = (x, y) => {{
         if current_lines < target_lines - 10 {
             content.push_str(&format!("const variable{} = 'test value {}';\n", function_count, function_count));
             content.push_str(&format!("let counter{} = {};\n", function_count, function_count));
-            content.push_str("\n");
+            content.push('\n');
             current_lines += 3;
         }
     }
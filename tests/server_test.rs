@@ -0,0 +1,975 @@
+use axum::{
+    body::Body,
+    http::{header, Method, Request},
+    Router,
+};
+use rust_analysis_engine::{
+    analysis::{cache::DiskCache, AnalysisEngine},
+    server::{Server, ServerConfig},
+};
+use tower::ServiceExt;
+
+async fn router_with_config(config: ServerConfig) -> Router {
+    Server::with_config(config).await.unwrap().create_router()
+}
+
+fn preflight(origin: &str) -> Request<Body> {
+    Request::builder()
+        .method(Method::OPTIONS)
+        .uri("/analyze")
+        .header(header::ORIGIN, origin)
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_cors_allows_configured_origin() {
+    let app = router_with_config(ServerConfig {
+        cors_allowed_origins: Some(vec!["https://app.example.com".to_string()]),
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let response = app.oneshot(preflight("https://app.example.com")).await.unwrap();
+
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        "https://app.example.com"
+    );
+}
+
+#[tokio::test]
+async fn test_cors_rejects_disallowed_origin() {
+    let app = router_with_config(ServerConfig {
+        cors_allowed_origins: Some(vec!["https://app.example.com".to_string()]),
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let response = app.oneshot(preflight("https://evil.example.com")).await.unwrap();
+
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn test_cors_permissive_when_unconfigured() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let response = app.oneshot(preflight("https://anything.example.com")).await.unwrap();
+
+    assert_eq!(
+        response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).unwrap(),
+        "*"
+    );
+}
+
+async fn post_json(app: Router, uri: &str, body: serde_json::Value) -> (u16, serde_json::Value) {
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status().as_u16();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+    (status, serde_json::from_slice(&bytes).unwrap())
+}
+
+#[tokio::test]
+async fn test_streamed_analysis_matches_buffered() {
+    let request = serde_json::json!({
+        "files": [
+            { "name": "a.js", "content": "function a() { return 1; }" },
+            { "name": "b.ts", "content": "const b = (x: number) => x * 2;" }
+        ]
+    });
+
+    let (status, buffered) = post_json(
+        router_with_config(ServerConfig::default()).await,
+        "/analyze",
+        request.clone(),
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let (status, streamed) = post_json(
+        router_with_config(ServerConfig::default()).await,
+        "/analyze/stream",
+        request,
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    assert_eq!(streamed["results"], buffered["results"]);
+    assert_eq!(streamed["summary"], buffered["summary"]);
+}
+
+fn analyze_request(request_id: Option<&str>) -> Request<Body> {
+    let body = serde_json::json!({
+        "files": [{ "name": "app.js", "content": "const x = 1;" }]
+    });
+    let mut builder = Request::builder()
+        .method(Method::POST)
+        .uri("/analyze")
+        .header(header::CONTENT_TYPE, "application/json");
+    if let Some(id) = request_id {
+        builder = builder.header("x-request-id", id);
+    }
+    builder.body(Body::from(body.to_string())).unwrap()
+}
+
+#[tokio::test]
+async fn test_request_id_round_trips() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let response = app.oneshot(analyze_request(Some("trace-abc-123"))).await.unwrap();
+
+    assert_eq!(response.status().as_u16(), 200);
+    assert_eq!(response.headers().get("x-request-id").unwrap(), "trace-abc-123");
+    let timing = response.headers().get("server-timing").unwrap().to_str().unwrap();
+    assert!(timing.starts_with("analyze;dur="));
+}
+
+#[tokio::test]
+async fn test_request_id_generated_when_absent() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let first = app.clone().oneshot(analyze_request(None)).await.unwrap();
+    let second = app.oneshot(analyze_request(None)).await.unwrap();
+
+    let first_id = first.headers().get("x-request-id").unwrap();
+    let second_id = second.headers().get("x-request-id").unwrap();
+    assert!(!first_id.is_empty());
+    assert_ne!(first_id, second_id);
+}
+
+#[tokio::test]
+async fn test_config_endpoint_reports_effective_settings() {
+    let app = router_with_config(ServerConfig {
+        max_files: 7,
+        cors_allowed_origins: Some(vec!["https://app.example.com".to_string()]),
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let request = Request::builder().uri("/config").body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let config: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+    assert_eq!(config["max_files"], 7);
+    assert_eq!(config["max_file_size_bytes"], 1024 * 1024);
+    assert_eq!(config["cors_allowed_origins"][0], "https://app.example.com");
+    assert_eq!(config["auth_enabled"], false);
+    assert_eq!(config["rate_limiting_enabled"], false);
+    assert_eq!(config["callback_signing_enabled"], false);
+}
+
+#[tokio::test]
+async fn test_config_endpoint_redacts_secrets() {
+    let app = router_with_config(ServerConfig {
+        callback_secret: Some("hunter2-callback-secret".to_string()),
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let request = Request::builder().uri("/config").body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body = String::from_utf8(bytes.to_vec()).unwrap();
+    let config: serde_json::Value = serde_json::from_str(&body).unwrap();
+
+    assert!(!body.contains("hunter2-callback-secret"));
+    assert!(config.get("callback_secret").is_none());
+    assert_eq!(config["callback_signing_enabled"], true);
+}
+
+#[tokio::test]
+async fn test_configured_max_files_is_enforced() {
+    let app = router_with_config(ServerConfig {
+        max_files: 1,
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let (status, _) = post_json(
+        app,
+        "/analyze",
+        serde_json::json!({
+            "files": [
+                { "name": "a.js", "content": "const a = 1;" },
+                { "name": "b.js", "content": "const b = 2;" }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_multi_config_compares_finding_counts() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, body) = post_json(
+        app,
+        "/analyze/multi-config",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "if (total === 0.1) { leaked = 1; }\n" }],
+            "configs": [
+                {},
+                { "disabled_rules": ["float-equality"] }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 200);
+    let summaries = body["summaries"].as_array().unwrap();
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0]["total_files"], 1);
+    assert_eq!(summaries[1]["total_files"], 1);
+    assert_eq!(
+        summaries[0]["total_findings"].as_u64().unwrap(),
+        summaries[1]["total_findings"].as_u64().unwrap() + 1
+    );
+}
+
+#[tokio::test]
+async fn test_session_retains_files_for_cross_file_results() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let (status, opened) = post_json(app.clone(), "/session", serde_json::json!({})).await;
+    assert_eq!(status, 200);
+    let uri = format!("/analyze?session={}", opened["session"].as_str().unwrap());
+
+    let utils = serde_json::json!({ "name": "src/utils.js", "content": "export const one = 1;\n" });
+    let (status, first) = post_json(app.clone(), &uri, serde_json::json!({ "files": [&utils] })).await;
+    assert_eq!(status, 200);
+    assert_eq!(first["results"][0]["coupling"]["fan_in"], 0);
+
+    // Only the importer is sent; the retained utils.js sees it
+    let app_file = serde_json::json!({ "name": "src/app.js", "content": "import { one } from './utils';\nconsole.log(one);\n" });
+    let (status, second) = post_json(app.clone(), &uri, serde_json::json!({ "files": [app_file] })).await;
+    assert_eq!(status, 200);
+    assert_eq!(second["summary"]["total_files"], 2);
+    assert_eq!(second["results"][0]["file_name"], "src/utils.js");
+    assert_eq!(second["results"][0]["coupling"]["fan_in"], 1);
+    assert_eq!(second["results"][1]["coupling"]["import_count"], 1);
+
+    let (status, error) = post_json(app, "/analyze?session=missing", serde_json::json!({ "files": [utils] })).await;
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["message"], "Validation error: Unknown session: missing");
+}
+
+#[tokio::test]
+async fn test_sessions_can_be_closed_and_are_bounded() {
+    let app = router_with_config(ServerConfig {
+        max_sessions: 2,
+        ..ServerConfig::default()
+    })
+    .await;
+    let file = serde_json::json!({ "files": [{ "name": "a.js", "content": "export const a = 1;\n" }] });
+    let mut tokens = Vec::new();
+    for _ in 0..3 {
+        let (_, opened) = post_json(app.clone(), "/session", serde_json::json!({})).await;
+        tokens.push(opened["session"].as_str().unwrap().to_string());
+    }
+
+    // Opening the third closed the least recently used one
+    let (status, _) = post_json(app.clone(), &format!("/analyze?session={}", tokens[0]), file.clone()).await;
+    assert_eq!(status, 400);
+    let (status, _) = post_json(app.clone(), &format!("/analyze?session={}", tokens[1]), file.clone()).await;
+    assert_eq!(status, 200);
+
+    let close = |token: &str| {
+        Request::builder()
+            .method(Method::DELETE)
+            .uri(format!("/session/{}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+    let response = app.clone().oneshot(close(&tokens[1])).await.unwrap();
+    assert_eq!(response.status().as_u16(), 204);
+    let response = app.clone().oneshot(close(&tokens[1])).await.unwrap();
+    assert_eq!(response.status().as_u16(), 404);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let error: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(error["error"]["code"], "not_found");
+    let response = app.clone().oneshot(close("never-opened")).await.unwrap();
+    assert_eq!(response.status().as_u16(), 404);
+    let (status, _) = post_json(app, &format!("/analyze?session={}", tokens[1]), file).await;
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_idle_sessions_expire() {
+    let app = router_with_config(ServerConfig {
+        session_ttl_secs: 0,
+        ..ServerConfig::default()
+    })
+    .await;
+
+    let (_, opened) = post_json(app.clone(), "/session", serde_json::json!({})).await;
+    let uri = format!("/analyze?session={}", opened["session"].as_str().unwrap());
+    let file = serde_json::json!({ "files": [{ "name": "a.js", "content": "export const a = 1;\n" }] });
+    let (status, error) = post_json(app, &uri, file).await;
+
+    assert_eq!(status, 400);
+    assert!(error["error"]["message"].as_str().unwrap().contains("Unknown session"));
+}
+
+#[tokio::test]
+async fn test_session_file_count_is_capped() {
+    let app = router_with_config(ServerConfig {
+        max_files: 2,
+        ..ServerConfig::default()
+    })
+    .await;
+    let (_, opened) = post_json(app.clone(), "/session", serde_json::json!({})).await;
+    let uri = format!("/analyze?session={}", opened["session"].as_str().unwrap());
+    let files = |names: &[&str]| {
+        let files: Vec<_> = names
+            .iter()
+            .map(|name| serde_json::json!({ "name": name, "content": "export const a = 1;\n" }))
+            .collect();
+        serde_json::json!({ "files": files })
+    };
+
+    let (status, _) = post_json(app.clone(), &uri, files(&["a.js", "b.js"])).await;
+    assert_eq!(status, 200);
+    // Replacing a retained file doesn't add to the count
+    let (status, _) = post_json(app.clone(), &uri, files(&["a.js"])).await;
+    assert_eq!(status, 200);
+
+    let (status, error) = post_json(app.clone(), &uri, files(&["c.js"])).await;
+    assert_eq!(status, 400);
+    assert_eq!(error["error"]["field"], "files");
+    assert!(error["error"]["message"].as_str().unwrap().contains("3 files (max: 2)"));
+
+    // The rejected file wasn't retained
+    let (status, body) = post_json(app, &uri, files(&["b.js"])).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["summary"]["total_files"], 2);
+}
+
+#[tokio::test]
+async fn test_validation_errors_name_the_offending_field() {
+    let app = router_with_config(ServerConfig {
+        max_file_size_bytes: 16,
+        ..ServerConfig::default()
+    })
+    .await;
+    let files = serde_json::json!([
+        { "name": "small.js", "content": "let a = 1;" },
+        { "name": "large.js", "content": "const value = 'far too long for the limit';" },
+    ]);
+
+    let (status, body) = post_json(app, "/analyze", serde_json::json!({ "files": files })).await;
+
+    assert_eq!(status, 413);
+    assert_eq!(body["error"]["field"], "files[1].content");
+    assert_eq!(body["error"]["code"], "file_too_large");
+    assert_eq!(body["error"]["message"], "File too large: 43 bytes exceeds limit of 16 bytes");
+}
+
+#[tokio::test]
+async fn test_multi_config_requires_configs() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, _) = post_json(
+        app,
+        "/analyze/multi-config",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "const x = 1;" }],
+            "configs": []
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_rules_endpoint_lists_catalog() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let rules: serde_json::Value = serde_json::from_str(&get_text(app, "/rules").await).unwrap();
+    let rules = rules.as_array().unwrap();
+
+    let find = |id: &str| rules.iter().find(|rule| rule["id"] == id).unwrap();
+    assert!(find("high-complexity")["rationale"].as_str().is_some_and(|text| !text.is_empty()));
+    assert_eq!(find("high-coupling")["enabled_by_default"], true);
+    assert_eq!(find("await-in-loop")["enabled_by_default"], false);
+    assert!(rules.iter().all(|rule| rule["rationale"].as_str().is_some_and(|text| text.ends_with('.'))));
+}
+
+#[tokio::test]
+async fn test_schema_describes_request_and_response() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let schema: serde_json::Value = serde_json::from_str(&get_text(app, "/schema").await).unwrap();
+
+    let request = &schema["request"];
+    assert_eq!(request["title"], "AnalysisRequest");
+    assert!(request["properties"]["files"].is_object());
+    assert!(request["properties"]["rules"].is_object());
+    assert!(request["definitions"]["RuleConfig"]["properties"]["complexity_threshold"].is_object());
+    assert!(schema["response"]["properties"]["summary"].is_object());
+}
+
+async fn get_text(app: Router, uri: &str) -> String {
+    let request = Request::builder().uri(uri).body(Body::empty()).unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_metrics_count_parses_per_language() {
+    let app = router_with_config(ServerConfig::default()).await;
+
+    let (status, _) = post_json(
+        app.clone(),
+        "/analyze",
+        serde_json::json!({
+            "files": [
+                { "name": "a.js", "content": "const a = 1;" },
+                { "name": "b.js", "content": "const b = 2;" },
+                { "name": "c.ts", "content": "const c: number = 3;" }
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let metrics = get_text(app, "/metrics").await;
+    assert!(metrics.contains("# TYPE parses_total counter"));
+    assert!(metrics.contains("parses_total{language=\"JavaScript\"} 2"));
+    assert!(metrics.contains("parses_total{language=\"TypeScript\"} 1"));
+}
+
+#[tokio::test]
+async fn test_api_surface_lists_only_exported_symbols() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let content = r#"
+export async function getUser(id: string, fresh?: boolean): Promise<User> { return load(id); }
+function load(id: string) { return cache[id]; }
+export const toName = (user: User): string => user.name;
+const internal = () => 1;
+export class UserStore {}
+class Cache {}
+export interface User { name: string; }
+function format(user) { return user.name; }
+export { format };
+"#;
+
+    let (status, body) = post_json(
+        app,
+        "/api-surface",
+        serde_json::json!({ "files": [{ "name": "users.ts", "content": content }] }),
+    )
+    .await;
+    assert_eq!(status, 200);
+
+    let surface = &body["results"][0];
+    assert_eq!(surface["language"], "TypeScript");
+    assert_eq!(
+        surface["functions"],
+        serde_json::json!([
+            { "name": "getUser", "line": 2, "signature": "(id: string, fresh?: boolean): Promise<User>" },
+            { "name": "toName", "line": 4, "signature": "(user: User): string" },
+            { "name": "format", "line": 9, "signature": "(user)" },
+        ])
+    );
+    let classes: Vec<_> = surface["classes"].as_array().unwrap().iter().map(|c| c["name"].clone()).collect();
+    assert_eq!(classes, vec!["UserStore", "User"]);
+}
+
+#[tokio::test]
+async fn test_github_format_emits_workflow_commands() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let body = serde_json::json!({
+        "files": [{ "name": "src/app.js", "content": "export const check = (total) => total === 0.1;\n" }]
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/analyze?format=github")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let output = String::from_utf8(bytes.to_vec()).unwrap();
+
+    let line = output
+        .lines()
+        .find(|line| line.contains("title=float-equality"))
+        .unwrap();
+    assert!(line.starts_with("::warning file=src/app.js,line=1,col="));
+    assert!(line.contains(",title=float-equality::"));
+}
+
+#[tokio::test]
+async fn test_query_options_compose_with_format() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let body = serde_json::json!({
+        "files": [{
+            "name": "src/app.js",
+            "content": "if (ratio === 0.1) { run(); }\nArray.prototype.last = function () {};\n"
+        }]
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/analyze?format=github&min_severity=high")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let output = String::from_utf8(bytes.to_vec()).unwrap();
+
+    assert!(output.contains("title=prototype-pollution"));
+    assert!(!output.contains("title=float-equality"));
+    assert!(output.lines().all(|line| line.starts_with("::error ")));
+}
+
+#[tokio::test]
+async fn test_github_format_annotates_every_deduped_occurrence() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let body = serde_json::json!({
+        "files": [
+            { "name": "src/a.js", "content": "export const check = (total) => total === 0.1;\n" },
+            { "name": "src/b.js", "content": "\nexport const check = (total) => total === 0.1;\n" }
+        ],
+        "rules": { "dedupe_across_files": true }
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/analyze?format=github")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let output = String::from_utf8(bytes.to_vec()).unwrap();
+
+    let annotations: Vec<_> = output
+        .lines()
+        .filter(|line| line.contains("title=float-equality"))
+        .collect();
+    assert_eq!(annotations.len(), 2);
+    assert!(annotations[0].starts_with("::warning file=src/a.js,line=1,"));
+    assert!(annotations[1].starts_with("::warning file=src/b.js,line=2,"));
+}
+
+#[tokio::test]
+async fn test_github_format_rejects_minimal_response() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, body) = post_json(
+        app,
+        "/analyze?format=github",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "const x = 1;" }],
+            "rules": { "minimal_response": true }
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["field"], "rules.minimal_response");
+}
+
+#[tokio::test]
+async fn test_unknown_min_severity_is_rejected() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, body) = post_json(
+        app,
+        "/analyze?min_severity=severe",
+        serde_json::json!({ "files": [{ "name": "app.js", "content": "const x = 1;" }] }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+    assert_eq!(body["error"]["field"], "min_severity");
+}
+
+#[tokio::test]
+async fn test_unknown_format_is_rejected() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, _) = post_json(
+        app,
+        "/analyze?format=xml",
+        serde_json::json!({ "files": [{ "name": "app.js", "content": "const x = 1;" }] }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_parse_check_reports_syntax_errors() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, body) = post_json(
+        app,
+        "/parse/check",
+        serde_json::json!({
+            "files": [
+                { "name": "ok.ts", "content": "export const x: number = 1;\n" },
+                { "name": "broken.js", "content": "const a = 1;\n\nif (a {\n" }
+            ]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 200);
+    let results = body["results"].as_array().unwrap();
+    assert_eq!(results[0]["file_name"], "ok.ts");
+    assert_eq!(results[0]["parses"], true);
+    assert_eq!(results[0]["error_count"], 0);
+    assert!(results[0]["first_error_line"].is_null());
+    assert_eq!(results[1]["parses"], false);
+    assert!(results[1]["error_count"].as_u64().unwrap() >= 1);
+    assert_eq!(results[1]["first_error_line"], 3);
+    assert!(results[1].get("metrics").is_none());
+}
+
+#[tokio::test]
+async fn test_rules_validate_reports_config_errors() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, body) = post_json(
+        app.clone(),
+        "/rules/validate",
+        serde_json::json!({
+            "max_statements": 0,
+            "disabled_rules": ["float-equality", "no-such-rule"],
+            "secret_name_patterns": ["*_dsn", ""]
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 200);
+    assert_eq!(body["valid"], false);
+    let errors = body["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 3);
+    assert!(errors[1].as_str().unwrap().contains("'no-such-rule'"));
+
+    let (status, body) = post_json(app.clone(), "/rules/validate", serde_json::json!({})).await;
+    assert_eq!(status, 200);
+    assert_eq!(body["valid"], true);
+
+    // `/analyze` rejects the same config
+    let (status, body) = post_json(
+        app,
+        "/analyze",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "const x = 1;" }],
+            "rules": { "secret_name_patterns": [""] }
+        }),
+    )
+    .await;
+    assert_eq!(status, 400);
+    assert!(body["error"]["message"].as_str().unwrap().contains("secret_name_patterns"));
+}
+
+#[tokio::test]
+async fn test_oversized_response_is_truncated() {
+    let app = router_with_config(ServerConfig {
+        max_response_bytes: 1500,
+        ..ServerConfig::default()
+    })
+    .await;
+    let files: Vec<serde_json::Value> = (0..20)
+        .map(|i| serde_json::json!({ "name": format!("f{}.js", i), "content": "if (x === 0.1) { leaked = 1; }\n" }))
+        .collect();
+
+    let (status, body) = post_json(app, "/analyze", serde_json::json!({ "files": files })).await;
+
+    assert_eq!(status, 200);
+    let summary = &body["summary"];
+    assert_eq!(summary["truncated"], true);
+    assert!(summary["truncation_reason"].as_str().unwrap().contains("1500 bytes"));
+    assert_eq!(summary["total_files"], 20);
+    assert!(body["results"].as_array().unwrap().len() < 20);
+    assert!(body.to_string().len() <= 1500);
+}
+
+#[tokio::test]
+async fn test_deduped_response_is_truncated_to_budget() {
+    let app = router_with_config(ServerConfig {
+        max_response_bytes: 4000,
+        ..ServerConfig::default()
+    })
+    .await;
+    let files: Vec<serde_json::Value> = (0..50)
+        .map(|i| serde_json::json!({ "name": format!("f{}.js", i), "content": "if (x === 0.1) { leaked = 1; }\n" }))
+        .collect();
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri("/analyze")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(
+            serde_json::json!({ "files": files, "rules": { "dedupe_across_files": true } }).to_string(),
+        ))
+        .unwrap();
+
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status().as_u16(), 200);
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    assert!(bytes.len() <= 4000, "{} bytes", bytes.len());
+
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["summary"]["truncated"], true);
+    let kept: Vec<_> = body["results"].as_array().unwrap().iter().map(|r| r["file_name"].clone()).collect();
+    assert!(!kept.is_empty() && kept.len() < 50);
+    let omitted = format!("{} of 50 file results omitted", 50 - kept.len());
+    assert!(body["summary"]["truncation_reason"].as_str().unwrap().contains(&omitted));
+    for deduped in body["deduped_findings"].as_array().unwrap() {
+        for occurrence in deduped["occurrences"].as_array().unwrap() {
+            assert!(kept.contains(&occurrence["file_name"]));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_streamed_response_is_not_truncated() {
+    let app = router_with_config(ServerConfig {
+        max_response_bytes: 1500,
+        ..ServerConfig::default()
+    })
+    .await;
+    let files: Vec<serde_json::Value> = (0..20)
+        .map(|i| serde_json::json!({ "name": format!("f{}.js", i), "content": "if (x === 0.1) { leaked = 1; }\n" }))
+        .collect();
+
+    let (status, body) = post_json(app, "/analyze/stream", serde_json::json!({ "files": files })).await;
+
+    assert_eq!(status, 200);
+    assert_eq!(body["summary"]["truncated"], false);
+    assert_eq!(body["results"].as_array().unwrap().len(), 20);
+}
+
+#[tokio::test]
+async fn test_minimal_response_has_only_the_summary() {
+    let request = |minimal: bool| {
+        serde_json::json!({
+            "files": [
+                { "name": "a.js", "content": "if (x === 0.1) { leaked = 1; }\n" },
+                { "name": "b.js", "content": "export const ok = 1;\n" }
+            ],
+            "rules": { "minimal_response": minimal, "flatten_findings": true }
+        })
+    };
+
+    let (_, full) = post_json(router_with_config(ServerConfig::default()).await, "/analyze", request(false)).await;
+    let (status, minimal) = post_json(router_with_config(ServerConfig::default()).await, "/analyze", request(true)).await;
+
+    assert_eq!(status, 200);
+    let keys: Vec<&String> = minimal.as_object().unwrap().keys().collect();
+    assert_eq!(keys, vec!["execution_time_ms", "summary"]);
+    assert_eq!(minimal["summary"], full["summary"]);
+    assert_eq!(minimal["summary"]["total_files"], 2);
+}
+
+#[tokio::test]
+async fn test_preloaded_file_is_a_cache_hit() {
+    let dir = std::env::temp_dir().join(format!("rae-preload-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let engine = AnalysisEngine::with_cache(Some(DiskCache::new(&dir, u64::MAX))).await.unwrap();
+    let app = Server::with_engine(engine, ServerConfig::default()).create_router();
+    let files = serde_json::json!([{ "name": "app.js", "content": "if (x === 0.1) { leaked = 1; }\n" }]);
+
+    let (status, body) = post_json(app.clone(), "/cache/preload", serde_json::json!({ "files": files })).await;
+    assert_eq!(status, 202);
+    assert!(!body["job_id"].as_str().unwrap().is_empty());
+    assert_eq!(body["files"], 1);
+
+    // The preload runs in the background; wait for its cache entry
+    for _ in 0..200 {
+        if std::fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_some()) {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    }
+
+    let (status, _) = post_json(app.clone(), "/analyze", serde_json::json!({ "files": files })).await;
+    assert_eq!(status, 200);
+    let metrics = get_text(app, "/metrics").await;
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    assert!(metrics.contains("cache_hits_total 1"), "{}", metrics);
+    assert!(metrics.contains("cache_misses_total 1"), "{}", metrics);
+}
+
+#[tokio::test]
+async fn test_preloads_over_the_job_limit_are_refused() {
+    let dir = std::env::temp_dir().join(format!("rae-preload-limit-{}", std::process::id()));
+    let engine = AnalysisEngine::with_cache(Some(DiskCache::new(&dir, u64::MAX))).await.unwrap();
+    let app = Server::with_engine(
+        engine,
+        ServerConfig {
+            max_async_jobs: 0,
+            ..ServerConfig::default()
+        },
+    )
+    .create_router();
+
+    let (status, body) = post_json(
+        app,
+        "/cache/preload",
+        serde_json::json!({ "files": [{ "name": "app.js", "content": "const x = 1;" }] }),
+    )
+    .await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    assert_eq!(status, 429);
+    assert_eq!(body["error"]["code"], "too_many_requests");
+}
+
+#[tokio::test]
+async fn test_preload_requires_disk_cache() {
+    let engine = AnalysisEngine::with_cache(None).await.unwrap();
+    let app = Server::with_engine(engine, ServerConfig::default()).create_router();
+    let (status, _) = post_json(
+        app,
+        "/cache/preload",
+        serde_json::json!({ "files": [{ "name": "app.js", "content": "const x = 1;" }] }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_async_analysis_posts_signed_result_to_callback() {
+    use axum::{extract::State, http::HeaderMap, http::StatusCode, routing::post};
+    use rust_analysis_engine::server::webhook;
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+    use tokio::sync::mpsc;
+
+    // Mock receiver: fails the first delivery, accepts the retry
+    type Received = (HeaderMap, Vec<u8>);
+    let (sender, mut received) = mpsc::unbounded_channel::<Received>();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let receiver = Router::new()
+        .route(
+            "/hook",
+            post(
+                |State((sender, attempts)): State<(mpsc::UnboundedSender<Received>, Arc<AtomicUsize>)>,
+                 headers: HeaderMap,
+                 body: axum::body::Bytes| async move {
+                    sender.send((headers, body.to_vec())).unwrap();
+                    match attempts.fetch_add(1, Ordering::SeqCst) {
+                        0 => StatusCode::INTERNAL_SERVER_ERROR,
+                        _ => StatusCode::OK,
+                    }
+                },
+            ),
+        )
+        .with_state((sender, attempts));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let callback_url = format!("http://{}/hook", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, receiver).await.unwrap() });
+
+    let app = router_with_config(ServerConfig {
+        callback_secret: Some("s3cret".to_string()),
+        callback_allowed_hosts: Some(vec!["127.0.0.1".to_string()]),
+        ..ServerConfig::default()
+    })
+    .await;
+    let (status, body) = post_json(
+        app,
+        "/analyze/async",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "if (x === 0.1) {}\n" }],
+            "callback_url": callback_url
+        }),
+    )
+    .await;
+    assert_eq!(status, 202);
+    let job_id = body["job_id"].as_str().unwrap().to_string();
+
+    let timeout = std::time::Duration::from_secs(10);
+    let (_, first) = tokio::time::timeout(timeout, received.recv()).await.unwrap().unwrap();
+    let (headers, retried) = tokio::time::timeout(timeout, received.recv()).await.unwrap().unwrap();
+
+    assert_eq!(first, retried);
+    assert_eq!(headers["x-job-id"], job_id.as_str());
+    assert_eq!(
+        headers["x-signature-256"].to_str().unwrap(),
+        format!("sha256={}", webhook::sign(b"s3cret", &retried))
+    );
+    let result: serde_json::Value = serde_json::from_slice(&retried).unwrap();
+    assert_eq!(result["summary"]["total_files"], 1);
+    assert_eq!(result["results"][0]["file_name"], "app.js");
+}
+
+#[tokio::test]
+async fn test_async_analysis_requires_http_callback() {
+    let app = router_with_config(ServerConfig::default()).await;
+    let (status, _) = post_json(
+        app,
+        "/analyze/async",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "const x = 1;" }],
+            "callback_url": "file:///etc/passwd"
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 400);
+}
+
+#[tokio::test]
+async fn test_async_analysis_rejects_internal_callback_hosts() {
+    for callback_url in ["http://127.0.0.1:9000/hook", "http://169.254.169.254/latest/meta-data", "http://10.0.0.8/hook"] {
+        let app = router_with_config(ServerConfig::default()).await;
+        let (status, body) = post_json(
+            app,
+            "/analyze/async",
+            serde_json::json!({
+                "files": [{ "name": "app.js", "content": "const x = 1;" }],
+                "callback_url": callback_url
+            }),
+        )
+        .await;
+
+        assert_eq!(status, 400, "{}", callback_url);
+        assert_eq!(body["error"]["field"], "callback_url");
+    }
+}
+
+#[tokio::test]
+async fn test_async_jobs_over_the_limit_are_refused() {
+    let app = router_with_config(ServerConfig {
+        callback_allowed_hosts: Some(vec!["127.0.0.1".to_string()]),
+        max_async_jobs: 0,
+        ..ServerConfig::default()
+    })
+    .await;
+    let (status, body) = post_json(
+        app,
+        "/analyze/async",
+        serde_json::json!({
+            "files": [{ "name": "app.js", "content": "const x = 1;" }],
+            "callback_url": "http://127.0.0.1:9/hook"
+        }),
+    )
+    .await;
+
+    assert_eq!(status, 429);
+    assert_eq!(body["error"]["code"], "too_many_requests");
+}